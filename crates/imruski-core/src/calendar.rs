@@ -0,0 +1,211 @@
+//! Small calendar types for `date_picker` and `time_input`: proleptic
+//! Gregorian dates and times of day, without time zones.
+//!
+//! `Date::today` and `Time::now` read the system clock in UTC.
+
+use std::fmt;
+
+pub const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Days in `month` (1–12) of `year`; 0 for an invalid month.
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11              => 30,
+        2                           => if is_leap_year(year) { 29 } else { 28 },
+        _                           => 0,
+    }
+}
+
+/// Seconds since the Unix epoch, UTC.
+fn unix_now() -> i64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(d)  => d.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs() as i64),
+    }
+}
+
+/// Days since 1970-01-01 → (year, month, day), proleptic Gregorian.
+/// Howard Hinnant's `civil_from_days`.
+pub(crate) fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z   = z + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp  = (5 * doy + 2) / 153;
+    let d   = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m   = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y   = yoe + era * 400 + i64::from(m <= 2);
+    (y, m, d)
+}
+
+// ─── Weekday ─────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Weekday {
+    #[default]
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub const ALL: [Self; 7] = [
+        Self::Monday, Self::Tuesday, Self::Wednesday, Self::Thursday,
+        Self::Friday, Self::Saturday, Self::Sunday,
+    ];
+
+    /// 0 for Monday … 6 for Sunday.
+    pub fn index(self) -> usize { self as usize }
+
+    /// Day `n` after this one, wrapping.
+    pub fn plus(self, n: usize) -> Self { Self::ALL[(self.index() + n) % 7] }
+
+    pub fn name(self) -> &'static str {
+        ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"][self.index()]
+    }
+
+    /// Two-letter name for calendar headers.
+    pub fn short_name(self) -> &'static str {
+        ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"][self.index()]
+    }
+}
+
+// ─── Date ────────────────────────────────────────────────────────────────────
+
+/// A calendar date. Always valid; ordered chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year:  i32,
+    month: u8,
+    day:   u8,
+}
+
+impl Default for Date {
+    fn default() -> Self { Self { year: 1970, month: 1, day: 1 } }
+}
+
+impl Date {
+    /// `None` unless `month` is 1–12 and `day` exists in that month.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        (day >= 1 && day <= days_in_month(year, month)).then_some(Self { year, month, day })
+    }
+
+    pub fn today() -> Self { Self::from_days_since_epoch(unix_now().div_euclid(86_400)) }
+
+    pub fn year(self)  -> i32 { self.year }
+    pub fn month(self) -> u8  { self.month }
+    pub fn day(self)   -> u8  { self.day }
+
+    /// Date `days` after 1970-01-01.
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        let (year, month, day) = civil_from_days(days);
+        Self { year: year as i32, month: month as u8, day: day as u8 }
+    }
+
+    /// Days from 1970-01-01 to this date.
+    pub fn days_since_epoch(self) -> i64 {
+        let y   = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = y.div_euclid(400);
+        let yoe = y.rem_euclid(400);
+        let m   = i64::from(self.month);
+        let doy = (153 * if m > 2 { m - 3 } else { m + 9 } + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::Thursday.plus(self.days_since_epoch().rem_euclid(7) as usize)
+    }
+
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Same day `months` later, clamped to the length of the target month.
+    pub fn add_months(self, months: i32) -> Self {
+        let total = self.year * 12 + i32::from(self.month) - 1 + months;
+        let (year, month) = (total.div_euclid(12), (total.rem_euclid(12) + 1) as u8);
+        Self { year, month, day: self.day.min(days_in_month(year, month)) }
+    }
+
+    pub fn first_of_month(self) -> Self { Self { day: 1, ..self } }
+
+    /// Parse `YYYY-MM-DD`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.trim().splitn(3, '-');
+        let year  = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day   = parts.next()?.parse().ok()?;
+        Self::new(year, month, day)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+// ─── Time ────────────────────────────────────────────────────────────────────
+
+/// A time of day with second resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Time {
+    hour:   u8,
+    minute: u8,
+    second: u8,
+}
+
+impl Time {
+    /// `None` unless the hour is 0–23 and minute and second 0–59.
+    pub fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60).then_some(Self { hour, minute, second })
+    }
+
+    pub fn now() -> Self { Self::from_seconds(unix_now().rem_euclid(86_400) as u32) }
+
+    pub fn hour(self)   -> u8 { self.hour }
+    pub fn minute(self) -> u8 { self.minute }
+    pub fn second(self) -> u8 { self.second }
+
+    /// Time `secs` after midnight, wrapping at 24 h.
+    pub fn from_seconds(secs: u32) -> Self {
+        let s = secs % 86_400;
+        Self { hour: (s / 3600) as u8, minute: (s / 60 % 60) as u8, second: (s % 60) as u8 }
+    }
+
+    pub fn seconds_since_midnight(self) -> u32 {
+        u32::from(self.hour) * 3600 + u32::from(self.minute) * 60 + u32::from(self.second)
+    }
+
+    /// Parse `HH:MM` or `HH:MM:SS`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.trim().split(':');
+        let hour   = parts.next()?.parse().ok()?;
+        let minute = parts.next()?.parse().ok()?;
+        let second = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+        if parts.next().is_some() { return None; }
+        Self::new(hour, minute, second)
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
@@ -0,0 +1,126 @@
+//! Virtualised lists – submit only the rows that can be seen.
+//!
+//! ```ignore
+//! let mut clipper = ListClipper::new(lines.len(), 0.0);
+//! while let Some(rows) = clipper.step(ui) {
+//!     for i in rows {
+//!         ui.text(&lines[i]);
+//!     }
+//! }
+//! ```
+//!
+//! Rows outside the window (or outside a scrolling table) are skipped, but
+//! the layout cursor still moves over them so the content size – and any
+//! scrollbar derived from it – stays correct. Works inside tables too: call
+//! `table_next_row` per submitted row as usual.
+
+use std::ops::Range;
+
+use crate::{ui::Ui, widgets::table};
+
+/// Steps through the visible index ranges of a list of `count` equally
+/// tall rows. See the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct ListClipper {
+    count:       usize,
+    item_height: f32,
+    step:        Step,
+    /// Cursor y before the first row (layout or table coordinates).
+    start_y:     f32,
+    /// First row not yet submitted or skipped.
+    done:        usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step { Start, Measure, Visible, Finished }
+
+impl ListClipper {
+    /// `item_height` is the distance between row tops, including spacing.
+    /// Pass `0` to measure it from the first row.
+    pub fn new(count: usize, item_height: f32) -> Self {
+        Self { count, item_height, step: Step::Start, start_y: 0.0, done: 0 }
+    }
+
+    /// Row pitch in use (measured after the first step when `0` was given).
+    pub fn item_height(&self) -> f32 { self.item_height }
+
+    /// Next range of rows to submit, or `None` once the list is done.
+    pub fn step(&mut self, ui: &mut Ui<'_>) -> Option<Range<usize>> {
+        match self.step {
+            Step::Start => {
+                if self.count == 0 { return self.finish(ui); }
+                self.start_y = cursor_y(ui)?;
+                if self.item_height <= 0.0 {
+                    self.step = Step::Measure;
+                    self.done = 1;
+                    return Some(0..1);
+                }
+                self.visible(ui)
+            }
+            Step::Measure => {
+                let h = cursor_y(ui).map_or(0.0, |y| y - self.start_y);
+                self.item_height = if h > 0.0 { h } else { ui.ctx.style.font_size };
+                self.visible(ui)
+            }
+            Step::Visible  => self.finish(ui),
+            Step::Finished => None,
+        }
+    }
+
+    /// Skip to the first visible row and return the visible range.
+    fn visible(&mut self, ui: &mut Ui<'_>) -> Option<Range<usize>> {
+        self.step = Step::Visible;
+        let (top, bot) = visible_y(ui);
+        let base  = self.start_y;
+        let h     = self.item_height;
+        let first = (((top - base) / h).floor().max(0.0) as usize).clamp(self.done, self.count);
+        let last  = (((bot - base) / h).ceil().max(0.0) as usize).clamp(first, self.count);
+        skip(ui, first - self.done, h);
+        self.done = last;
+        if first < last { Some(first..last) } else { self.finish(ui) }
+    }
+
+    /// Move past the remaining rows.
+    fn finish(&mut self, ui: &mut Ui<'_>) -> Option<Range<usize>> {
+        if self.step != Step::Finished {
+            skip(ui, self.count - self.done, self.item_height);
+            self.done = self.count;
+            self.step = Step::Finished;
+        }
+        None
+    }
+}
+
+fn in_table(ui: &Ui<'_>) -> bool { !ui.ctx.table_stack.is_empty() }
+
+/// Where the next row would start.
+fn cursor_y(ui: &mut Ui<'_>) -> Option<f32> {
+    if in_table(ui) {
+        return table::clipper_frame(ui).map(|(y, _, _)| y);
+    }
+    ui.ctx.current_window().map(|w| w.layout.cursor.y)
+}
+
+/// Visible y range in the same coordinates as `cursor_y`.
+fn visible_y(ui: &mut Ui<'_>) -> (f32, f32) {
+    if in_table(ui) {
+        if let Some((_, top, bot)) = table::clipper_frame(ui) { return (top, bot); }
+    }
+    let r = ui.ctx.visible_rect();
+    (r.min.y, r.max.y)
+}
+
+/// Advance the layout (or table) over `rows` rows without submitting them.
+fn skip(ui: &mut Ui<'_>, rows: usize, pitch: f32) {
+    if rows == 0 || pitch <= 0.0 { return; }
+    if in_table(ui) {
+        table::clipper_skip(ui, rows, pitch);
+        return;
+    }
+    let sp = ui.ctx.style.item_spacing;
+    if let Some(l) = ui.ctx.current_layout_mut() {
+        let h = rows as f32 * pitch;
+        // Same as placing `rows` items of height `pitch - spacing`
+        l.dummy(crate::Vec2::new(0.0, h - sp.1), sp);
+    }
+}
@@ -12,11 +12,17 @@ fn new_fxmap<K, V>() -> FxMap<K, V> {
 
 use crate::{
 
+    drag_drop::DragDropPayload,
     draw_list::DrawList,
     id::Id,
-    input::InputState,
+    input::{InputState, KeyChord},
     layout::Layout,
+    shortcuts::Shortcuts,
     style::Style,
+    widgets::chart::{PlotFrame, PlotState},
+    widgets::node_editor::{NodeEditorFrame, NodeEditorState},
+    widgets::table::{TableFrame, TableState},
+    widgets::tabs::{TabBarFrame, TabBarState},
     Vec2, Rect, WindowFlags,
 };
 
@@ -52,6 +58,21 @@ pub struct WidgetStorage {
     pub open:   bool,
 }
 
+// ─── Commands ────────────────────────────────────────────────────────────────
+
+/// An action reachable from the command palette.
+#[derive(Debug, Clone)]
+pub struct Command {
+    /// `Id::from_str(name)` – returned by the palette when chosen.
+    pub id:       Id,
+    pub name:     String,
+    pub category: String,
+    pub shortcut: Option<KeyChord>,
+}
+
+/// Most-recently-used commands remembered by the palette.
+pub(crate) const MAX_RECENT_COMMANDS: usize = 8;
+
 // ─── Active window stack entry ────────────────────────────────────────────────
 
 #[derive(Debug)]
@@ -90,6 +111,7 @@ pub struct Context {
     pub(crate) hot_item:    Option<Id>,   // hovered
     pub(crate) active_item: Option<Id>,   // being pressed/dragged
     pub(crate) focus_item:  Option<Id>,   // keyboard focus
+    pub(crate) focused_window: Option<Id>, // last window clicked
 
     // ID stack (pushed/popped by the user)
     pub(crate) id_stack: Vec<Id>,
@@ -97,11 +119,48 @@ pub struct Context {
     // Window draw-order (back → front)
     pub(crate) window_order: Vec<Id>,
 
+    // Open state applied to the next tree node / collapsing header
+    pub(crate) next_item_open: Option<bool>,
+
     // Tooltip buffer (rendered on top at end of frame)
     pub(crate) tooltip: Option<String>,
 
     // Delta time passed from the backend
     pub(crate) delta_time: f32,
+
+    // Named keyboard shortcuts
+    pub(crate) shortcuts: Shortcuts,
+    // Hotkey widget currently recording a chord (suppresses shortcuts)
+    pub(crate) hotkey_capture: Option<Id>,
+    // Whether that widget was submitted this frame; if not, recording stops
+    pub(crate) hotkey_capture_seen: bool,
+
+    // Command palette registry + most-recent-first history
+    pub(crate) commands:        Vec<Command>,
+    pub(crate) recent_commands: Vec<Id>,
+
+    // Table column layouts (persistent) and the tables being submitted
+    pub(crate) tables:      FxMap<Id, TableState>,
+    pub(crate) table_stack: Vec<TableFrame>,
+
+    // Tab order / selection (persistent) and the tab bars being submitted
+    pub(crate) tab_bars:      FxMap<Id, TabBarState>,
+    pub(crate) tab_bar_stack: Vec<TabBarFrame>,
+
+    // Plot limits / hidden series (persistent) and the plot being submitted
+    pub(crate) plots: FxMap<Id, PlotState>,
+    pub(crate) plot:  Option<PlotFrame>,
+
+    // Node editor views / positions (persistent) and the editor being submitted
+    pub(crate) node_editors: FxMap<Id, NodeEditorState>,
+    pub(crate) node_editor:  Option<NodeEditorFrame>,
+
+    // Payload being dragged, and the colour pickers' most-recent-first history
+    pub(crate) drag_drop:     Option<DragDropPayload>,
+    pub(crate) color_history: Vec<[f32; 4]>,
+
+    // Text copied by a widget, waiting for the backend
+    pub(crate) clipboard_out: Option<String>,
 }
 
 impl Default for Context {
@@ -120,10 +179,28 @@ impl Context {
             hot_item:       None,
             active_item:    None,
             focus_item:     None,
+            focused_window: None,
             id_stack:       Vec::new(),
             window_order:   Vec::new(),
+            next_item_open: None,
             tooltip:        None,
             delta_time:     0.016,
+            shortcuts:      Shortcuts::new(),
+            hotkey_capture: None,
+            hotkey_capture_seen: false,
+            commands:       Vec::new(),
+            recent_commands: Vec::new(),
+            tables:          new_fxmap(),
+            table_stack:     Vec::new(),
+            tab_bars:        new_fxmap(),
+            tab_bar_stack:   Vec::new(),
+            plots:           new_fxmap(),
+            plot:            None,
+            node_editors:    new_fxmap(),
+            node_editor:     None,
+            drag_drop:       None,
+            color_history:   Vec::new(),
+            clipboard_out:   None,
         }
     }
 
@@ -132,10 +209,73 @@ impl Context {
     pub fn style(&self) -> &Style { &self.style }
     pub fn style_mut(&mut self) -> &mut Style { &mut self.style }
     pub fn set_display_size(&mut self, sz: Vec2) { self.input.display_size = sz; }
-    pub fn set_delta_time(&mut self, dt: f32)    { self.delta_time = dt; }
+    pub fn set_delta_time(&mut self, dt: f32)    { self.delta_time = dt; self.input.delta_time = dt; }
     pub fn input_mut(&mut self) -> &mut InputState { &mut self.input }
     pub fn input(&self)         -> &InputState     { &self.input }
 
+    // ── Clipboard ─────────────────────────────────────────────────────────────
+
+    /// Ask the backend to put `text` on the system clipboard. The text rides
+    /// along on this frame's [`RenderFrame`](crate::renderer::RenderFrame).
+    pub fn set_clipboard_text(&mut self, text: impl Into<String>) { self.clipboard_out = Some(text.into()); }
+
+    /// Text copied this frame, for hosts that drive the clipboard themselves
+    /// instead of reading [`RenderFrame::clipboard_text`](crate::renderer::RenderFrame::clipboard_text).
+    /// Must be called before the next `new_frame`, which drops it.
+    pub fn take_clipboard_text(&mut self) -> Option<String> { self.clipboard_out.take() }
+
+    // ── Shortcuts ─────────────────────────────────────────────────────────────
+
+    pub fn shortcuts(&self)         -> &Shortcuts     { &self.shortcuts }
+    pub fn shortcuts_mut(&mut self) -> &mut Shortcuts { &mut self.shortcuts }
+
+    /// `true` if the shortcut bound to `action` was pressed this frame.
+    ///
+    /// Window-scoped bindings only fire while their window has focus (the
+    /// last window clicked). Nothing fires while a hotkey widget is recording.
+    pub fn shortcut_pressed(&self, action: &str) -> bool {
+        self.hotkey_capture.is_none()
+            && self.shortcuts.pressed(action, &self.input, self.focused_window)
+    }
+
+    // ── Commands ──────────────────────────────────────────────────────────────
+
+    /// Register (or update) a command for the command palette.
+    ///
+    /// A `shortcut` is also bound in [`Shortcuts`] under `name`, so
+    /// `shortcut_pressed(name)` fires for it. Returns the command's ID.
+    pub fn register_command(&mut self, name: &str, category: &str, shortcut: Option<KeyChord>) -> Id {
+        let id  = Id::from_str(name);
+        let cmd = Command { id, name: name.to_owned(), category: category.to_owned(), shortcut };
+        match self.commands.iter_mut().find(|c| c.id == id) {
+            Some(existing) => *existing = cmd,
+            None           => self.commands.push(cmd),
+        }
+        match shortcut {
+            Some(chord) => self.shortcuts.bind(name, chord),
+            None        => self.shortcuts.unbind(name),
+        }
+        id
+    }
+
+    pub fn unregister_command(&mut self, name: &str) {
+        let id = Id::from_str(name);
+        self.commands.retain(|c| c.id != id);
+        self.recent_commands.retain(|&r| r != id);
+        self.shortcuts.unbind(name);
+    }
+
+    pub fn commands(&self) -> &[Command] { &self.commands }
+
+    /// Commands run from the palette, most recent first.
+    pub fn recent_commands(&self) -> &[Id] { &self.recent_commands }
+
+    pub(crate) fn push_recent_command(&mut self, id: Id) {
+        self.recent_commands.retain(|&r| r != id);
+        self.recent_commands.insert(0, id);
+        self.recent_commands.truncate(MAX_RECENT_COMMANDS);
+    }
+
     // ── Frame lifecycle ───────────────────────────────────────────────────────
 
     /// Begin a new frame. Call this before any widget methods.
@@ -144,12 +284,26 @@ impl Context {
         self.draw_list.clear();
         self.window_stack.clear();
         self.tooltip = None;
+        self.next_item_open = None;
+        self.table_stack.clear();
+        self.tab_bar_stack.clear();
+        self.plot = None;
+        self.node_editor = None;
+        // Already handed to the backend by the last `end_frame`
+        self.clipboard_out = None;
+        // The recording hotkey widget went away (window closed, tab switched)
+        if !self.hotkey_capture_seen { self.hotkey_capture = None; }
+        self.hotkey_capture_seen = false;
 
         // Release active item if mouse was released
         use crate::input::MouseButton;
         if self.input.mouse_released(MouseButton::Left) {
             self.active_item = None;
         }
+        // A payload lives until the frame after the button goes up
+        if !self.input.mouse_down(MouseButton::Left) {
+            self.drag_drop = None;
+        }
     }
 
     /// End the frame and return a render frame.
@@ -158,6 +312,7 @@ impl Context {
             draw_list:    &self.draw_list,
             display_size:  self.input.display_size,
             scale_factor:  1.0,
+            clipboard_text: self.clipboard_out.as_deref(),
         }
     }
 
@@ -173,6 +328,9 @@ impl Context {
         self.id_stack.iter().fold(base, |acc, &id| acc.combine(id))
     }
 
+    /// Force the open state of the next tree node / collapsing header.
+    pub fn set_next_item_open(&mut self, open: bool) { self.next_item_open = Some(open); }
+
     // ── Interaction helpers ───────────────────────────────────────────────────
 
     pub(crate) fn is_hot(&self, id: Id)    -> bool { self.hot_item    == Some(id) }
@@ -187,7 +345,11 @@ impl Context {
         rect: Rect,
     ) -> (bool, bool, bool) {
         use crate::input::MouseButton;
-        let hovered = rect.contains(self.input.mouse_pos);
+        let in_clip = match self.draw_list.clip_rect() {
+            Some(clip) => clip.contains(self.input.mouse_pos),
+            None       => true,
+        };
+        let hovered = in_clip && rect.contains(self.input.mouse_pos);
         if hovered { self.hot_item = Some(id); }
 
         let active  = self.active_item == Some(id);
@@ -230,6 +392,19 @@ impl Context {
         self.window_stack.last_mut().map(|w| &mut w.layout)
     }
 
+    /// Screen area where content of the current window can be seen: the
+    /// window rect, narrowed by the active clip rect and the display.
+    pub(crate) fn visible_rect(&self) -> Rect {
+        let mut r = Rect::from_min_size(Vec2::ZERO, self.input.display_size);
+        if let Some(ws) = self.current_window().and_then(|w| self.windows.get(&w.id)) {
+            r = r.intersect(ws.rect());
+        }
+        if let Some(clip) = self.draw_list.clip_rect() {
+            r = r.intersect(clip);
+        }
+        r
+    }
+
     // ── Draw list passthrough ─────────────────────────────────────────────────
 
     pub(crate) fn draw_list_mut(&mut self) -> &mut DrawList { &mut self.draw_list }
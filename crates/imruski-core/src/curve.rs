@@ -0,0 +1,74 @@
+//! Easing curves mapping `0..=1` to a value, edited with `curve_edit`.
+
+use crate::Vec2;
+
+/// An easing curve. Inputs outside `0..=1` are clamped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Curve {
+    /// CSS-style `cubic-bezier(p1, p2)` from (0, 0) to (1, 1). Control
+    /// point `x` is kept in `0..=1` so the curve is a function of `t`;
+    /// `y` may overshoot.
+    Bezier { p1: Vec2, p2: Vec2 },
+    /// Points kept sorted by `x`, joined by straight segments and flat
+    /// beyond the ends.
+    Linear(Vec<Vec2>),
+}
+
+impl Default for Curve {
+    fn default() -> Self { Self::ease_in_out() }
+}
+
+impl Curve {
+    pub fn linear()      -> Self { Self::Linear(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)]) }
+    pub fn ease_in()     -> Self { Self::Bezier { p1: Vec2::new(0.42, 0.0), p2: Vec2::new(1.0, 1.0) } }
+    pub fn ease_out()    -> Self { Self::Bezier { p1: Vec2::new(0.0, 0.0),  p2: Vec2::new(0.58, 1.0) } }
+    pub fn ease_in_out() -> Self { Self::Bezier { p1: Vec2::new(0.42, 0.0), p2: Vec2::new(0.58, 1.0) } }
+
+    /// Value of the curve at `t`.
+    pub fn evaluate(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Bezier { p1, p2 } => {
+                let s = bezier_param_for_x(p1.x, p2.x, t);
+                cubic(p1.y, p2.y, s)
+            }
+            Self::Linear(points) => {
+                let i = points.partition_point(|p| p.x <= t);
+                match (i.checked_sub(1).map(|k| points[k]), points.get(i)) {
+                    (Some(a), Some(b)) => {
+                        let span = b.x - a.x;
+                        a.y + (b.y - a.y) * if span > 0.0 { (t - a.x) / span } else { 0.0 }
+                    }
+                    (Some(a), None) => a.y,
+                    (None, Some(b)) => b.y,
+                    (None, None)    => t,
+                }
+            }
+        }
+    }
+}
+
+/// One coordinate of the cubic Bézier with end points 0 and 1.
+pub(crate) fn cubic(c1: f32, c2: f32, s: f32) -> f32 {
+    let u = 1.0 - s;
+    3.0 * u * u * s * c1 + 3.0 * u * s * s * c2 + s * s * s
+}
+
+/// Bézier parameter whose `x` is `x`: Newton steps, bisection fallback.
+fn bezier_param_for_x(x1: f32, x2: f32, x: f32) -> f32 {
+    let mut s = x;
+    for _ in 0..8 {
+        let err = cubic(x1, x2, s) - x;
+        if err.abs() < 1e-5 { return s; }
+        let u = 1.0 - s;
+        let d = 3.0 * u * u * x1 + 6.0 * u * s * (x2 - x1) + 3.0 * s * s * (1.0 - x2);
+        if d.abs() < 1e-6 { break; }
+        s = (s - err / d).clamp(0.0, 1.0);
+    }
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..30 {
+        s = (lo + hi) * 0.5;
+        if cubic(x1, x2, s) < x { lo = s; } else { hi = s; }
+    }
+    s
+}
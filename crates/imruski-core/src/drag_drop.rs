@@ -0,0 +1,56 @@
+//! Drag and drop between widgets.
+//!
+//! A source publishes a typed payload once its item has been dragged a few
+//! pixels; a target takes it when the mouse is released over it. The
+//! payload is dropped at the start of the frame after the release.
+//!
+//! Colour swatches use [`PAYLOAD_COLOR`] – an RGBA `[f32; 4]`.
+
+use crate::{context::Context, id::Id, input::MouseButton, Rect};
+
+/// Payload kind for RGBA colours (16 bytes, `[f32; 4]`).
+pub const PAYLOAD_COLOR: &str = "COLOR_4F";
+
+/// Pixels the mouse must travel before a press becomes a drag.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// Data carried by the current drag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragDropPayload {
+    /// Application-defined type tag; targets only accept kinds they know.
+    pub kind:   String,
+    pub data:   Vec<u8>,
+    /// The item that started the drag.
+    pub source: Id,
+}
+
+impl DragDropPayload {
+    pub fn is(&self, kind: &str) -> bool { self.kind == kind }
+
+    /// The colour carried by a [`PAYLOAD_COLOR`] payload.
+    pub fn color(&self) -> Option<[f32; 4]> {
+        if !self.is(PAYLOAD_COLOR) || self.data.len() != 16 { return None; }
+        Some(bytemuck::pod_read_unaligned(&self.data))
+    }
+}
+
+impl Context {
+    /// Publish a payload once the active item `id` is dragged past the
+    /// threshold. Returns `true` while `id` is the drag source.
+    pub(crate) fn drag_drop_source(&mut self, id: Id, kind: &str, data: impl FnOnce() -> Vec<u8>) -> bool {
+        if let Some(p) = &self.drag_drop { return p.source == id; }
+        let dragging = self.active_item == Some(id)
+            && self.input.mouse_drag_delta(MouseButton::Left).length() > DRAG_THRESHOLD;
+        if dragging {
+            self.drag_drop = Some(DragDropPayload { kind: kind.to_owned(), data: data(), source: id });
+        }
+        dragging
+    }
+
+    /// The payload of `kind` released over `rect` this frame, unless it
+    /// came from `id` itself.
+    pub(crate) fn drag_drop_target(&self, id: Id, rect: Rect, kind: &str) -> Option<&DragDropPayload> {
+        if !self.input.mouse_released(MouseButton::Left) || !rect.contains(self.input.mouse_pos) { return None; }
+        self.drag_drop.as_ref().filter(|p| p.is(kind) && p.source != id)
+    }
+}
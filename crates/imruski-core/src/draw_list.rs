@@ -170,6 +170,25 @@ impl DrawList {
         }
     }
 
+    /// Emit a placeholder quad and return its first vertex index. Fill it in
+    /// with `patch_rect` once the size is known – used for backgrounds that
+    /// must sit under content submitted after them (e.g. table rows).
+    pub(crate) fn reserve_rect(&mut self) -> usize {
+        let at = self.vtx_buf.len();
+        self.fill_rect_raw(Rect::new(Vec2::ZERO, Vec2::ZERO), 0);
+        at
+    }
+
+    /// Set the geometry and colour of a quad from `reserve_rect`.
+    pub(crate) fn patch_rect(&mut self, at: usize, r: Rect, col: Color) {
+        let c = col.to_rgba_u32();
+        let corners = [r.min, Vec2::new(r.max.x, r.min.y), r.max, Vec2::new(r.min.x, r.max.y)];
+        for (v, p) in self.vtx_buf[at..at + 4].iter_mut().zip(corners) {
+            v.pos = p.into();
+            v.col = c;
+        }
+    }
+
     fn fill_rect_raw(&mut self, r: Rect, col: u32) {
         let base = self.vtx_buf.len() as u32;
         let uv = Vec2::new(0.0, 0.0); // white pixel UV
@@ -266,6 +285,29 @@ impl DrawList {
         }
     }
 
+    /// Stroked arc from `a_min` to `a_max` (radians, clockwise on screen,
+    /// 0 = +x). Segment count follows the arc length.
+    pub fn arc(&mut self, center: Vec2, radius: f32, a_min: f32, a_max: f32, thickness: f32, col: Color) {
+        let c  = col.to_rgba_u32();
+        let uv = Vec2::ZERO;
+        let span = a_max - a_min;
+        if span.abs() < 1e-4 || radius <= 0.0 { return; }
+        let segs = ((span.abs() * radius / 3.0).ceil() as usize).clamp(4, 256);
+        let (r0, r1) = ((radius - thickness * 0.5).max(0.0), radius + thickness * 0.5);
+        let base = self.vtx_buf.len() as u32;
+        for i in 0..=segs {
+            let a = a_min + span * i as f32 / segs as f32;
+            let d = Vec2::new(a.cos(), a.sin());
+            self.add_vert(center + d * r0, uv, c);
+            self.add_vert(center + d * r1, uv, c);
+        }
+        for i in 0..segs as u32 {
+            let k = i * 2;
+            self.add_idx(base, k, k + 1, k + 3);
+            self.add_idx(base, k, k + 3, k + 2);
+        }
+    }
+
     /// Render a textured quad (e.g. an image or font glyph).
     pub fn image_quad(
         &mut self,
@@ -317,4 +359,14 @@ impl DrawList {
         self.add_vert(c_pt, uv, c);
         self.add_idx(base, 0, 1, 2);
     }
+
+    /// Triangle with a colour per corner, blended across the face.
+    pub fn triangle_gradient(&mut self, a: Vec2, b: Vec2, c_pt: Vec2, cols: [Color; 3]) {
+        let uv = Vec2::ZERO;
+        let base = self.vtx_buf.len() as u32;
+        self.add_vert(a,    uv, cols[0].to_rgba_u32());
+        self.add_vert(b,    uv, cols[1].to_rgba_u32());
+        self.add_vert(c_pt, uv, cols[2].to_rgba_u32());
+        self.add_idx(base, 0, 1, 2);
+    }
 }
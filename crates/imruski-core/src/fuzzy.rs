@@ -0,0 +1,99 @@
+//! Case-insensitive substring and fuzzy matching for filterable widgets.
+
+/// How a filter string is matched against candidate items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilterMode {
+    /// The filter must appear contiguously somewhere in the item.
+    Substring,
+    /// The filter characters must appear in order, possibly with gaps.
+    #[default]
+    Fuzzy,
+}
+
+/// Result of a successful match.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Match {
+    /// Higher is better.
+    pub score: i32,
+    /// Char indices (not byte offsets) of the matched characters in the item.
+    pub positions: Vec<usize>,
+}
+
+const SCORE_MATCH:       i32 = 16;
+const BONUS_CONSECUTIVE: i32 = 15;
+const BONUS_WORD_START:  i32 = 10;
+const BONUS_FIRST_CHAR:  i32 = 8;
+const MAX_LEADING_GAP:   i32 = 15;
+
+/// Match `pattern` against `text` using `mode`.
+pub fn matches(mode: FilterMode, pattern: &str, text: &str) -> Option<Match> {
+    match mode {
+        FilterMode::Substring => substring_match(pattern, text),
+        FilterMode::Fuzzy     => fuzzy_match(pattern, text),
+    }
+}
+
+/// Case-insensitive substring match. Earlier occurrences score higher.
+pub fn substring_match(pattern: &str, text: &str) -> Option<Match> {
+    let pat: Vec<char> = fold(pattern);
+    if pat.is_empty() { return Some(Match::default()); }
+    let txt: Vec<char> = fold(text);
+    let start = txt.windows(pat.len()).position(|w| w == pat.as_slice())?;
+    let mut score = SCORE_MATCH * pat.len() as i32 - (start as i32).min(MAX_LEADING_GAP);
+    if start == 0 { score += BONUS_FIRST_CHAR; }
+    Some(Match { score, positions: (start..start + pat.len()).collect() })
+}
+
+/// Case-insensitive fuzzy match (pattern characters in order, gaps allowed).
+///
+/// Consecutive runs and matches at word boundaries (`_`, `-`, space, `.`,
+/// `/`, or a lower→upper camel-case step) are rewarded; leading gaps are
+/// penalised. Every possible starting character is tried and the best
+/// greedy alignment wins.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<Match> {
+    let pat: Vec<char> = fold(pattern);
+    if pat.is_empty() { return Some(Match::default()); }
+    let raw: Vec<char> = text.chars().collect();
+    let txt: Vec<char> = fold(text);
+    if pat.len() > txt.len() { return None; }
+
+    let mut best: Option<Match> = None;
+    for start in (0..txt.len()).filter(|&i| txt[i] == pat[0]) {
+        let mut positions = Vec::with_capacity(pat.len());
+        let mut pi = 0;
+        for (ti, &c) in txt.iter().enumerate().skip(start) {
+            if pi < pat.len() && c == pat[pi] {
+                positions.push(ti);
+                pi += 1;
+            }
+        }
+        if pi < pat.len() { break; } // later starts can only match fewer chars
+
+        let mut score = -(start as i32).min(MAX_LEADING_GAP);
+        for (k, &p) in positions.iter().enumerate() {
+            score += SCORE_MATCH;
+            if p == 0 { score += BONUS_FIRST_CHAR; }
+            if is_word_start(&raw, p) { score += BONUS_WORD_START; }
+            if k > 0 {
+                let gap = p - positions[k - 1] - 1;
+                if gap == 0 { score += BONUS_CONSECUTIVE; } else { score -= gap.min(5) as i32; }
+            }
+        }
+        let better = match &best { Some(b) => score > b.score, None => true };
+        if better {
+            best = Some(Match { score, positions });
+        }
+    }
+    best
+}
+
+/// Lower-case one char at a time so indices stay aligned with the original.
+fn fold(s: &str) -> Vec<char> {
+    s.chars().map(|c| c.to_lowercase().next().unwrap_or(c)).collect()
+}
+
+fn is_word_start(chars: &[char], i: usize) -> bool {
+    if i == 0 { return true; }
+    let (prev, cur) = (chars[i - 1], chars[i]);
+    matches!(prev, '_' | '-' | ' ' | '.' | '/' | ':') || (prev.is_lowercase() && cur.is_uppercase())
+}
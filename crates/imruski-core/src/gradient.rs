@@ -0,0 +1,90 @@
+//! Colour ramps sampled with [`Color::lerp`].
+//!
+//! ```ignore
+//! let mut fire = Gradient::new(Color::from_hex(0x200000), Color::YELLOW);
+//! fire.add_stop(0.4, Color::RED);
+//! ui.gradient_edit("fire", &mut fire);
+//! let c = fire.sample(particle.age / particle.life);
+//! ```
+
+use crate::Color;
+
+/// A colour at a position in `0..=1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    pub pos:   f32,
+    pub color: Color,
+}
+
+/// Stops sorted by position; never empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    stops: Vec<GradientStop>,
+}
+
+impl Default for Gradient {
+    fn default() -> Self { Self::new(Color::BLACK, Color::WHITE) }
+}
+
+impl Gradient {
+    /// Two-stop ramp from `from` at 0 to `to` at 1.
+    pub fn new(from: Color, to: Color) -> Self {
+        Self { stops: vec![GradientStop { pos: 0.0, color: from }, GradientStop { pos: 1.0, color: to }] }
+    }
+
+    /// Gradient from arbitrary stops; positions are clamped and sorted.
+    /// An empty list gives the default black → white ramp.
+    pub fn from_stops(stops: &[GradientStop]) -> Self {
+        if stops.is_empty() { return Self::default(); }
+        let mut stops: Vec<_> = stops.iter().map(|s| GradientStop { pos: s.pos.clamp(0.0, 1.0), ..*s }).collect();
+        stops.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+        Self { stops }
+    }
+
+    pub fn stops(&self) -> &[GradientStop] { &self.stops }
+    pub fn len(&self) -> usize { self.stops.len() }
+    /// Always `false` – a gradient keeps at least one stop.
+    pub fn is_empty(&self) -> bool { self.stops.is_empty() }
+
+    /// Insert a stop; returns its index.
+    pub fn add_stop(&mut self, pos: f32, color: Color) -> usize {
+        let pos = pos.clamp(0.0, 1.0);
+        let i = self.stops.partition_point(|s| s.pos <= pos);
+        self.stops.insert(i, GradientStop { pos, color });
+        i
+    }
+
+    /// Remove a stop unless it is the last one left.
+    pub fn remove_stop(&mut self, index: usize) -> Option<GradientStop> {
+        (self.stops.len() > 1 && index < self.stops.len()).then(|| self.stops.remove(index))
+    }
+
+    /// Move a stop; returns its index after re-sorting, or `None` if
+    /// `index` is out of range.
+    pub fn set_stop_pos(&mut self, index: usize, pos: f32) -> Option<usize> {
+        if index >= self.stops.len() { return None; }
+        let mut stop = self.stops.remove(index);
+        stop.pos = pos.clamp(0.0, 1.0);
+        let i = self.stops.partition_point(|s| s.pos <= stop.pos);
+        self.stops.insert(i, stop);
+        Some(i)
+    }
+
+    pub fn set_stop_color(&mut self, index: usize, color: Color) {
+        if let Some(s) = self.stops.get_mut(index) { s.color = color; }
+    }
+
+    /// Colour at `t`; flat before the first and after the last stop.
+    pub fn sample(&self, t: f32) -> Color {
+        let i = self.stops.partition_point(|s| s.pos <= t);
+        match (i.checked_sub(1).map(|k| self.stops[k]), self.stops.get(i)) {
+            (Some(a), Some(b)) => {
+                let span = b.pos - a.pos;
+                a.color.lerp(b.color, if span > 0.0 { (t - a.pos) / span } else { 0.0 })
+            }
+            (Some(a), None) => a.color,
+            (None, Some(b)) => b.color,
+            (None, None)    => Color::TRANSPARENT,
+        }
+    }
+}
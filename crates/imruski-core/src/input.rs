@@ -15,10 +15,16 @@ pub enum Key {
     // Editing
     Backspace, Delete, Enter, Tab,
     Left, Right, Up, Down,
-    Home, End, PageUp, PageDown,
+    Home, End, PageUp, PageDown, Insert,
     // Common
     Escape, Space,
-    A, C, V, X, Y, Z, // cut/copy/paste/undo/redo shortcuts
+    // Letters
+    A, B, C, D, E, F, G, H, I, J, K, L, M,
+    N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    // Top-row digits
+    Num0, Num1, Num2, Num3, Num4, Num5, Num6, Num7, Num8, Num9,
+    // Function keys
+    F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12,
     // Modifiers treated as keys too
     LeftShift, RightShift,
     LeftCtrl, RightCtrl,
@@ -27,10 +33,50 @@ pub enum Key {
     COUNT,
 }
 
+impl Key {
+    /// Every real key, in discriminant order (excludes `COUNT`).
+    pub const ALL: [Key; Key::COUNT as usize] = {
+        use Key::*;
+        [
+            Backspace, Delete, Enter, Tab, Left, Right, Up, Down,
+            Home, End, PageUp, PageDown, Insert, Escape, Space,
+            A, B, C, D, E, F, G, H, I, J, K, L, M,
+            N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+            Num0, Num1, Num2, Num3, Num4, Num5, Num6, Num7, Num8, Num9,
+            F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12,
+            LeftShift, RightShift, LeftCtrl, RightCtrl, LeftAlt, RightAlt,
+        ]
+    };
+
+    /// `true` for the Shift / Ctrl / Alt keys.
+    pub fn is_modifier(self) -> bool {
+        matches!(self, Key::LeftShift | Key::RightShift | Key::LeftCtrl | Key::RightCtrl | Key::LeftAlt | Key::RightAlt)
+    }
+
+    /// Human-readable name, as used by [`KeyChord`]'s text form.
+    pub fn name(self) -> &'static str {
+        const NAMES: [&str; Key::COUNT as usize] = [
+            "Backspace", "Delete", "Enter", "Tab", "Left", "Right", "Up", "Down",
+            "Home", "End", "PageUp", "PageDown", "Insert", "Escape", "Space",
+            "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M",
+            "N", "O", "P", "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z",
+            "0", "1", "2", "3", "4", "5", "6", "7", "8", "9",
+            "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12",
+            "LeftShift", "RightShift", "LeftCtrl", "RightCtrl", "LeftAlt", "RightAlt",
+        ];
+        NAMES[self as usize]
+    }
+
+    /// Inverse of [`Key::name`] (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|k| k.name().eq_ignore_ascii_case(name))
+    }
+}
+
 // ─── Modifiers ───────────────────────────────────────────────────────────────
 
 bitflags::bitflags! {
-    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
     pub struct Modifiers: u8 {
         const CTRL  = 1 << 0;
         const SHIFT = 1 << 1;
@@ -39,12 +85,84 @@ bitflags::bitflags! {
     }
 }
 
+// ─── KeyChord ────────────────────────────────────────────────────────────────
+
+/// A key plus the exact set of modifiers that must be held with it.
+///
+/// The text form is `Ctrl+Shift+K`; see the `Display` / `FromStr` impls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub key:  Key,
+    pub mods: Modifiers,
+}
+
+impl KeyChord {
+    pub const fn new(key: Key, mods: Modifiers) -> Self { Self { key, mods } }
+
+    /// `true` if the key went down this frame with exactly these modifiers held.
+    pub fn pressed(&self, input: &InputState) -> bool {
+        input.key_pressed(self.key) && input.modifiers == self.mods
+    }
+}
+
+impl From<Key> for KeyChord {
+    fn from(key: Key) -> Self { Self::new(key, Modifiers::empty()) }
+}
+
+const MOD_NAMES: [(Modifiers, &str); 4] = [
+    (Modifiers::CTRL,  "Ctrl"),
+    (Modifiers::SHIFT, "Shift"),
+    (Modifiers::ALT,   "Alt"),
+    (Modifiers::SUPER, "Super"),
+];
+
+impl std::fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (m, name) in MOD_NAMES {
+            if self.mods.contains(m) { write!(f, "{name}+")?; }
+        }
+        f.write_str(self.key.name())
+    }
+}
+
+/// Error returned when parsing a [`KeyChord`] from text.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid key chord `{0}`")]
+pub struct ParseKeyChordError(pub String);
+
+impl std::str::FromStr for KeyChord {
+    type Err = ParseKeyChordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseKeyChordError(s.to_owned());
+        let mut mods  = Modifiers::empty();
+        let mut parts = s.split('+').map(str::trim).peekable();
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                let key = Key::from_name(part).filter(|k| !k.is_modifier()).ok_or_else(err)?;
+                return Ok(Self::new(key, mods));
+            }
+            let m = MOD_NAMES.iter()
+                .find(|(_, name)| name.eq_ignore_ascii_case(part))
+                .map(|&(m, _)| m)
+                .ok_or_else(err)?;
+            mods |= m;
+        }
+        Err(err())
+    }
+}
+
+/// Max seconds between two presses to count as a double-click.
+pub const DOUBLE_CLICK_TIME: f64 = 0.30;
+/// Max pixels the mouse may move between the presses of a double-click.
+pub const DOUBLE_CLICK_DIST: f32 = 6.0;
+
 // ─── InputState ──────────────────────────────────────────────────────────────
 
 /// Snapshot of input at the start of each frame.
 ///
 /// Backends fill this in; the UI reads from it.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct InputState {
     // Mouse
     pub mouse_pos:       Vec2,
@@ -54,6 +172,8 @@ pub struct InputState {
     pub mouse_clicked:   [bool; 3],  // rose this frame
     pub mouse_released:  [bool; 3],  // fell this frame
     pub mouse_double_clicked: [bool; 3],
+    last_click_time: [f64; 3],
+    last_click_pos:  [Vec2; 3],
 
     // Keyboard / text
     pub keys_down:  [bool; Key::COUNT as usize],
@@ -65,6 +185,33 @@ pub struct InputState {
     pub display_size:   Vec2,
     pub delta_time:     f32,
     pub frame_count:    u64,
+    /// Seconds since the first frame (sum of `delta_time`); double-click
+    /// detection relies on it, so backends should call `Context::set_delta_time`.
+    pub time:           f64,
+}
+
+impl Default for InputState {
+    fn default() -> Self {
+        Self {
+            mouse_pos:            Vec2::ZERO,
+            mouse_delta:          Vec2::ZERO,
+            mouse_wheel:          0.0,
+            mouse_down:           [false; 3],
+            mouse_clicked:        [false; 3],
+            mouse_released:       [false; 3],
+            mouse_double_clicked: [false; 3],
+            last_click_time:      [f64::NEG_INFINITY; 3],
+            last_click_pos:       [Vec2::ZERO; 3],
+            keys_down:            [false; Key::COUNT as usize],
+            keys_pressed:         [false; Key::COUNT as usize],
+            modifiers:            Modifiers::empty(),
+            text_input:           String::new(),
+            display_size:         Vec2::ZERO,
+            delta_time:           0.0,
+            frame_count:          0,
+            time:                 0.0,
+        }
+    }
 }
 
 impl InputState {
@@ -78,6 +225,7 @@ impl InputState {
         self.mouse_wheel     = 0.0;
         self.text_input.clear();
         self.frame_count    += 1;
+        self.time           += self.delta_time as f64;
     }
 
     // ── builder helpers (call before new_frame for the coming frame) ──────────
@@ -87,9 +235,21 @@ impl InputState {
         self.mouse_pos   = pos;
     }
 
+    /// Press detection also raises `mouse_double_clicked` when two presses
+    /// land within `DOUBLE_CLICK_TIME` / `DOUBLE_CLICK_DIST` of each other.
     pub fn set_mouse_button(&mut self, btn: MouseButton, down: bool) {
         let i = btn as usize;
-        if down && !self.mouse_down[i] { self.mouse_clicked[i]  = true; }
+        if down && !self.mouse_down[i] {
+            self.mouse_clicked[i] = true;
+            let near = (self.mouse_pos - self.last_click_pos[i]).length() <= DOUBLE_CLICK_DIST;
+            if near && self.time - self.last_click_time[i] <= DOUBLE_CLICK_TIME {
+                self.mouse_double_clicked[i] = true;
+                self.last_click_time[i] = f64::NEG_INFINITY; // a third press starts over
+            } else {
+                self.last_click_time[i] = self.time;
+            }
+            self.last_click_pos[i] = self.mouse_pos;
+        }
         if !down && self.mouse_down[i] { self.mouse_released[i] = true; }
         self.mouse_down[i] = down;
     }
@@ -109,6 +269,11 @@ impl InputState {
     #[inline] pub fn mouse_down(&self, btn: MouseButton)     -> bool { self.mouse_down[btn as usize] }
     #[inline] pub fn mouse_clicked(&self, btn: MouseButton)  -> bool { self.mouse_clicked[btn as usize] }
     #[inline] pub fn mouse_released(&self, btn: MouseButton) -> bool { self.mouse_released[btn as usize] }
+    #[inline] pub fn mouse_double_clicked(&self, btn: MouseButton) -> bool { self.mouse_double_clicked[btn as usize] }
+    /// Distance moved since `btn` was pressed; zero while it is up.
+    #[inline] pub fn mouse_drag_delta(&self, btn: MouseButton) -> Vec2 {
+        if self.mouse_down(btn) { self.mouse_pos - self.last_click_pos[btn as usize] } else { Vec2::ZERO }
+    }
 
     #[inline] pub fn key_down(&self, k: Key)     -> bool { self.keys_down[k as usize] }
     #[inline] pub fn key_pressed(&self, k: Key)  -> bool { self.keys_pressed[k as usize] }
@@ -15,7 +15,7 @@ pub struct Layout {
     pub start_x: f32,
     /// Last item bounding box (for same-line spacing).
     pub last_item_max: Vec2,
-    /// Previous cursor Y (for same-line).
+    /// Height of the current line (tallest item placed on it so far).
     pub prev_line_height: f32,
     /// Indent depth.
     pub indent: f32,
@@ -27,6 +27,8 @@ pub struct Layout {
     pub dir: LayoutDir,
     /// Stack for same-line state.
     same_line: bool,
+    /// Top of the current line.
+    line_y: f32,
 }
 
 impl Layout {
@@ -37,6 +39,7 @@ impl Layout {
             last_item_max: start,
             content_size:  Vec2::new(width, f32::MAX),
             content_max:   start,
+            line_y:        start.y,
             ..Default::default()
         }
     }
@@ -56,13 +59,16 @@ impl Layout {
             self.content_max.y.max(pos.y + size.y),
         );
 
-        if self.dir == LayoutDir::Horizontal {
-            self.cursor.x = pos.x + size.x + item_spacing.0;
+        if self.same_line {
             self.prev_line_height = self.prev_line_height.max(size.y);
         } else {
-            self.cursor.y = pos.y + size.y + item_spacing.1;
-            self.cursor.x = self.start_x + self.indent;
+            self.line_y           = pos.y;
+            self.prev_line_height = size.y;
         }
+        // The next item starts a new line unless `same_line` is called.
+        self.cursor.y  = self.line_y + self.prev_line_height + item_spacing.1;
+        self.cursor.x  = self.start_x + self.indent;
+        self.dir       = LayoutDir::Vertical;
         self.same_line = false;
         pos
     }
@@ -71,18 +77,27 @@ impl Layout {
     pub fn same_line(&mut self, spacing: f32) {
         let spacing = if spacing < 0.0 { 8.0 } else { spacing };
         self.cursor.x = self.last_item_max.x + spacing;
-        self.cursor.y = self.last_item_max.y - self.prev_line_height;
+        self.cursor.y = self.line_y;
         self.dir = LayoutDir::Horizontal;
         self.same_line = true;
     }
 
     /// Advance cursor to the next line without placing a widget.
     pub fn new_line(&mut self, item_spacing: (f32, f32)) {
-        self.cursor.y = self.last_item_max.y + item_spacing.1;
+        self.cursor.y = self.line_y + self.prev_line_height + item_spacing.1;
         self.cursor.x = self.start_x + self.indent;
         self.dir = LayoutDir::Vertical;
     }
 
+    /// Start a fresh line at the cursor, dropping any pending `same_line`.
+    /// For callers that move the cursor themselves (table cells).
+    pub fn reset_line(&mut self) {
+        self.line_y           = self.cursor.y;
+        self.prev_line_height = 0.0;
+        self.dir              = LayoutDir::Vertical;
+        self.same_line        = false;
+    }
+
     /// Insert blank space.
     pub fn dummy(&mut self, size: Vec2, item_spacing: (f32, f32)) {
         self.place(size, item_spacing);
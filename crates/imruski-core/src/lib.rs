@@ -6,36 +6,96 @@
 //! - `imruski-ultralight`  (Ultralight GPU web renderer)
 //! - `imruski-dx11`        (DirectX 11 game-overlay hook)
 
+pub mod calendar;
+pub mod clipper;
 pub mod context;
+pub mod curve;
+pub mod drag_drop;
 pub mod draw_list;
+pub mod fuzzy;
+pub mod gradient;
 pub mod id;
 pub mod input;
 pub mod layout;
+pub mod logger;
 pub mod renderer;
+pub mod scrolling;
+pub mod selection;
+pub mod settings;
+pub mod shortcuts;
+pub mod sprite;
 pub mod style;
 pub mod ui;
 pub mod widgets;
 
 // ─── re-exports ──────────────────────────────────────────────────────────────
-pub use context::Context;
+pub use calendar::{Date, Time, Weekday};
+pub use clipper::ListClipper;
+pub use context::{Command, Context};
+pub use curve::Curve;
+pub use drag_drop::DragDropPayload;
 pub use draw_list::{DrawCmd, DrawList, DrawVert, TextureId};
+pub use fuzzy::FilterMode;
+pub use gradient::{Gradient, GradientStop};
 pub use id::Id;
-pub use input::{InputState, Key, Modifiers, MouseButton};
+pub use input::{InputState, Key, KeyChord, Modifiers, MouseButton};
 pub use layout::LayoutDir;
+pub use logger::{ImruskiLogger, LogBuffer, LogLine};
 pub use renderer::{Renderer, RenderFrame};
+pub use scrolling::ScrollingBuffer;
+pub use selection::{Selection, SelectionRequest};
+pub use settings::SettingsError;
+pub use shortcuts::Shortcuts;
+pub use sprite::SpriteSheet;
 pub use style::{Style, StyleColor, StyleVar};
 pub use ui::Ui;
+pub use widgets::chart::{AxisScale, PlotAxis, PlotCond, PlotFlags};
+pub use widgets::checkbox::CheckState;
+pub use widgets::color_picker::ColorEditFlags;
+pub use widgets::datetime::DatePickerOptions;
+pub use widgets::file_dialog::{FileDialog, FileDialogMode};
+pub use widgets::hex_editor::{HexEditorOptions, HexHighlight};
+pub use widgets::log_console::LogConsole;
+pub use widgets::node_editor::{NodeEditorFlags, NodeEditorResponse, NodePin};
+pub use widgets::selectable::MultiSelect;
+pub use widgets::slider::{Numeric, SliderFlags};
+pub use widgets::table::{SortDirection, TableColumnFlags, TableColumnSortSpec, TableFlags, TableSortSpecs};
+pub use widgets::tabs::{TabBarFlags, TabItemFlags};
+pub use widgets::timeline::{TimelineClip, TimelineEvent, TimelineKey, TimelineState, TimelineTrack};
+pub use widgets::tree::TreeNodeFlags;
+pub use widgets::code_editor::{ErrorMarker, HighlightSpan, Highlighter, PlainText, SimpleHighlighter};
 
 // ─── Prelude ─────────────────────────────────────────────────────────────────
 pub mod prelude {
     pub use super::{
+        calendar::{Date, Time, Weekday},
+        clipper::ListClipper,
         context::Context,
+        curve::Curve,
         draw_list::TextureId,
+        fuzzy::FilterMode,
+        gradient::{Gradient, GradientStop},
         id::Id,
-        input::{Key, Modifiers, MouseButton},
+        input::{Key, KeyChord, Modifiers, MouseButton},
+        logger::{ImruskiLogger, LogBuffer},
         renderer::Renderer,
+        scrolling::ScrollingBuffer,
+        selection::{Selection, SelectionRequest},
+        sprite::SpriteSheet,
         style::StyleColor,
         ui::Ui,
+        widgets::chart::{AxisScale, PlotAxis, PlotFlags},
+        widgets::checkbox::CheckState,
+        widgets::color_picker::ColorEditFlags,
+        widgets::file_dialog::{FileDialog, FileDialogMode},
+        widgets::hex_editor::{HexEditorOptions, HexHighlight},
+        widgets::log_console::LogConsole,
+        widgets::node_editor::{NodeEditorFlags, NodeEditorResponse, NodePin},
+        widgets::slider::SliderFlags,
+        widgets::table::{TableColumnFlags, TableFlags},
+        widgets::tabs::{TabBarFlags, TabItemFlags},
+        widgets::timeline::{TimelineClip, TimelineEvent, TimelineState, TimelineTrack},
+        widgets::tree::TreeNodeFlags,
         Color, Rect, Vec2, WindowFlags,
     };
 }
@@ -197,6 +257,22 @@ impl Color {
             4 => Self::rgb(t, p, v), _ => Self::rgb(v, p, q),
         }
     }
+
+    /// Convert to HSL (h/s/l all in 0..1).
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (h, _, v) = self.to_hsv();
+        let min = self.r.min(self.g).min(self.b);
+        let l   = (v + min) * 0.5;
+        let s   = if l > 0. && l < 1. { (v - l) / l.min(1. - l) } else { 0. };
+        (h, s, l)
+    }
+
+    /// Create from HSL (all parameters 0..1).
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        let v  = l + s * l.min(1. - l);
+        let sv = if v > 0. { 2. * (1. - l / v) } else { 0. };
+        Self::from_hsv(h, sv, v)
+    }
 }
 
 impl From<[f32; 4]> for Color { fn from([r,g,b,a]: [f32; 4]) -> Self { Self::new(r,g,b,a) } }
@@ -0,0 +1,133 @@
+//! `log` integration: a thread-safe line buffer and a [`log::Log`]
+//! implementation that fills it, for display in a
+//! [`LogConsole`](crate::widgets::log_console::LogConsole).
+//!
+//! ```ignore
+//! let buffer = ImruskiLogger::new(LogBuffer::new(5_000))
+//!     .with_level(log::LevelFilter::Debug)
+//!     .init()?;
+//! let mut console = LogConsole::new(buffer);
+//! // each frame, inside a window:
+//! ui.log_console(&mut console);
+//! ```
+
+use std::{collections::VecDeque, sync::Arc, time::SystemTime};
+
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+use parking_lot::Mutex;
+
+/// One logged message.
+#[derive(Debug, Clone)]
+pub struct LogLine {
+    pub level:   Level,
+    pub target:  String,
+    pub message: String,
+    pub time:    SystemTime,
+}
+
+#[derive(Debug)]
+struct Lines {
+    lines:    VecDeque<LogLine>,
+    capacity: usize,
+    /// Lines ever pushed, including those dropped by the cap.
+    pushed:   u64,
+}
+
+/// Bounded, shared list of log lines; clones refer to the same buffer.
+/// The oldest lines are dropped once `capacity` is reached.
+#[derive(Debug, Clone)]
+pub struct LogBuffer {
+    inner: Arc<Mutex<Lines>>,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self { Self::new(10_000) }
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Lines { lines: VecDeque::new(), capacity: capacity.max(1), pushed: 0 })),
+        }
+    }
+
+    pub fn push(&self, line: LogLine) {
+        let mut inner = self.inner.lock();
+        if inner.lines.len() >= inner.capacity { inner.lines.pop_front(); }
+        inner.lines.push_back(line);
+        inner.pushed += 1;
+    }
+
+    /// Push a message without going through the `log` macros.
+    pub fn push_message(&self, level: Level, target: &str, message: &str) {
+        self.push(LogLine { level, target: target.to_owned(), message: message.to_owned(), time: SystemTime::now() });
+    }
+
+    pub fn clear(&self) { self.inner.lock().lines.clear(); }
+
+    pub fn len(&self)      -> usize { self.inner.lock().lines.len() }
+    pub fn is_empty(&self) -> bool  { self.inner.lock().lines.is_empty() }
+
+    pub fn capacity(&self) -> usize { self.inner.lock().capacity }
+
+    /// Change the cap, dropping the oldest lines if needed.
+    pub fn set_capacity(&self, capacity: usize) {
+        let mut inner = self.inner.lock();
+        inner.capacity = capacity.max(1);
+        while inner.lines.len() > inner.capacity { inner.lines.pop_front(); }
+    }
+
+    /// Number of lines ever pushed.
+    pub fn pushed(&self) -> u64 { self.inner.lock().pushed }
+
+    /// Copies of the lines pushed after the first `seen`, oldest first, and
+    /// the new `pushed` count. Lines already dropped by the cap are skipped.
+    pub fn lines_since(&self, seen: u64) -> (Vec<LogLine>, u64) {
+        let inner = self.inner.lock();
+        let new  = inner.pushed.saturating_sub(seen).min(inner.lines.len() as u64) as usize;
+        let skip = inner.lines.len() - new;
+        (inner.lines.iter().skip(skip).cloned().collect(), inner.pushed)
+    }
+}
+
+/// `log::Log` implementation writing into a [`LogBuffer`].
+#[derive(Debug)]
+pub struct ImruskiLogger {
+    buffer: LogBuffer,
+    level:  LevelFilter,
+}
+
+impl ImruskiLogger {
+    /// Logger accepting every level; narrow it with `with_level`.
+    pub fn new(buffer: LogBuffer) -> Self { Self { buffer, level: LevelFilter::Trace } }
+
+    pub fn with_level(mut self, level: LevelFilter) -> Self { self.level = level; self }
+
+    pub fn buffer(&self) -> &LogBuffer { &self.buffer }
+
+    /// Install as the global logger and set the max level. The logger lives
+    /// for the rest of the program; returns its buffer.
+    pub fn init(self) -> Result<LogBuffer, SetLoggerError> {
+        let buffer = self.buffer.clone();
+        let level  = self.level;
+        log::set_logger(Box::leak(Box::new(self)))?;
+        log::set_max_level(level);
+        Ok(buffer)
+    }
+}
+
+impl Log for ImruskiLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool { metadata.level() <= self.level }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) { return; }
+        self.buffer.push(LogLine {
+            level:   record.level(),
+            target:  record.target().to_owned(),
+            message: record.args().to_string(),
+            time:    SystemTime::now(),
+        });
+    }
+
+    fn flush(&self) {}
+}
@@ -8,6 +8,9 @@ pub struct RenderFrame<'a> {
     pub draw_list:    &'a DrawList,
     pub display_size: Vec2,
     pub scale_factor: f32,
+    /// Text the UI copied this frame, for the backend to put on the system
+    /// clipboard.
+    pub clipboard_text: Option<&'a str>,
 }
 
 /// Font glyph information returned by [`FontAtlas`].
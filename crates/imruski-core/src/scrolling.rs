@@ -0,0 +1,95 @@
+//! Fixed-capacity ring buffer of timestamped samples for live plots.
+//!
+//! ```ignore
+//! let mut frame_ms = ScrollingBuffer::with_channels(2000, &["cpu", "gpu"]);
+//! // every frame:
+//! frame_ms.push_row(ui.time(), &[cpu_ms, gpu_ms]);
+//! ui.plot_realtime("frame time", &frame_ms, 10.0);
+//! ```
+//!
+//! Once full, each push overwrites the oldest sample.
+
+/// Timestamped samples of one or more named channels.
+#[derive(Debug, Clone)]
+pub struct ScrollingBuffer<T> {
+    names:    Vec<String>,
+    times:    Vec<f64>,
+    /// Sample-major: channel `c` of sample `i` is at `i * channels + c`.
+    values:   Vec<T>,
+    capacity: usize,
+    /// Slot of the oldest sample once the buffer is full.
+    head:     usize,
+}
+
+impl<T: Copy + Default> ScrollingBuffer<T> {
+    /// Single-channel buffer holding up to `capacity` samples.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_channels(capacity, &[""])
+    }
+
+    /// Buffer with one channel per name.
+    pub fn with_channels(capacity: usize, names: &[&str]) -> Self {
+        let capacity = capacity.max(1);
+        let names: Vec<String> = if names.is_empty() { vec![String::new()] } else { names.iter().map(|&n| n.to_owned()).collect() };
+        Self {
+            times:  Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity * names.len()),
+            names,
+            capacity,
+            head: 0,
+        }
+    }
+
+    /// Append a sample to the first channel (others get `T::default()`).
+    pub fn push(&mut self, time: f64, value: T) {
+        self.push_row(time, &[value]);
+    }
+
+    /// Append one sample per channel, in channel order. Missing values are
+    /// `T::default()`; extra ones are ignored.
+    pub fn push_row(&mut self, time: f64, values: &[T]) {
+        let n = self.names.len();
+        let row = (0..n).map(|c| values.get(c).copied().unwrap_or_default());
+        if self.times.len() < self.capacity {
+            self.times.push(time);
+            self.values.extend(row);
+        } else {
+            self.times[self.head] = time;
+            for (slot, v) in self.values[self.head * n..(self.head + 1) * n].iter_mut().zip(row) {
+                *slot = v;
+            }
+            self.head = (self.head + 1) % self.capacity;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.times.clear();
+        self.values.clear();
+        self.head = 0;
+    }
+
+    pub fn len(&self)           -> usize { self.times.len() }
+    pub fn is_empty(&self)      -> bool  { self.times.is_empty() }
+    pub fn capacity(&self)      -> usize { self.capacity }
+    pub fn channel_count(&self) -> usize { self.names.len() }
+
+    pub fn channel_name(&self, channel: usize) -> &str {
+        self.names.get(channel).map_or("", |n| n.as_str())
+    }
+
+    /// Time of the newest sample.
+    pub fn last_time(&self) -> Option<f64> {
+        if self.is_empty() { return None; }
+        Some(self.times[(self.head + self.len() - 1) % self.len()])
+    }
+
+    /// `(time, value)` pairs of `channel`, oldest first.
+    pub fn iter(&self, channel: usize) -> impl Iterator<Item = (f64, T)> + '_ {
+        let (n, len) = (self.names.len(), self.len());
+        let channel = channel.min(n - 1);
+        (0..len).map(move |i| {
+            let slot = (self.head + i) % len;
+            (self.times[slot], self.values[slot * n + channel])
+        })
+    }
+}
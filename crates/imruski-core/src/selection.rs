@@ -0,0 +1,54 @@
+//! Multi-selection model shared by `multi_selectable` rows and list boxes.
+//!
+//! The widgets never own the selection. Each frame they emit
+//! [`SelectionRequest`]s which the app applies to whatever it stores –
+//! a flag per item, a database query, or the ready-made [`Selection`] set.
+
+use std::collections::BTreeSet;
+
+/// A change the user asked for. Indices are item positions in the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionRequest {
+    /// Select (`true`) or deselect (`false`) every item.
+    SetAll(bool),
+    /// Set the state of the inclusive range `first..=last`.
+    SetRange { first: usize, last: usize, selected: bool },
+}
+
+/// Basic index-set storage for apps that own a small list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection {
+    items: BTreeSet<usize>,
+}
+
+impl Selection {
+    pub fn new() -> Self { Self::default() }
+
+    /// Apply requests in order; `count` bounds `SetAll(true)`.
+    pub fn apply_requests(&mut self, requests: &[SelectionRequest], count: usize) {
+        for req in requests {
+            match *req {
+                SelectionRequest::SetAll(true)  => self.items = (0..count).collect(),
+                SelectionRequest::SetAll(false) => self.items.clear(),
+                SelectionRequest::SetRange { first, last, selected } => {
+                    let last = last.min(count.saturating_sub(1));
+                    for i in first..=last {
+                        if selected { self.items.insert(i); } else { self.items.remove(&i); }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn contains(&self, index: usize) -> bool { self.items.contains(&index) }
+    pub fn len(&self)      -> usize { self.items.len() }
+    pub fn is_empty(&self) -> bool  { self.items.is_empty() }
+    pub fn clear(&mut self)         { self.items.clear(); }
+
+    pub fn set(&mut self, index: usize, selected: bool) {
+        if selected { self.items.insert(index); } else { self.items.remove(&index); }
+    }
+
+    /// Selected indices in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ { self.items.iter().copied() }
+}
@@ -0,0 +1,220 @@
+//! Persistent UI settings – table column layouts and node editor views –
+//! as INI-style text.
+//!
+//! ```text
+//! [Table][9f86d081884c7d65]
+//! Column 0 Width=120 Visible=1 Order=0 Sort=0^
+//! Column 1 Width=0 Visible=0 Order=2
+//! Column 2 Width=0 Visible=1 Order=1
+//!
+//! [NodeEditor][3c5e0a1b22d4f9e7]
+//! View PanX=-40 PanY=12 Zoom=0.8
+//! Node 1 X=20 Y=20
+//! Node 7 X=260 Y=84.5
+//! ```
+//!
+//! `Width=0` means automatic. `Sort=<priority><^|v>` marks a sort column,
+//! ascending (`^`) or descending (`v`). Node positions are in canvas units.
+
+use crate::{
+    context::Context,
+    id::Id,
+    Vec2,
+    widgets::{
+        node_editor::{NodeEditorState, ZOOM_RANGE},
+        table::{ColumnState, SortDirection, TableState},
+    },
+};
+
+/// Most columns a saved table may have; guards `load_settings` against
+/// corrupt files asking for huge allocations. Wider tables are not saved.
+const MAX_COLUMNS: usize = 64;
+
+/// Error returned by [`Context::load_settings`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SettingsError {
+    #[error("line {line}: unknown section `{section}`")]
+    UnknownSection { line: usize, section: String },
+    #[error("line {line}: malformed entry")]
+    BadEntry { line: usize },
+}
+
+/// A `[Table]` section being parsed.
+struct PendingTable {
+    id:    Id,
+    state: TableState,
+    /// `(display position, column)` pairs.
+    order: Vec<(usize, usize)>,
+    /// `(priority, column, direction)` triples.
+    sort:  Vec<(usize, usize, SortDirection)>,
+}
+
+/// A `[NodeEditor]` section being parsed.
+struct PendingEditor {
+    id:    Id,
+    state: NodeEditorState,
+}
+
+impl Context {
+    /// Serialise table layouts (except `NO_SAVED_SETTINGS` tables) and node
+    /// editor views.
+    pub fn save_settings(&self) -> String {
+        let mut ids: Vec<&Id> = self.tables.iter()
+            .filter(|(_, t)| t.save && !t.columns.is_empty() && t.columns.len() <= MAX_COLUMNS)
+            .map(|(id, _)| id)
+            .collect();
+        ids.sort_by_key(|id| id.0);
+
+        let mut out = String::new();
+        for id in ids {
+            let t = &self.tables[id];
+            out.push_str(&format!("[Table][{:016x}]\n", id.0));
+            for (c, col) in t.columns.iter().enumerate() {
+                let order = t.order.iter().position(|&o| o == c).unwrap_or(c);
+                out.push_str(&format!(
+                    "Column {c} Width={} Visible={} Order={order}",
+                    col.width.round() as i32, col.visible as u8,
+                ));
+                if let Some(p) = t.sort.iter().position(|&(s, _)| s == c) {
+                    let dir = match t.sort[p].1 { SortDirection::Ascending => '^', SortDirection::Descending => 'v' };
+                    out.push_str(&format!(" Sort={p}{dir}"));
+                }
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+
+        let mut ids: Vec<&Id> = self.node_editors.iter()
+            .filter(|(_, e)| !e.positions.is_empty())
+            .map(|(id, _)| id)
+            .collect();
+        ids.sort_by_key(|id| id.0);
+        for id in ids {
+            let e = &self.node_editors[id];
+            out.push_str(&format!("[NodeEditor][{:016x}]\n", id.0));
+            out.push_str(&format!("View PanX={} PanY={} Zoom={}\n", e.pan.x, e.pan.y, e.zoom));
+            for (node, p) in &e.positions {
+                out.push_str(&format!("Node {node} X={} Y={}\n", p.x, p.y));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Merge settings produced by `save_settings`. Tables are matched by ID
+    /// and take effect the next time they are shown with the same column
+    /// count; node editors replace their view and stored node positions.
+    pub fn load_settings(&mut self, text: &str) -> Result<(), SettingsError> {
+        let mut table: Option<PendingTable> = None;
+        let mut editor: Option<PendingEditor> = None;
+        let mut done = Vec::new();
+        let mut editors = Vec::new();
+
+        for (i, raw) in text.lines().enumerate() {
+            let line = raw.trim();
+            let n = i + 1;
+            if line.is_empty() || line.starts_with(';') || line.starts_with('#') { continue; }
+
+            if let Some(rest) = line.strip_prefix('[') {
+                done.extend(table.take());
+                editors.extend(editor.take());
+                let (kind, rest) = rest.split_once("][").ok_or(SettingsError::BadEntry { line: n })?;
+                let hex = rest.strip_suffix(']').ok_or(SettingsError::BadEntry { line: n })?;
+                let id  = Id(u64::from_str_radix(hex, 16).map_err(|_| SettingsError::BadEntry { line: n })?);
+                match kind {
+                    "Table"      => table  = Some(PendingTable { id, state: TableState::default(), order: Vec::new(), sort: Vec::new() }),
+                    "NodeEditor" => editor = Some(PendingEditor { id, state: NodeEditorState::default() }),
+                    _ => return Err(SettingsError::UnknownSection { line: n, section: kind.to_owned() }),
+                }
+                continue;
+            }
+
+            if let Some(PendingEditor { state, .. }) = editor.as_mut() {
+                parse_editor_line(state, line).ok_or(SettingsError::BadEntry { line: n })?;
+                continue;
+            }
+
+            let Some(PendingTable { state, order, sort, .. }) = table.as_mut() else { return Err(SettingsError::BadEntry { line: n }) };
+            let mut words = line.split_whitespace();
+            if words.next() != Some("Column") { return Err(SettingsError::BadEntry { line: n }); }
+            let c: usize = words.next()
+                .and_then(|w| w.parse().ok())
+                .filter(|&c| c < MAX_COLUMNS)
+                .ok_or(SettingsError::BadEntry { line: n })?;
+            let mut col = ColumnState { width: 0.0, visible: true };
+            let mut pos = c;
+            for w in words {
+                let (key, val) = w.split_once('=').ok_or(SettingsError::BadEntry { line: n })?;
+                let bad = || SettingsError::BadEntry { line: n };
+                match key {
+                    // NaN / infinite / negative widths keep the automatic default
+                    "Width"   => {
+                        let w: f32 = val.parse().map_err(|_| bad())?;
+                        if w.is_finite() && w >= 0.0 { col.width = w; }
+                    }
+                    "Visible" => col.visible = val != "0",
+                    "Order"   => pos         = val.parse().map_err(|_| bad())?,
+                    "Sort"    => {
+                        let (p, dir) = val.split_at(val.len().saturating_sub(1));
+                        let dir = match dir { "^" => SortDirection::Ascending, "v" => SortDirection::Descending, _ => return Err(bad()) };
+                        sort.push((p.parse().map_err(|_| bad())?, c, dir));
+                    }
+                    _ => {}
+                }
+            }
+            if state.columns.len() <= c { state.columns.resize(c + 1, ColumnState::default()); }
+            state.columns[c] = col;
+            order.push((pos, c));
+        }
+        done.extend(table.take());
+        editors.extend(editor.take());
+
+        for PendingTable { id, mut state, mut order, mut sort } in done {
+            order.sort_by_key(|&(pos, _)| pos);
+            state.order = order.into_iter().map(|(_, c)| c).collect();
+            let mut seen = state.order.clone();
+            seen.sort_unstable();
+            seen.dedup();
+            if seen.len() != state.columns.len() || state.order.len() != state.columns.len() {
+                state.order = (0..state.columns.len()).collect();
+            }
+            sort.sort_by_key(|&(p, _, _)| p);
+            state.sort   = sort.into_iter().map(|(_, c, d)| (c, d)).collect();
+            state.loaded = true;
+            state.save   = true;
+            self.tables.insert(id, state);
+        }
+        for PendingEditor { id, state } in editors {
+            let e = self.node_editors.entry(id).or_default();
+            e.pan       = state.pan;
+            e.zoom      = state.zoom;
+            e.positions = state.positions;
+        }
+        Ok(())
+    }
+}
+
+/// One `View …` or `Node <id> …` line; `None` if malformed.
+fn parse_editor_line(state: &mut NodeEditorState, line: &str) -> Option<()> {
+    let mut words = line.split_whitespace();
+    let node = match words.next()? {
+        "View" => None,
+        "Node" => Some(words.next()?.parse::<u64>().ok()?),
+        _      => return None,
+    };
+    let mut pos = Vec2::ZERO;
+    for w in words {
+        let (key, val) = w.split_once('=')?;
+        let val: f32 = val.parse().ok().filter(|v: &f32| v.is_finite())?;
+        match (node, key) {
+            (None, "PanX") => state.pan.x = val,
+            (None, "PanY") => state.pan.y = val,
+            (None, "Zoom") => state.zoom  = val.clamp(ZOOM_RANGE.0, ZOOM_RANGE.1),
+            (Some(_), "X") => pos.x = val,
+            (Some(_), "Y") => pos.y = val,
+            _ => {}
+        }
+    }
+    if let Some(id) = node { state.positions.insert(id, pos); }
+    Some(())
+}
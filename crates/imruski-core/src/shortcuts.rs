@@ -0,0 +1,166 @@
+//! Named keyboard shortcuts – bindings, conflict detection and text persistence.
+//!
+//! A binding maps an action name to a [`KeyChord`]. Bindings are either
+//! global or scoped to a window (by its title ID); when a window-scoped
+//! binding and a global one share a chord, the scoped one wins while that
+//! window has focus.
+//!
+//! Text format, one binding per line (lines starting with `#` are comments):
+//!
+//! ```text
+//! toggle_menu = Insert
+//! save        = Ctrl+S
+//! run         = F5 @ Script Editor
+//! ```
+
+use crate::{
+    id::{parse_label, Id},
+    input::{InputState, KeyChord},
+};
+
+/// One action → chord binding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortcut {
+    pub action: String,
+    pub chord:  KeyChord,
+    /// Window title (as passed to `begin`) the binding is limited to, or
+    /// `None` for a global binding.
+    pub window: Option<String>,
+}
+
+impl Shortcut {
+    fn window_id(&self) -> Option<Id> {
+        self.window.as_deref().map(|w| Id::from_str(parse_label(w).1))
+    }
+}
+
+/// Two bindings that would fire on the same chord in the same scope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortcutConflict {
+    pub first:  String,
+    pub second: String,
+    pub chord:  KeyChord,
+}
+
+/// Error returned by [`Shortcuts::load`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ShortcutParseError {
+    #[error("line {line}: expected `action = chord`")]
+    MissingEquals { line: usize },
+    #[error("line {line}: {source}")]
+    BadChord { line: usize, source: crate::input::ParseKeyChordError },
+}
+
+/// Registry of named shortcuts. Lives on [`Context`](crate::Context).
+#[derive(Debug, Clone, Default)]
+pub struct Shortcuts {
+    bindings: Vec<Shortcut>,
+}
+
+impl Shortcuts {
+    pub fn new() -> Self { Self::default() }
+
+    /// Bind `action` globally, replacing any previous global binding for it.
+    pub fn bind(&mut self, action: &str, chord: KeyChord) {
+        self.insert(Shortcut { action: action.to_owned(), chord, window: None });
+    }
+
+    /// Bind `action` only while the window titled `window` has focus.
+    pub fn bind_in_window(&mut self, action: &str, chord: KeyChord, window: &str) {
+        self.insert(Shortcut { action: action.to_owned(), chord, window: Some(window.to_owned()) });
+    }
+
+    fn insert(&mut self, sc: Shortcut) {
+        match self.bindings.iter_mut().find(|b| b.action == sc.action && b.window == sc.window) {
+            Some(existing) => *existing = sc,
+            None           => self.bindings.push(sc),
+        }
+    }
+
+    /// Remove every binding for `action`.
+    pub fn unbind(&mut self, action: &str) {
+        self.bindings.retain(|b| b.action != action);
+    }
+
+    pub fn clear(&mut self) { self.bindings.clear(); }
+
+    /// The chord of the first binding for `action`.
+    pub fn chord(&self, action: &str) -> Option<KeyChord> {
+        self.bindings.iter().find(|b| b.action == action).map(|b| b.chord)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Shortcut> { self.bindings.iter() }
+
+    /// Actions (other than `action`) bound to `chord` in the same scope.
+    pub fn conflicts_with(&self, action: &str, chord: KeyChord, window: Option<&str>) -> Vec<&str> {
+        self.bindings.iter()
+            .filter(|b| b.action != action && b.chord == chord && b.window.as_deref() == window)
+            .map(|b| b.action.as_str())
+            .collect()
+    }
+
+    /// Every pair of bindings that share a chord and a scope.
+    pub fn conflicts(&self) -> Vec<ShortcutConflict> {
+        let mut out = Vec::new();
+        for (i, a) in self.bindings.iter().enumerate() {
+            for b in &self.bindings[i + 1..] {
+                if a.chord == b.chord && a.window == b.window && a.action != b.action {
+                    out.push(ShortcutConflict { first: a.action.clone(), second: b.action.clone(), chord: a.chord });
+                }
+            }
+        }
+        out
+    }
+
+    /// `true` if `action` fired this frame, given the focused window.
+    ///
+    /// A global binding is shadowed while the focused window has its own
+    /// binding on the same chord.
+    pub(crate) fn pressed(&self, action: &str, input: &InputState, focused_window: Option<Id>) -> bool {
+        self.bindings.iter()
+            .filter(|b| b.action == action && b.chord.pressed(input))
+            .any(|b| match b.window_id() {
+                Some(w) => focused_window == Some(w),
+                None    => !self.bindings.iter().any(|o| {
+                    o.chord == b.chord && o.window_id().is_some() && o.window_id() == focused_window
+                }),
+            })
+    }
+
+    /// Serialise to the line-based text format.
+    pub fn save(&self) -> String {
+        let mut out = String::new();
+        for b in &self.bindings {
+            out.push_str(&b.action);
+            out.push_str(" = ");
+            out.push_str(&b.chord.to_string());
+            if let Some(w) = &b.window {
+                out.push_str(" @ ");
+                out.push_str(w);
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Merge bindings from the text format into the registry. Nothing is
+    /// merged if any line fails to parse.
+    pub fn load(&mut self, text: &str) -> Result<(), ShortcutParseError> {
+        let mut parsed = Vec::new();
+        for (i, raw) in text.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') { continue; }
+            let (action, rest) = line.split_once('=')
+                .ok_or(ShortcutParseError::MissingEquals { line: i + 1 })?;
+            let (chord, window) = match rest.split_once('@') {
+                Some((c, w)) => (c, Some(w.trim().to_owned())),
+                None         => (rest, None),
+            };
+            let chord = chord.trim().parse::<KeyChord>()
+                .map_err(|source| ShortcutParseError::BadChord { line: i + 1, source })?;
+            parsed.push(Shortcut { action: action.trim().to_owned(), chord, window });
+        }
+        for sc in parsed { self.insert(sc); }
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+//! Sprite sheets: UV rects for the grid frames and named regions of one
+//! texture, ready for `image_ex` / `image_button_ex`.
+//!
+//! ```ignore
+//! let mut icons = SpriteSheet::grid(atlas, Vec2::new(256.0, 256.0), Vec2::splat(32.0));
+//! icons.add_region("folder", Rect::from_min_size(Vec2::new(0.0, 224.0), Vec2::splat(32.0)));
+//! let (uv0, uv1) = icons.region_uv("folder").unwrap();
+//! ui.image_ex(icons.texture(), Vec2::splat(16.0), uv0, uv1, Color::WHITE, Color::TRANSPARENT);
+//! ```
+
+use crate::{draw_list::TextureId, Rect, Vec2};
+
+/// A texture cut into equal frames (row-major, left to right) and/or
+/// named pixel regions.
+#[derive(Debug, Clone)]
+pub struct SpriteSheet {
+    texture:    TextureId,
+    /// Texture size in pixels.
+    size:       Vec2,
+    frame_size: Vec2,
+    /// Border around the whole grid and gap between frames, in pixels.
+    margin:     Vec2,
+    spacing:    Vec2,
+    regions:    Vec<(String, Rect)>,
+}
+
+impl SpriteSheet {
+    /// Sheet without a frame grid; add named regions with `add_region`.
+    pub fn new(texture: TextureId, texture_size: Vec2) -> Self {
+        Self::grid(texture, texture_size, Vec2::ZERO)
+    }
+
+    /// Sheet cut into `frame_size` frames.
+    pub fn grid(texture: TextureId, texture_size: Vec2, frame_size: Vec2) -> Self {
+        Self {
+            texture,
+            size:    texture_size,
+            frame_size,
+            margin:  Vec2::ZERO,
+            spacing: Vec2::ZERO,
+            regions: Vec::new(),
+        }
+    }
+
+    /// Skip `margin` pixels around the grid.
+    pub fn with_margin(mut self, margin: Vec2) -> Self { self.margin = margin; self }
+    /// Leave `spacing` pixels between frames.
+    pub fn with_spacing(mut self, spacing: Vec2) -> Self { self.spacing = spacing; self }
+
+    pub fn texture(&self)    -> TextureId { self.texture }
+    pub fn frame_size(&self) -> Vec2      { self.frame_size }
+
+    /// Frames per row and per column.
+    pub fn grid_dims(&self) -> (usize, usize) {
+        let fit = |total: f32, margin: f32, frame: f32, gap: f32| {
+            if frame <= 0.0 { 0 } else { ((total - margin * 2.0 + gap) / (frame + gap)).floor().max(0.0) as usize }
+        };
+        (
+            fit(self.size.x, self.margin.x, self.frame_size.x, self.spacing.x),
+            fit(self.size.y, self.margin.y, self.frame_size.y, self.spacing.y),
+        )
+    }
+
+    pub fn frame_count(&self) -> usize {
+        let (cols, rows) = self.grid_dims();
+        cols * rows
+    }
+
+    /// Pixel rect of frame `index`.
+    pub fn frame_rect(&self, index: usize) -> Option<Rect> {
+        let (cols, _) = self.grid_dims();
+        if index >= self.frame_count() { return None; }
+        let (col, row) = (index % cols, index / cols);
+        let min = Vec2::new(
+            self.margin.x + col as f32 * (self.frame_size.x + self.spacing.x),
+            self.margin.y + row as f32 * (self.frame_size.y + self.spacing.y),
+        );
+        Some(Rect::from_min_size(min, self.frame_size))
+    }
+
+    /// `(uv0, uv1)` of frame `index`.
+    pub fn frame_uv(&self, index: usize) -> Option<(Vec2, Vec2)> {
+        self.frame_rect(index).map(|r| self.uv_of(r))
+    }
+
+    /// Frame of a looping animation at `time` seconds, `fps` frames per
+    /// second.
+    pub fn frame_at(&self, time: f64, fps: f32) -> usize {
+        let n = self.frame_count();
+        if n == 0 || fps <= 0.0 { return 0; }
+        ((time.max(0.0) * fps as f64) as usize) % n
+    }
+
+    /// Name a pixel rect; replaces an existing region of the same name.
+    pub fn add_region(&mut self, name: &str, rect: Rect) {
+        match self.regions.iter_mut().find(|(n, _)| n == name) {
+            Some(r) => r.1 = rect,
+            None    => self.regions.push((name.to_owned(), rect)),
+        }
+    }
+
+    /// Pixel rect of the named region.
+    pub fn region(&self, name: &str) -> Option<Rect> {
+        self.regions.iter().find(|(n, _)| n == name).map(|r| r.1)
+    }
+
+    /// `(uv0, uv1)` of the named region.
+    pub fn region_uv(&self, name: &str) -> Option<(Vec2, Vec2)> {
+        self.region(name).map(|r| self.uv_of(r))
+    }
+
+    /// Region names in insertion order.
+    pub fn region_names(&self) -> impl Iterator<Item = &str> {
+        self.regions.iter().map(|(n, _)| n.as_str())
+    }
+
+    fn uv_of(&self, r: Rect) -> (Vec2, Vec2) {
+        let s = Vec2::new(self.size.x.max(1.0), self.size.y.max(1.0));
+        (Vec2::new(r.min.x / s.x, r.min.y / s.y), Vec2::new(r.max.x / s.x, r.max.y / s.y))
+    }
+}
@@ -41,8 +41,18 @@ pub enum StyleColor {
     ResizeGripHovered,
     ResizeGripActive,
     PlotLines,
+    PlotLinesHovered,
     PlotHistogram,
+    PlotHistogramHovered,
+    PlotBg,
+    PlotGrid,
+    PlotSelection,
     ProgressBar,
+    TableHeaderBg,
+    TableBorderStrong,
+    TableBorderLight,
+    TableRowBg,
+    TableRowBgAlt,
     // Sentinel – always last
     COUNT,
 }
@@ -125,9 +135,19 @@ impl Style {
         colors[SC::ResizeGrip    as usize] = Color::from_hex(0x1e6bb5).with_alpha(0.4);
         colors[SC::ResizeGripHovered as usize] = Color::from_hex(0x4db5ff).with_alpha(0.6);
         colors[SC::ResizeGripActive  as usize] = Color::from_hex(0x4db5ff).with_alpha(0.9);
-        colors[SC::PlotLines     as usize] = Color::from_hex(0x9a9a9a);
-        colors[SC::PlotHistogram as usize] = Color::from_hex(0xe6b400);
+        colors[SC::PlotLines            as usize] = Color::from_hex(0x9a9a9a);
+        colors[SC::PlotLinesHovered     as usize] = Color::from_hex(0xff6e59);
+        colors[SC::PlotHistogram        as usize] = Color::from_hex(0xe6b400);
+        colors[SC::PlotHistogramHovered as usize] = Color::from_hex(0xff9900);
+        colors[SC::PlotBg               as usize] = Color::from_hex(0x1a1a1d);
+        colors[SC::PlotGrid             as usize] = Color::WHITE.with_alpha(0.12);
+        colors[SC::PlotSelection        as usize] = Color::from_hex(0xffcc00);
         colors[SC::ProgressBar   as usize] = Color::from_hex(0x1e6bb5);
+        colors[SC::TableHeaderBg     as usize] = Color::from_hex(0x303033);
+        colors[SC::TableBorderStrong as usize] = Color::from_hex(0x4f4f59);
+        colors[SC::TableBorderLight  as usize] = Color::from_hex(0x3b3b40);
+        colors[SC::TableRowBg        as usize] = Color::TRANSPARENT;
+        colors[SC::TableRowBgAlt     as usize] = Color::WHITE.with_alpha(0.06);
         Self {
             colors,
             window_padding:      (8.0, 8.0),
@@ -154,6 +174,11 @@ impl Style {
         s.colors[SC::Text       as usize] = Color::BLACK;
         s.colors[SC::FrameBg    as usize] = Color::from_hex(0xdedede);
         s.colors[SC::Button     as usize] = Color::from_hex(0x4293d1);
+        s.colors[SC::TableHeaderBg as usize] = Color::from_hex(0xc7dded);
+        s.colors[SC::TableRowBgAlt as usize] = Color::BLACK.with_alpha(0.04);
+        s.colors[SC::PlotBg        as usize] = Color::from_hex(0xfafafa);
+        s.colors[SC::PlotGrid      as usize] = Color::BLACK.with_alpha(0.12);
+        s.colors[SC::PlotSelection as usize] = Color::from_hex(0xd18f00);
         s
     }
 
@@ -5,6 +5,7 @@
 use crate::{
     context::{Context, WindowFrame, WindowState},
     draw_list::TextureId,
+    fuzzy::FilterMode,
     id::{parse_label, Id},
     layout::Layout,
     renderer::FontAtlas,
@@ -83,8 +84,15 @@ impl<'ctx> Ui<'ctx> {
 
     pub fn display_size(&self) -> Vec2  { self.ctx.input.display_size }
     pub fn delta_time(&self)   -> f32   { self.ctx.delta_time }
+    /// Seconds since the first frame; the clock `plot_realtime` follows.
+    pub fn time(&self)         -> f64   { self.ctx.input.time }
     pub fn frame_count(&self)  -> u64   { self.ctx.input.frame_count }
 
+    // ── Clipboard ─────────────────────────────────────────────────────────────
+
+    /// Hand `text` to the backend for the system clipboard.
+    pub fn set_clipboard_text(&mut self, text: &str) { self.ctx.set_clipboard_text(text); }
+
     // ── ID helpers ────────────────────────────────────────────────────────────
 
     pub fn push_id_str(&mut self, s: &str) { self.ctx.push_id_str(s); }
@@ -172,6 +180,23 @@ impl<'ctx> Ui<'ctx> {
         crate::widgets::checkbox::checkbox(self, label, v)
     }
 
+    pub fn checkbox_tristate(&mut self, label: &str, state: &mut crate::widgets::checkbox::CheckState) -> bool {
+        crate::widgets::checkbox::checkbox_tristate(self, label, state)
+    }
+
+    /// Checkbox for the bits of `flag` within `flags`.
+    pub fn checkbox_flags<F: bitflags::Flags + Copy>(&mut self, label: &str, flags: &mut F, flag: F) -> bool {
+        crate::widgets::checkbox::checkbox_flags(self, label, flags, flag)
+    }
+
+    pub fn radio_button<T: PartialEq + Clone>(&mut self, label: &str, value: &mut T, this_value: T) -> bool {
+        crate::widgets::checkbox::radio_button(self, label, value, this_value)
+    }
+
+    pub fn toggle(&mut self, label: &str, v: &mut bool) -> bool {
+        crate::widgets::checkbox::toggle(self, label, v)
+    }
+
     // ── Slider ───────────────────────────────────────────────────────────────
 
     pub fn slider_float(&mut self, label: &str, v: &mut f32, min: f32, max: f32) -> bool {
@@ -179,19 +204,58 @@ impl<'ctx> Ui<'ctx> {
     }
 
     pub fn slider_float2(&mut self, label: &str, v: &mut [f32; 2], min: f32, max: f32) -> bool {
-        let mut changed = false;
-        self.push_id_str("__x"); changed |= self.slider_float("X", &mut v[0], min, max); self.pop_id();
-        self.same_line(4.0);
-        self.push_id_str("__y"); changed |= self.slider_float("Y", &mut v[1], min, max); self.pop_id();
-        self.text(label);
-        changed
+        self.slider_scalar_n(label, v, min, max, "%.3f", crate::widgets::slider::SliderFlags::empty())
     }
 
     pub fn slider_int(&mut self, label: &str, v: &mut i32, min: i32, max: i32) -> bool {
-        let mut vf = *v as f32;
-        let changed = self.slider_float(label, &mut vf, min as f32, max as f32);
-        if changed { *v = vf.round() as i32; }
-        changed
+        self.slider_scalar(label, v, min, max, "%d", crate::widgets::slider::SliderFlags::empty())
+    }
+
+    /// Slider for any [`Numeric`](crate::widgets::slider::Numeric) type with
+    /// a printf-style `format` such as `"%.2f ms"` or `"%d"`.
+    pub fn slider_scalar<T: crate::widgets::slider::Numeric>(
+        &mut self,
+        label:  &str,
+        v:      &mut T,
+        min:    T,
+        max:    T,
+        format: &str,
+        flags:  crate::widgets::slider::SliderFlags,
+    ) -> bool {
+        crate::widgets::slider::slider_scalar(self, label, v, min, max, format, flags)
+    }
+
+    /// One slider per element of `v` on a single row, tagged X/Y/Z/W.
+    pub fn slider_scalar_n<T: crate::widgets::slider::Numeric>(
+        &mut self,
+        label:  &str,
+        v:      &mut [T],
+        min:    T,
+        max:    T,
+        format: &str,
+        flags:  crate::widgets::slider::SliderFlags,
+    ) -> bool {
+        crate::widgets::slider::slider_scalar_n(self, label, v, min, max, format, flags)
+    }
+
+    /// Vertical slider of `size`, minimum at the bottom.
+    #[allow(clippy::too_many_arguments)]
+    pub fn v_slider<T: crate::widgets::slider::Numeric>(
+        &mut self,
+        label:  &str,
+        size:   Vec2,
+        v:      &mut T,
+        min:    T,
+        max:    T,
+        format: &str,
+        flags:  crate::widgets::slider::SliderFlags,
+    ) -> bool {
+        crate::widgets::slider::v_slider(self, label, size, v, min, max, format, flags)
+    }
+
+    /// Edit an angle stored in radians, shown in degrees.
+    pub fn slider_angle(&mut self, label: &str, v_rad: &mut f32, min_deg: f32, max_deg: f32) -> bool {
+        crate::widgets::slider::slider_angle(self, label, v_rad, min_deg, max_deg)
     }
 
     pub fn drag_float(&mut self, label: &str, v: &mut f32, speed: f32, min: f32, max: f32) -> bool {
@@ -199,10 +263,59 @@ impl<'ctx> Ui<'ctx> {
     }
 
     pub fn drag_int(&mut self, label: &str, v: &mut i32, speed: f32, min: i32, max: i32) -> bool {
-        let mut vf = *v as f32;
-        let c = self.drag_float(label, &mut vf, speed, min as f32, max as f32);
-        if c { *v = vf.round() as i32; }
-        c
+        self.drag_scalar(label, v, speed, min, max, "%d")
+    }
+
+    /// Drag box for any [`Numeric`](crate::widgets::slider::Numeric) type.
+    /// Shift drags 10× faster, Alt 10× slower; `min == max` is unbounded.
+    pub fn drag_scalar<T: crate::widgets::slider::Numeric>(
+        &mut self,
+        label:  &str,
+        v:      &mut T,
+        speed:  f32,
+        min:    T,
+        max:    T,
+        format: &str,
+    ) -> bool {
+        crate::widgets::slider::drag_scalar(self, label, v, speed, min, max, format)
+    }
+
+    /// One drag box per element of `v` on a single row, tagged X/Y/Z/W.
+    pub fn drag_scalar_n<T: crate::widgets::slider::Numeric>(
+        &mut self,
+        label:  &str,
+        v:      &mut [T],
+        speed:  f32,
+        min:    T,
+        max:    T,
+        format: &str,
+    ) -> bool {
+        crate::widgets::slider::drag_scalar_n(self, label, v, speed, min, max, format)
+    }
+
+    /// Drag a `v_min..=v_max` pair; the ends can't cross.
+    pub fn drag_float_range2(&mut self, label: &str, v_min: &mut f32, v_max: &mut f32, speed: f32, min: f32, max: f32) -> bool {
+        crate::widgets::slider::drag_float_range2(self, label, v_min, v_max, speed, min, max)
+    }
+
+    // ── Hotkeys / shortcuts ──────────────────────────────────────────────────
+
+    /// Click to record the next key combination into `chord`.
+    pub fn hotkey_input(&mut self, label: &str, chord: &mut crate::input::KeyChord) -> bool {
+        crate::widgets::hotkey::hotkey_input(self, label, chord)
+    }
+
+    /// See [`Context::shortcut_pressed`].
+    pub fn shortcut_pressed(&self, action: &str) -> bool {
+        self.ctx.shortcut_pressed(action)
+    }
+
+    // ── Command palette ──────────────────────────────────────────────────────
+
+    /// Centered fuzzy-search popup over `Context::register_command` entries.
+    /// Returns the chosen command's ID (and sets `*open = false`).
+    pub fn command_palette(&mut self, open: &mut bool) -> Option<Id> {
+        crate::widgets::command_palette::command_palette(self, open)
     }
 
     // ── Input text ───────────────────────────────────────────────────────────
@@ -216,12 +329,46 @@ impl<'ctx> Ui<'ctx> {
         crate::widgets::input_text::input_text(self, label, buf, true)
     }
 
+    // ── Code editor ──────────────────────────────────────────────────────────
+
+    /// Monospace multi-line editor with line numbers and syntax colouring.
+    pub fn code_editor(
+        &mut self,
+        id: &str,
+        buf: &mut String,
+        highlighter: &dyn crate::widgets::code_editor::Highlighter,
+    ) -> bool {
+        crate::widgets::code_editor::code_editor(self, id, buf, highlighter)
+    }
+
+    /// Code editor with explicit size (`Vec2::ZERO` → auto) and per-line error markers.
+    pub fn code_editor_ex(
+        &mut self,
+        id: &str,
+        buf: &mut String,
+        highlighter: &dyn crate::widgets::code_editor::Highlighter,
+        size: Vec2,
+        markers: &[crate::widgets::code_editor::ErrorMarker<'_>],
+    ) -> bool {
+        crate::widgets::code_editor::code_editor_ex(self, id, buf, highlighter, size, markers)
+    }
+
     // ── Combo ────────────────────────────────────────────────────────────────
 
     pub fn combo(&mut self, label: &str, selected: &mut usize, items: &[&str]) -> bool {
         crate::widgets::combo::combo(self, label, selected, items)
     }
 
+    /// Combo whose popup has a type-to-filter field; matched characters are highlighted.
+    pub fn combo_filtered(&mut self, label: &str, selected: &mut usize, items: &[&str], mode: FilterMode) -> bool {
+        crate::widgets::combo::combo_filtered(self, label, selected, items, mode)
+    }
+
+    /// Text input that suggests matching `items` in a popup while typing.
+    pub fn input_with_completion(&mut self, label: &str, buf: &mut String, items: &[&str], mode: FilterMode) -> bool {
+        crate::widgets::combo::input_with_completion(self, label, buf, items, mode)
+    }
+
     // ── Color edit ───────────────────────────────────────────────────────────
 
     pub fn color_edit3(&mut self, label: &str, color: &mut [f32; 3]) -> bool {
@@ -235,10 +382,152 @@ impl<'ctx> Ui<'ctx> {
         crate::widgets::color_picker::color_edit4(self, label, color, true)
     }
 
+    /// RGBA editor in the mode chosen by `flags`; right-click the inputs to
+    /// switch between RGB, HSV, HSL and hex.
+    pub fn color_edit4_ex(&mut self, label: &str, color: &mut [f32; 4], flags: crate::widgets::color_picker::ColorEditFlags) -> bool {
+        crate::widgets::color_picker::color_edit4_ex(self, label, color, flags)
+    }
+
     pub fn color_picker4(&mut self, label: &str, color: &mut [f32; 4]) -> bool {
         crate::widgets::color_picker::color_picker4(self, label, color)
     }
 
+    pub fn color_picker4_ex(&mut self, label: &str, color: &mut [f32; 4], flags: crate::widgets::color_picker::ColorEditFlags) -> bool {
+        crate::widgets::color_picker::color_picker4_ex(self, label, color, flags)
+    }
+
+    /// Colour swatch; `true` when clicked. Drag it onto a colour editor.
+    pub fn color_button(&mut self, desc_id: &str, color: [f32; 4], size: Vec2, flags: crate::widgets::color_picker::ColorEditFlags) -> bool {
+        crate::widgets::color_picker::color_button(self, desc_id, color, size, flags)
+    }
+
+    // ── Date / time ──────────────────────────────────────────────────────────
+
+    /// Date field with a month-grid popup.
+    pub fn date_picker(&mut self, label: &str, date: &mut crate::calendar::Date) -> bool {
+        crate::widgets::datetime::date_picker(self, label, date)
+    }
+
+    /// `date_picker` with min / max bounds and a week start.
+    pub fn date_picker_ex(&mut self, label: &str, date: &mut crate::calendar::Date, opts: &crate::widgets::datetime::DatePickerOptions) -> bool {
+        crate::widgets::datetime::date_picker_ex(self, label, date, opts)
+    }
+
+    /// Hour / minute / second spinners.
+    pub fn time_input(&mut self, label: &str, time: &mut crate::calendar::Time) -> bool {
+        crate::widgets::datetime::time_input(self, label, time)
+    }
+
+    // ── File dialog ──────────────────────────────────────────────────────────
+
+    /// Draw `dialog` while it is open; the chosen paths on the frame the
+    /// user confirms.
+    pub fn file_dialog(&mut self, dialog: &mut crate::widgets::file_dialog::FileDialog) -> Option<Vec<std::path::PathBuf>> {
+        dialog.show(self)
+    }
+
+    // ── Hex editor ───────────────────────────────────────────────────────────
+
+    /// Hex / character view of `data` with addresses from `base_addr`;
+    /// `true` when a byte was edited.
+    pub fn hex_editor(&mut self, str_id: &str, data: &mut [u8], base_addr: u64) -> bool {
+        crate::widgets::hex_editor::hex_editor(self, str_id, data, base_addr)
+    }
+
+    pub fn hex_editor_ex(&mut self, str_id: &str, data: &mut [u8], base_addr: u64, opts: &crate::widgets::hex_editor::HexEditorOptions) -> bool {
+        crate::widgets::hex_editor::hex_editor_ex(self, str_id, data, base_addr, opts)
+    }
+
+    // ── Log console ──────────────────────────────────────────────────────────
+
+    /// Draw `console`, filling the rest of the current window.
+    pub fn log_console(&mut self, console: &mut crate::widgets::log_console::LogConsole) {
+        console.show(self);
+    }
+
+    // ── Node editor ──────────────────────────────────────────────────────────
+
+    /// Start a node graph canvas with a minimap; `size` components `<= 0`
+    /// fill the available space. Submit nodes and links, then call
+    /// `end_node_editor`.
+    pub fn begin_node_editor(&mut self, str_id: &str, size: Vec2) {
+        crate::widgets::node_editor::begin_node_editor(self, str_id, size);
+    }
+
+    pub fn begin_node_editor_ex(&mut self, str_id: &str, size: Vec2, flags: crate::widgets::node_editor::NodeEditorFlags) {
+        crate::widgets::node_editor::begin_node_editor_ex(self, str_id, size, flags);
+    }
+
+    /// Lay out, draw and handle input for the submitted graph. New links
+    /// must join pins of the same `kind`.
+    pub fn end_node_editor(&mut self) -> crate::widgets::node_editor::NodeEditorResponse {
+        crate::widgets::node_editor::end_node_editor(self)
+    }
+
+    /// Like `end_node_editor`, with `validate(output, input)` deciding which
+    /// links may be drawn.
+    pub fn end_node_editor_with(
+        &mut self,
+        validate: impl Fn(&crate::widgets::node_editor::NodePin, &crate::widgets::node_editor::NodePin) -> bool,
+    ) -> crate::widgets::node_editor::NodeEditorResponse {
+        crate::widgets::node_editor::end_node_editor_with(self, validate)
+    }
+
+    pub fn begin_node(&mut self, id: u64, title: &str) {
+        crate::widgets::node_editor::begin_node(self, id, title);
+    }
+
+    pub fn node_input(&mut self, id: u64, label: &str, kind: u32) {
+        crate::widgets::node_editor::node_input(self, id, label, kind);
+    }
+
+    pub fn node_output(&mut self, id: u64, label: &str, kind: u32) {
+        crate::widgets::node_editor::node_output(self, id, label, kind);
+    }
+
+    pub fn end_node(&mut self) {
+        crate::widgets::node_editor::end_node(self);
+    }
+
+    pub fn node_link(&mut self, id: u64, from: u64, to: u64) {
+        crate::widgets::node_editor::node_link(self, id, from, to);
+    }
+
+    pub fn set_node_position(&mut self, id: u64, pos: Vec2) {
+        crate::widgets::node_editor::set_node_position(self, id, pos);
+    }
+
+    pub fn node_position(&self, id: u64) -> Option<Vec2> {
+        crate::widgets::node_editor::node_position(self, id)
+    }
+
+    // ── Timeline ─────────────────────────────────────────────────────────────
+
+    /// Tracks of clips and keyframes with a scrubbing playhead; edits are
+    /// applied to `state` and returned as events.
+    pub fn timeline(&mut self, str_id: &str, state: &mut crate::widgets::timeline::TimelineState) -> Vec<crate::widgets::timeline::TimelineEvent> {
+        crate::widgets::timeline::timeline(self, str_id, state)
+    }
+
+    // ── Gradient / curve ─────────────────────────────────────────────────────
+
+    /// Gradient bar with editable stops; `true` when the gradient changed.
+    pub fn gradient_edit(&mut self, str_id: &str, gradient: &mut crate::gradient::Gradient) -> bool {
+        crate::widgets::gradient::gradient_edit(self, str_id, gradient)
+    }
+
+    /// Easing-curve canvas with draggable points; Ctrl snaps to the grid.
+    pub fn curve_edit(&mut self, str_id: &str, curve: &mut crate::curve::Curve) -> bool {
+        crate::widgets::curve::curve_edit(self, str_id, curve)
+    }
+
+    // ── Drag and drop ────────────────────────────────────────────────────────
+
+    /// What is being dragged right now, if anything.
+    pub fn drag_drop_payload(&self) -> Option<&crate::drag_drop::DragDropPayload> {
+        self.ctx.drag_drop.as_ref()
+    }
+
     // ── Progress bar ─────────────────────────────────────────────────────────
 
     /// `fraction` in 0.0..=1.0; `size.x = 0` → full available width.
@@ -246,18 +535,169 @@ impl<'ctx> Ui<'ctx> {
         crate::widgets::progress_bar::progress_bar(self, fraction, size, overlay);
     }
 
+    /// Animated bar for work of unknown length.
+    pub fn progress_bar_indeterminate(&mut self, size: Vec2, overlay: Option<&str>) {
+        crate::widgets::progress_bar::progress_bar_indeterminate(self, size, overlay);
+    }
+
+    /// Progress ring; `radius <= 0` → font size.
+    pub fn circular_progress(&mut self, fraction: f32, radius: f32, overlay: Option<&str>) {
+        crate::widgets::progress_bar::circular_progress(self, fraction, radius, overlay);
+    }
+
+    /// Loading spinner; zero `radius` / `thickness` pick defaults.
+    pub fn spinner(&mut self, radius: f32, thickness: f32) {
+        crate::widgets::progress_bar::spinner(self, radius, thickness);
+    }
+
+    // ── Knob ─────────────────────────────────────────────────────────────────
+
+    /// Rotary knob; drag vertically to turn (Shift faster, Alt finer).
+    pub fn knob(&mut self, label: &str, v: &mut f32, min: f32, max: f32, format: &str) -> bool {
+        crate::widgets::knob::knob(self, label, v, min, max, format)
+    }
+
+    // ── Plots ────────────────────────────────────────────────────────────────
+
+    /// Line graph; pass `widgets::plot::AUTO_SCALE` for a bound to fit the data.
+    #[allow(clippy::too_many_arguments)]
+    pub fn plot_lines(
+        &mut self,
+        label:     &str,
+        values:    &[f32],
+        offset:    usize,
+        overlay:   Option<&str>,
+        scale_min: f32,
+        scale_max: f32,
+        size:      Vec2,
+    ) {
+        crate::widgets::plot::plot_lines(self, label, values, offset, overlay, scale_min, scale_max, size);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn plot_lines_fn(
+        &mut self,
+        label:     &str,
+        count:     usize,
+        getter:    impl Fn(usize) -> f32,
+        overlay:   Option<&str>,
+        scale_min: f32,
+        scale_max: f32,
+        size:      Vec2,
+    ) {
+        crate::widgets::plot::plot_lines_fn(self, label, count, getter, overlay, scale_min, scale_max, size);
+    }
+
+    /// Bar chart; pass `widgets::plot::AUTO_SCALE` for a bound to fit the data.
+    #[allow(clippy::too_many_arguments)]
+    pub fn plot_histogram(
+        &mut self,
+        label:     &str,
+        values:    &[f32],
+        offset:    usize,
+        overlay:   Option<&str>,
+        scale_min: f32,
+        scale_max: f32,
+        size:      Vec2,
+    ) {
+        crate::widgets::plot::plot_histogram(self, label, values, offset, overlay, scale_min, scale_max, size);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn plot_histogram_fn(
+        &mut self,
+        label:     &str,
+        count:     usize,
+        getter:    impl Fn(usize) -> f32,
+        overlay:   Option<&str>,
+        scale_min: f32,
+        scale_max: f32,
+        size:      Vec2,
+    ) {
+        crate::widgets::plot::plot_histogram_fn(self, label, count, getter, overlay, scale_min, scale_max, size);
+    }
+
+    // ── Charts ───────────────────────────────────────────────────────────────
+
+    pub fn begin_plot(&mut self, title: &str, size: Vec2) -> bool {
+        crate::widgets::chart::begin_plot(self, title, size)
+    }
+
+    pub fn begin_plot_ex(&mut self, title: &str, size: Vec2, flags: crate::widgets::chart::PlotFlags) -> bool {
+        crate::widgets::chart::begin_plot_ex(self, title, size, flags)
+    }
+
+    pub fn plot_setup_axis(&mut self, axis: crate::widgets::chart::PlotAxis, label: &str, scale: crate::widgets::chart::AxisScale) {
+        crate::widgets::chart::plot_setup_axis(self, axis, label, scale);
+    }
+
+    pub fn plot_setup_axis_limits(&mut self, axis: crate::widgets::chart::PlotAxis, min: f64, max: f64, cond: crate::widgets::chart::PlotCond) {
+        crate::widgets::chart::plot_setup_axis_limits(self, axis, min, max, cond);
+    }
+
+    pub fn plot_line<X: Copy + Into<f64>, Y: Copy + Into<f64>>(&mut self, label: &str, xs: &[X], ys: &[Y]) {
+        crate::widgets::chart::plot_line(self, label, xs, ys);
+    }
+
+    pub fn plot_scatter<X: Copy + Into<f64>, Y: Copy + Into<f64>>(&mut self, label: &str, xs: &[X], ys: &[Y]) {
+        crate::widgets::chart::plot_scatter(self, label, xs, ys);
+    }
+
+    pub fn plot_bars<X: Copy + Into<f64>, Y: Copy + Into<f64>>(&mut self, label: &str, xs: &[X], ys: &[Y], width: f64) {
+        crate::widgets::chart::plot_bars(self, label, xs, ys, width);
+    }
+
+    pub fn plot_stairs<X: Copy + Into<f64>, Y: Copy + Into<f64>>(&mut self, label: &str, xs: &[X], ys: &[Y]) {
+        crate::widgets::chart::plot_stairs(self, label, xs, ys);
+    }
+
+    pub fn plot_shaded<X: Copy + Into<f64>, Y: Copy + Into<f64>>(&mut self, label: &str, xs: &[X], ys: &[Y], y_ref: f64) {
+        crate::widgets::chart::plot_shaded(self, label, xs, ys, y_ref);
+    }
+
+    pub fn plot_shaded_between<X: Copy + Into<f64>, Y: Copy + Into<f64>>(&mut self, label: &str, xs: &[X], ys1: &[Y], ys2: &[Y]) {
+        crate::widgets::chart::plot_shaded_between(self, label, xs, ys1, ys2);
+    }
+
+    pub fn plot_error_bars<X: Copy + Into<f64>, Y: Copy + Into<f64>>(&mut self, label: &str, xs: &[X], ys: &[Y], err: &[Y]) {
+        crate::widgets::chart::plot_error_bars(self, label, xs, ys, err);
+    }
+
+    pub fn drag_line_x(&mut self, id: &str, x: &mut f64, color: Color) -> bool {
+        crate::widgets::chart::drag_line_x(self, id, x, color)
+    }
+
+    pub fn drag_line_y(&mut self, id: &str, y: &mut f64, color: Color) -> bool {
+        crate::widgets::chart::drag_line_y(self, id, y, color)
+    }
+
+    pub fn end_plot(&mut self) {
+        crate::widgets::chart::end_plot(self);
+    }
+
+    /// Live plot of the last `history_secs` of `buffer`, with a pause button.
+    pub fn plot_realtime<T: Copy + Default + Into<f64>>(&mut self, id: &str, buffer: &crate::scrolling::ScrollingBuffer<T>, history_secs: f32) {
+        crate::widgets::realtime::plot_realtime(self, id, buffer, history_secs);
+    }
+
     // ── Tab bar ──────────────────────────────────────────────────────────────
 
     pub fn begin_tab_bar(&mut self, id: &str) -> bool {
         crate::widgets::tabs::begin_tab_bar(self, id)
     }
 
+    /// Tab bar with reordering, auto-selection and fitting options.
+    pub fn begin_tab_bar_ex(&mut self, id: &str, flags: crate::widgets::tabs::TabBarFlags) -> bool {
+        crate::widgets::tabs::begin_tab_bar_ex(self, id, flags)
+    }
+
     pub fn end_tab_bar(&mut self) {
         crate::widgets::tabs::end_tab_bar(self);
     }
 
-    pub fn tab_item(&mut self, label: &str) -> bool {
-        crate::widgets::tabs::tab_item(self, label)
+    /// `true` while the tab is selected. Pass `open` for a close button.
+    pub fn tab_item(&mut self, label: &str, open: Option<&mut bool>, flags: crate::widgets::tabs::TabItemFlags) -> bool {
+        crate::widgets::tabs::tab_item(self, label, open, flags)
     }
 
     pub fn end_tab_item(&mut self) {
@@ -270,16 +710,142 @@ impl<'ctx> Ui<'ctx> {
         crate::widgets::button::collapsing_header(self, label)
     }
 
+    // ── Selectable / list box ─────────────────────────────────────────────────
+
+    pub fn selectable(&mut self, label: &str, selected: bool) -> bool {
+        crate::widgets::selectable::selectable(self, label, selected)
+    }
+
+    pub fn selectable_sized(&mut self, label: &str, selected: bool, size: Vec2) -> bool {
+        crate::widgets::selectable::selectable_sized(self, label, selected, size)
+    }
+
+    pub fn list_box(&mut self, label: &str, current: &mut usize, items: &[&str], height_in_items: usize) -> bool {
+        crate::widgets::selectable::list_box(self, label, current, items, height_in_items)
+    }
+
+    pub fn list_box_multi(
+        &mut self,
+        label:           &str,
+        selection:       &mut crate::selection::Selection,
+        items:           &[&str],
+        height_in_items: usize,
+    ) -> bool {
+        crate::widgets::selectable::list_box_multi(self, label, selection, items, height_in_items)
+    }
+
+    /// Open a multi-selection scope over `count` rows submitted with
+    /// `multi_selectable`; `end_multi_select` returns the requests to apply.
+    pub fn begin_multi_select(&mut self, str_id: &str, count: usize) -> crate::widgets::selectable::MultiSelect {
+        crate::widgets::selectable::begin_multi_select(self, str_id, count)
+    }
+
+    pub fn multi_selectable(
+        &mut self,
+        ms:       &mut crate::widgets::selectable::MultiSelect,
+        index:    usize,
+        label:    &str,
+        selected: bool,
+    ) -> bool {
+        crate::widgets::selectable::multi_selectable(self, ms, index, label, selected)
+    }
+
+    pub fn end_multi_select(
+        &mut self,
+        ms: crate::widgets::selectable::MultiSelect,
+    ) -> Vec<crate::selection::SelectionRequest> {
+        crate::widgets::selectable::end_multi_select(self, ms)
+    }
+
+    // ── Table ─────────────────────────────────────────────────────────────────
+
+    /// Start a table; call `end_table` only if this returns `true`.
+    pub fn begin_table(&mut self, id: &str, columns: usize, flags: crate::widgets::table::TableFlags, outer_size: Vec2) -> bool {
+        crate::widgets::table::begin_table(self, id, columns, flags, outer_size)
+    }
+
+    pub fn table_setup_column(&mut self, label: &str, flags: crate::widgets::table::TableColumnFlags, init_width_or_weight: f32) {
+        crate::widgets::table::table_setup_column(self, label, flags, init_width_or_weight);
+    }
+
+    pub fn table_setup_scroll_freeze(&mut self, cols: usize, rows: usize) {
+        crate::widgets::table::table_setup_scroll_freeze(self, cols, rows);
+    }
+
+    pub fn table_sort_specs(&mut self) -> Option<crate::widgets::table::TableSortSpecs> {
+        crate::widgets::table::table_sort_specs(self)
+    }
+
+    pub fn table_headers_row(&mut self) {
+        crate::widgets::table::table_headers_row(self);
+    }
+
+    pub fn table_header(&mut self, label: &str) {
+        crate::widgets::table::table_header(self, label);
+    }
+
+    pub fn table_next_row(&mut self) {
+        crate::widgets::table::table_next_row(self);
+    }
+
+    /// Move to the next cell; `false` if its column is hidden.
+    pub fn table_next_column(&mut self) -> bool {
+        crate::widgets::table::table_next_column(self)
+    }
+
+    pub fn table_set_column_index(&mut self, column: usize) -> bool {
+        crate::widgets::table::table_set_column_index(self, column)
+    }
+
+    pub fn end_table(&mut self) {
+        crate::widgets::table::end_table(self);
+    }
+
+    // ── Tree ──────────────────────────────────────────────────────────────────
+
+    /// Hierarchical node; when it returns `true`, call `tree_pop` after the children.
+    pub fn tree_node(&mut self, label: &str, flags: crate::widgets::tree::TreeNodeFlags) -> bool {
+        crate::widgets::tree::tree_node(self, label, flags)
+    }
+
+    pub fn tree_pop(&mut self) {
+        crate::widgets::tree::tree_pop(self);
+    }
+
+    /// Force the open state of the next `tree_node` / `collapsing` call.
+    pub fn set_next_item_open(&mut self, open: bool) {
+        self.ctx.set_next_item_open(open);
+    }
+
     // ── Image ─────────────────────────────────────────────────────────────────
 
     pub fn image(&mut self, texture: TextureId, size: Vec2) {
-        let sp  = self.ctx.style.item_spacing;
-        if let Some(l) = self.ctx.current_layout_mut() {
-            let pos = l.place(size, sp);
-            drop(l); // borrow ends
-            let draw = &mut self.ctx.draw_list;
-            draw.image_quad(texture, pos, pos + size, Vec2::ZERO, Vec2::ONE, Color::WHITE);
-        }
+        crate::widgets::image::image(self, texture, size);
+    }
+
+    /// Image of the `uv0..uv1` part of `texture`, tinted, with an optional
+    /// 1 px border (pass `Color::TRANSPARENT` for none).
+    pub fn image_ex(&mut self, texture: TextureId, size: Vec2, uv0: Vec2, uv1: Vec2, tint: Color, border_col: Color) {
+        crate::widgets::image::image_ex(self, texture, size, uv0, uv1, tint, border_col);
+    }
+
+    /// Button showing `texture`; `true` when clicked.
+    pub fn image_button(&mut self, str_id: &str, texture: TextureId, size: Vec2) -> bool {
+        crate::widgets::image::image_button(self, str_id, texture, size)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn image_button_ex(
+        &mut self,
+        str_id:  &str,
+        texture: TextureId,
+        size:    Vec2,
+        uv0:     Vec2,
+        uv1:     Vec2,
+        bg_col:  Color,
+        tint:    Color,
+    ) -> bool {
+        crate::widgets::image::image_button_ex(self, str_id, texture, size, uv0, uv1, bg_col, tint)
     }
 
     // ─── Internal helpers ──────────────────────────────────────────────────────
@@ -319,6 +885,34 @@ impl<'ctx> Ui<'ctx> {
         self.ctx.draw_list.pop_texture();
     }
 
+    /// Emit text with the chars at `positions` (ascending char indices) drawn
+    /// in `highlight` instead of `col`.
+    pub(crate) fn draw_text_highlighted(
+        &mut self,
+        text:      &str,
+        pos:       Vec2,
+        col:       Color,
+        highlight: Color,
+        positions: &[usize],
+    ) {
+        let mut x       = pos.x;
+        let mut run     = 0;
+        let mut run_hl  = false;
+        let mut next_hl = positions.iter().peekable();
+        for (ci, (bi, _)) in text.char_indices().enumerate() {
+            let hl = next_hl.peek() == Some(&&ci);
+            if hl { next_hl.next(); }
+            if hl != run_hl && bi > run {
+                let seg = &text[run..bi];
+                self.draw_text(seg, Vec2::new(x, pos.y), if run_hl { highlight } else { col });
+                x  += self.text_width(seg);
+                run = bi;
+            }
+            run_hl = hl;
+        }
+        self.draw_text(&text[run..], Vec2::new(x, pos.y), if run_hl { highlight } else { col });
+    }
+
     /// Measure text width in logical pixels.
     pub(crate) fn text_width(&self, text: &str) -> f32 {
         let fs = self.ctx.style.font_size * self.scale;
@@ -64,6 +64,9 @@ pub fn small_button(ui: &mut Ui<'_>, label: &str) -> bool {
 pub fn collapsing_header(ui: &mut Ui<'_>, label: &str) -> bool {
     let (text, id_src) = parse_label(label);
     let id   = ui.ctx.make_id(id_src);
+    if let Some(forced) = ui.ctx.next_item_open.take() {
+        ui.ctx.get_storage_mut(id).open = forced;
+    }
     let open = ui.ctx.get_storage(id).map_or(true, |s| s.open);
 
     let width = ui.available_width();
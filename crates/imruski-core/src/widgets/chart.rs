@@ -0,0 +1,868 @@
+//! 2D charts – `begin_plot` … `end_plot` with line, scatter, bar, stairs,
+//! shaded and error-bar series over linear, log or time axes.
+//!
+//! ```ignore
+//! if ui.begin_plot("Signals", Vec2::ZERO) {
+//!     ui.plot_setup_axis(PlotAxis::X, "time", AxisScale::Time);
+//!     ui.plot_line("sin", &ts, &sin);
+//!     ui.plot_scatter("samples", &ts, &noise);
+//!     ui.drag_line_x("cursor", &mut cursor, Color::from_hex(0xffcc00));
+//!     ui.end_plot();
+//! }
+//! ```
+//!
+//! Wheel zooms (over an axis: that axis only), left-drag pans, right-drag
+//! zooms to a box and double-click fits the data. Clicking a legend entry
+//! hides or shows its series. Limits and hidden series are kept on the
+//! [`Context`](crate::Context).
+//!
+//! Time axes take UNIX seconds and label them in UTC.
+
+use crate::{
+    calendar::civil_from_days,
+    id::{parse_label, Id},
+    input::{Key, MouseButton},
+    style::StyleColor,
+    ui::Ui,
+    Color, Rect, Vec2,
+};
+
+bitflags::bitflags! {
+    /// Options for `begin_plot_ex`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct PlotFlags: u32 {
+        const NO_TITLE      = 1 << 0;
+        const NO_LEGEND     = 1 << 1;
+        /// Hide the cursor coordinate readout.
+        const NO_MOUSE_TEXT = 1 << 2;
+        /// No zoom, pan, box select or fit from the mouse.
+        const NO_INPUTS     = 1 << 3;
+        const NO_BOX_SELECT = 1 << 4;
+        /// Draw crosshair lines through the cursor.
+        const CROSSHAIRS    = 1 << 5;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlotAxis { X, Y }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AxisScale {
+    #[default]
+    Linear,
+    /// Base-10 logarithmic; non-positive values are not drawn.
+    Log10,
+    /// UNIX timestamps in seconds.
+    Time,
+}
+
+/// When `plot_setup_axis_limits` applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlotCond {
+    /// The first time the plot is shown; the user may zoom away.
+    Once,
+    /// Every frame; zoom and pan on the axis are overridden.
+    Always,
+}
+
+const DEFAULT_HEIGHT: f32 = 300.0;
+/// Gap between the frame edge, labels and the plot area.
+const PAD:            f32 = 6.0;
+/// Zoom factor per wheel notch.
+const ZOOM_STEP:      f32 = 0.9;
+/// Half-width of the grab zone around a drag line.
+const LINE_GRAB:      f32 = 4.0;
+/// Box selections smaller than this keep the axis unchanged.
+const MIN_SELECT:     f32 = 4.0;
+/// Margin added around the data when fitting, as a fraction of the range.
+const FIT_PAD:        f64 = 0.05;
+const MARKER_RADIUS:  f32 = 3.0;
+const ERROR_CAP:      f32 = 3.0;
+const PALETTE: [u32; 10] = [
+    0x4c72b0, 0xdd8452, 0x55a868, 0xc44e52, 0x8172b3,
+    0x937860, 0xda8bc3, 0x8c8c8c, 0xccb974, 0x64b5cd,
+];
+
+// ─── Persistent state ────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Limits { min: f64, max: f64 }
+
+impl Default for Limits {
+    fn default() -> Self { Self { min: 0.0, max: 1.0 } }
+}
+
+impl Limits {
+    const EMPTY: Self = Self { min: f64::INFINITY, max: f64::NEG_INFINITY };
+
+    fn is_empty(self) -> bool { self.min > self.max }
+
+    fn include(&mut self, v: f64) {
+        self.min = self.min.min(v);
+        self.max = self.max.max(v);
+    }
+
+    /// Keep the range usable for `scale`.
+    fn sanitized(self, scale: AxisScale) -> Self {
+        let (mut min, mut max) = (self.min, self.max);
+        if !min.is_finite() || !max.is_finite() { return Self::default(); }
+        if min > max { std::mem::swap(&mut min, &mut max); }
+        if scale == AxisScale::Log10 {
+            if max <= 0.0 { max = 10.0; }
+            if min <= 0.0 { min = max * 1e-3; }
+            if max / min < 1.000_001 { max = min * 10.0; }
+        } else if max - min < 1e-12 * max.abs().max(1.0) {
+            min -= 0.5;
+            max += 0.5;
+        }
+        Self { min, max }
+    }
+
+    /// Data extents plus `FIT_PAD` on each side.
+    fn padded(self, scale: AxisScale) -> Self {
+        if scale == AxisScale::Log10 {
+            let (a, b) = (self.min.log10(), self.max.log10());
+            let pad = ((b - a) * FIT_PAD).max(0.05);
+            return Self { min: 10f64.powf(a - pad), max: 10f64.powf(b + pad) };
+        }
+        let pad = (self.max - self.min) * FIT_PAD;
+        Self { min: self.min - pad, max: self.max + pad }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PanAnchor { mouse: Vec2, x: Limits, y: Limits }
+
+/// Per-plot state kept across frames.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PlotState {
+    x:         Limits,
+    y:         Limits,
+    ready:     bool,
+    /// Fit each axis to the data at the end of this frame.
+    fit:       [bool; 2],
+    hidden:    Vec<Id>,
+    pan:       Option<PanAnchor>,
+    /// Box-select start while the right button is held.
+    select:    Option<Vec2>,
+    drag_line: Option<Id>,
+}
+
+// ─── Per-frame state ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Default)]
+struct AxisSetup {
+    label:  String,
+    scale:  AxisScale,
+    limits: Option<(f64, f64, PlotCond)>,
+}
+
+/// Data ↔ screen mapping for one axis. `p0` is where `lim.min` lands.
+#[derive(Debug, Clone, Copy)]
+struct AxisMap {
+    lim:   Limits,
+    scale: AxisScale,
+    p0:    f32,
+    p1:    f32,
+}
+
+impl AxisMap {
+    fn fwd(&self, v: f64) -> f64 { if self.scale == AxisScale::Log10 { v.log10() } else { v } }
+    fn inv(&self, t: f64) -> f64 { if self.scale == AxisScale::Log10 { 10f64.powf(t) } else { t } }
+
+    fn px(&self, v: f64) -> f32 {
+        let (a, b) = (self.fwd(self.lim.min), self.fwd(self.lim.max));
+        self.p0 + ((self.fwd(v) - a) / (b - a)) as f32 * (self.p1 - self.p0)
+    }
+
+    fn value_at(&self, p: f32) -> f64 {
+        let (a, b) = (self.fwd(self.lim.min), self.fwd(self.lim.max));
+        self.inv(a + ((p - self.p0) / (self.p1 - self.p0)) as f64 * (b - a))
+    }
+
+    /// Limits after scaling the visible pixel span by `f` around `m`.
+    fn zoomed(&self, m: f32, f: f32) -> Limits {
+        Limits { min: self.value_at(m + (self.p0 - m) * f), max: self.value_at(m + (self.p1 - m) * f) }
+    }
+
+    fn with(&self, lim: Limits) -> Self { Self { lim, ..*self } }
+}
+
+#[derive(Debug, Clone)]
+struct LegendEntry {
+    id:    Id,
+    label: String,
+    color: Color,
+}
+
+/// The plot being submitted; lives in `Context::plot`.
+#[derive(Debug)]
+pub(crate) struct PlotFrame {
+    id:       Id,
+    flags:    PlotFlags,
+    title:    String,
+    frame:    Rect,
+    x:        AxisSetup,
+    y:        AxisSetup,
+    /// Layout and input done; series may be drawn.
+    ready:    bool,
+    area:     Rect,
+    mx:       AxisMap,
+    my:       AxisMap,
+    /// Tick spacing, for formatting the cursor readout.
+    step:     [f64; 2],
+    hovered:  bool,
+    /// A drag line or the legend took this frame's press.
+    claimed:  bool,
+    legend:   Vec<LegendEntry>,
+    extents:  [Limits; 2],
+}
+
+/// Colour of the `index`-th series in a plot's legend.
+pub(crate) fn series_color(index: usize) -> Color {
+    Color::from_hex(PALETTE[index % PALETTE.len()])
+}
+
+fn state<'a>(ui: &'a mut Ui<'_>, id: Id) -> &'a mut PlotState {
+    ui.ctx.plots.entry(id).or_default()
+}
+
+// ─── Begin / setup ───────────────────────────────────────────────────────────
+
+/// Start a plot. `size` components `<= 0` mean "available width" and a
+/// default height. Call `end_plot` only if this returns `true`.
+pub fn begin_plot(ui: &mut Ui<'_>, title: &str, size: Vec2) -> bool {
+    begin_plot_ex(ui, title, size, PlotFlags::empty())
+}
+
+pub fn begin_plot_ex(ui: &mut Ui<'_>, title: &str, size: Vec2, flags: PlotFlags) -> bool {
+    if ui.ctx.plot.is_some() { return false; }
+    let (text, id_src) = parse_label(title);
+    let id = ui.ctx.make_id(id_src);
+
+    let w = if size.x > 0.0 { size.x } else { ui.available_width() };
+    let h = if size.y > 0.0 { size.y } else { DEFAULT_HEIGHT };
+    let Some(pos) = ui.layout_next(Vec2::new(w, h)) else { return false };
+    let frame = Rect::from_min_size(pos, Vec2::new(w, h));
+
+    let map = AxisMap { lim: Limits::default(), scale: AxisScale::Linear, p0: 0.0, p1: 1.0 };
+    ui.ctx.plot = Some(PlotFrame {
+        id, flags, frame,
+        title:   text.to_owned(),
+        x:       AxisSetup::default(),
+        y:       AxisSetup::default(),
+        ready:   false,
+        area:    frame,
+        mx:      map,
+        my:      map,
+        step:    [0.0; 2],
+        hovered: false,
+        claimed: false,
+        legend:  Vec::new(),
+        extents: [Limits::EMPTY; 2],
+    });
+    true
+}
+
+/// Label and scale an axis. Call before the first series.
+pub fn plot_setup_axis(ui: &mut Ui<'_>, axis: PlotAxis, label: &str, scale: AxisScale) {
+    let Some(p) = ui.ctx.plot.as_mut().filter(|p| !p.ready) else { return };
+    let a = match axis { PlotAxis::X => &mut p.x, PlotAxis::Y => &mut p.y };
+    a.label = label.to_owned();
+    a.scale = scale;
+}
+
+/// Set an axis range. Call before the first series.
+pub fn plot_setup_axis_limits(ui: &mut Ui<'_>, axis: PlotAxis, min: f64, max: f64, cond: PlotCond) {
+    let Some(p) = ui.ctx.plot.as_mut().filter(|p| !p.ready) else { return };
+    let a = match axis { PlotAxis::X => &mut p.x, PlotAxis::Y => &mut p.y };
+    a.limits = Some((min, max, cond));
+}
+
+/// Lay out the plot, apply zoom / pan / box select and draw the axes.
+/// Runs once, before the first series.
+fn setup_finish(ui: &mut Ui<'_>) {
+    let Some(p) = ui.ctx.plot.as_ref() else { return };
+    if p.ready { return; }
+    let (id, flags, frame) = (p.id, p.flags, p.frame);
+    let (xs, ys) = (p.x.clone(), p.y.clone());
+    let title = if flags.contains(PlotFlags::NO_TITLE) { String::new() } else { p.title.clone() };
+
+    let fs = ui.ctx.style.font_size;
+    let st = state(ui, id);
+    let first = !st.ready;
+    if first {
+        st.ready = true;
+        st.fit   = [true; 2];
+    }
+    for (i, (setup, lim)) in [(&xs, &mut st.x), (&ys, &mut st.y)].into_iter().enumerate() {
+        if let Some((min, max, cond)) = setup.limits {
+            if cond == PlotCond::Always || first {
+                *lim = Limits { min, max };
+                st.fit[i] = false;
+            }
+        }
+    }
+    st.x = st.x.sanitized(xs.scale);
+    st.y = st.y.sanitized(ys.scale);
+    let (lim_x, lim_y) = (st.x, st.y);
+
+    // ── Layout ───────────────────────────────────────────────────────────────
+    let top_row  = !title.is_empty() || !ys.label.is_empty();
+    let x_label  = if xs.label.is_empty() { 0.0 } else { fs + PAD };
+    let top      = frame.min.y + PAD + if top_row { fs + PAD } else { 0.0 };
+    let bottom   = frame.max.y - PAD - x_label - fs - PAD * 0.5;
+    let my0      = AxisMap { lim: lim_y, scale: ys.scale, p0: bottom, p1: top };
+    let y_target = ((bottom - top) / (fs * 3.0)).max(2.0) as usize;
+    let (y_ticks, _) = ticks(&my0, y_target);
+    let label_w  = y_ticks.iter().map(|t| ui.text_width(&t.label)).fold(0.0, f32::max);
+    let area     = Rect::new(Vec2::new(frame.min.x + PAD + label_w + PAD * 0.5, top), Vec2::new(frame.max.x - PAD, bottom));
+    let mut mx   = AxisMap { lim: lim_x, scale: xs.scale, p0: area.min.x, p1: area.max.x };
+    let mut my   = my0;
+
+    // ── Input ────────────────────────────────────────────────────────────────
+    let (hovered, _, _) = ui.ctx.button_behavior(id, frame);
+    let mouse   = ui.ctx.input.mouse_pos;
+    let over    = hovered && area.contains(mouse);
+    let over_x  = hovered && mouse.y > area.max.y && mouse.x >= area.min.x && mouse.x <= area.max.x;
+    let over_y  = hovered && mouse.x < area.min.x && mouse.y >= area.min.y && mouse.y <= area.max.y;
+    let down_l  = ui.ctx.input.mouse_down(MouseButton::Left);
+    let down_r  = ui.ctx.input.mouse_down(MouseButton::Right);
+    let wheel   = ui.ctx.input.mouse_wheel;
+    let right   = ui.ctx.input.mouse_clicked(MouseButton::Right);
+    let dbl     = ui.ctx.input.mouse_double_clicked(MouseButton::Left);
+    let escape  = ui.ctx.input.key_pressed(Key::Escape);
+    let always  = [
+        matches!(xs.limits, Some((_, _, PlotCond::Always))),
+        matches!(ys.limits, Some((_, _, PlotCond::Always))),
+    ];
+
+    let st = state(ui, id);
+    if !down_l {
+        st.pan       = None;
+        st.drag_line = None;
+    }
+    if !flags.contains(PlotFlags::NO_INPUTS) {
+        if let Some(a) = st.pan {
+            let d = mouse - a.mouse;
+            let (ax, ay) = (mx.with(a.x), my.with(a.y));
+            if !always[0] { st.x = Limits { min: ax.value_at(ax.p0 - d.x), max: ax.value_at(ax.p1 - d.x) }; }
+            if !always[1] { st.y = Limits { min: ay.value_at(ay.p0 - d.y), max: ay.value_at(ay.p1 - d.y) }; }
+            st.fit = [false; 2];
+        }
+        if wheel != 0.0 && (over || over_x || over_y) {
+            let f = ZOOM_STEP.powf(wheel);
+            if (over || over_x) && !always[0] { st.x = mx.zoomed(mouse.x, f); st.fit[0] = false; }
+            if (over || over_y) && !always[1] { st.y = my.zoomed(mouse.y, f); st.fit[1] = false; }
+        }
+        if over && right && !flags.contains(PlotFlags::NO_BOX_SELECT) {
+            st.select = Some(mouse);
+        }
+        if let Some(s) = st.select {
+            if escape {
+                st.select = None;
+            } else if !down_r {
+                let r = Rect::new(s.min(mouse), s.max(mouse)).intersect(area);
+                if r.width() > MIN_SELECT && !always[0] {
+                    st.x = Limits { min: mx.value_at(r.min.x), max: mx.value_at(r.max.x) };
+                    st.fit[0] = false;
+                }
+                if r.height() > MIN_SELECT && !always[1] {
+                    st.y = Limits { min: my.value_at(r.max.y), max: my.value_at(r.min.y) };
+                    st.fit[1] = false;
+                }
+                st.select = None;
+            }
+        }
+        if over && dbl {
+            st.fit = [!always[0], !always[1]];
+        }
+        st.x = st.x.sanitized(xs.scale);
+        st.y = st.y.sanitized(ys.scale);
+    }
+    mx.lim = st.x;
+    my.lim = st.y;
+
+    // ── Axes ─────────────────────────────────────────────────────────────────
+    let x_target = (area.width() / 100.0).max(2.0) as usize;
+    let (x_ticks, x_step) = ticks(&mx, x_target);
+    let (y_ticks, y_step) = ticks(&my, y_target);
+
+    let bg     = ui.ctx.style.color(StyleColor::PlotBg);
+    let grid   = ui.ctx.style.color(StyleColor::PlotGrid);
+    let border = ui.ctx.style.color(StyleColor::Border);
+    let tc     = ui.ctx.style.color(StyleColor::Text);
+    ui.ctx.draw_list.filled_rect(area, 0.0, bg);
+
+    ui.ctx.draw_list.push_clip_rect(area);
+    for t in &x_ticks {
+        let x = mx.px(t.value).round();
+        let col = if t.major { grid } else { grid.with_alpha(grid.a * 0.4) };
+        ui.ctx.draw_list.line(Vec2::new(x, area.min.y), Vec2::new(x, area.max.y), 1.0, col);
+    }
+    for t in &y_ticks {
+        let y = my.px(t.value).round();
+        let col = if t.major { grid } else { grid.with_alpha(grid.a * 0.4) };
+        ui.ctx.draw_list.line(Vec2::new(area.min.x, y), Vec2::new(area.max.x, y), 1.0, col);
+    }
+    ui.ctx.draw_list.pop_clip_rect();
+    ui.ctx.draw_list.rect_outline(area, 1.0, border);
+
+    for t in x_ticks.iter().filter(|t| !t.label.is_empty()) {
+        let w = ui.text_width(&t.label);
+        let x = (mx.px(t.value) - w * 0.5).clamp(frame.min.x, frame.max.x - w);
+        ui.draw_text(&t.label, Vec2::new(x, area.max.y + PAD * 0.5), tc);
+    }
+    for t in y_ticks.iter().filter(|t| !t.label.is_empty()) {
+        let w = ui.text_width(&t.label);
+        let y = (my.px(t.value) - fs * 0.5).clamp(area.min.y - fs * 0.5, area.max.y - fs * 0.5);
+        ui.draw_text(&t.label, Vec2::new(area.min.x - PAD * 0.5 - w, y), tc);
+    }
+    if !title.is_empty() {
+        let w = ui.text_width(&title);
+        ui.draw_text(&title, Vec2::new(frame.center().x - w * 0.5, frame.min.y + PAD), tc);
+    }
+    if !ys.label.is_empty() {
+        ui.draw_text(&ys.label, Vec2::new(frame.min.x + PAD, frame.min.y + PAD), tc);
+    }
+    if !xs.label.is_empty() {
+        let w = ui.text_width(&xs.label);
+        ui.draw_text(&xs.label, Vec2::new(area.center().x - w * 0.5, frame.max.y - PAD - fs), tc);
+    }
+
+    ui.ctx.draw_list.push_clip_rect(area);
+    let p = ui.ctx.plot.as_mut().unwrap();
+    p.ready   = true;
+    p.area    = area;
+    p.mx      = mx;
+    p.my      = my;
+    p.step    = [x_step, y_step];
+    p.hovered = over;
+}
+
+// ─── Ticks ───────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone)]
+struct Tick {
+    value: f64,
+    major: bool,
+    /// Empty for unlabelled minor ticks.
+    label: String,
+}
+
+/// Ticks for the visible range of `map`, aiming for about `target` labels.
+/// Also returns the label spacing (0 for log axes).
+fn ticks(map: &AxisMap, target: usize) -> (Vec<Tick>, f64) {
+    let Limits { min, max } = map.lim;
+    let target = target.max(1);
+    match map.scale {
+        AxisScale::Linear => {
+            let step = nice_step((max - min) / target as f64);
+            (multiples(min, max, step).map(|v| Tick { value: v, major: true, label: format_linear(v, step) }).collect(), step)
+        }
+        AxisScale::Time => {
+            let step = time_step((max - min) / target as f64);
+            (multiples(min, max, step).map(|v| Tick { value: v, major: true, label: format_time(v, step) }).collect(), step)
+        }
+        AxisScale::Log10 => {
+            let (a, b) = (min.log10().floor() as i32, max.log10().ceil() as i32);
+            let decades = (b - a).max(1) as usize;
+            let every   = decades.div_ceil(target).max(1) as i32;
+            let mut out = Vec::new();
+            for k in a..=b {
+                let base = 10f64.powi(k);
+                if k.rem_euclid(every) == 0 && base >= min && base <= max {
+                    out.push(Tick { value: base, major: true, label: format_decade(k) });
+                }
+                if every == 1 {
+                    for m in 2..10 {
+                        let v = base * m as f64;
+                        if v >= min && v <= max { out.push(Tick { value: v, major: false, label: String::new() }); }
+                    }
+                }
+            }
+            (out, 0.0)
+        }
+    }
+}
+
+/// Multiples of `step` in `min..=max`.
+fn multiples(min: f64, max: f64, step: f64) -> impl Iterator<Item = f64> {
+    let first = (min / step).ceil() as i64;
+    let last  = (max / step).floor() as i64;
+    // Guard against runaway ranges; `nice_step` keeps this near `target`
+    (first..=last.min(first + 1000)).map(move |k| {
+        let v = k as f64 * step;
+        if v.abs() < step * 1e-9 { 0.0 } else { v }
+    })
+}
+
+/// 1, 2 or 5 times a power of ten, at least `raw`.
+fn nice_step(raw: f64) -> f64 {
+    if raw <= 0.0 || !raw.is_finite() { return 1.0; }
+    let mag = 10f64.powf(raw.log10().floor());
+    let n = raw / mag;
+    let nice = if n <= 1.0 { 1.0 } else if n <= 2.0 { 2.0 } else if n <= 5.0 { 5.0 } else { 10.0 };
+    nice * mag
+}
+
+/// A calendar-friendly spacing for time axes.
+fn time_step(raw: f64) -> f64 {
+    const STEPS: [f64; 23] = [
+        1.0, 2.0, 5.0, 10.0, 15.0, 30.0,
+        60.0, 120.0, 300.0, 600.0, 900.0, 1800.0,
+        3600.0, 7200.0, 10800.0, 21600.0, 43200.0,
+        86400.0, 172800.0, 604800.0, 1209600.0, 2592000.0, 7776000.0,
+    ];
+    const YEAR: f64 = 31_536_000.0;
+    if raw < 1.0 { return nice_step(raw); }
+    match STEPS.iter().find(|&&s| s >= raw) {
+        Some(&s) => s,
+        None     => YEAR * nice_step(raw / YEAR),
+    }
+}
+
+fn decimals(step: f64) -> usize {
+    if step <= 0.0 { return 3; }
+    (-step.log10().floor()).clamp(0.0, 12.0) as usize
+}
+
+fn format_linear(v: f64, step: f64) -> String {
+    format!("{v:.*}", decimals(step))
+}
+
+fn format_decade(k: i32) -> String {
+    if (-3..=4).contains(&k) { format!("{:.*}", (-k).max(0) as usize, 10f64.powi(k)) } else { format!("1e{k}") }
+}
+
+/// UTC label for a UNIX time, as precise as `step` needs.
+fn format_time(t: f64, step: f64) -> String {
+    let secs = t.floor();
+    let days = (secs / 86_400.0).floor() as i64;
+    let sod  = secs as i64 - days * 86_400;
+    let (h, m, s) = (sod / 3600, sod / 60 % 60, sod % 60);
+    if step >= 86_400.0 {
+        let (y, mo, d) = civil_from_days(days);
+        format!("{y:04}-{mo:02}-{d:02}")
+    } else if step >= 60.0 {
+        format!("{h:02}:{m:02}")
+    } else if step >= 1.0 {
+        format!("{h:02}:{m:02}:{s:02}")
+    } else {
+        let dec = decimals(step).clamp(1, 3);
+        format!("{h:02}:{m:02}:{:0w$.dec$}", s as f64 + (t - secs), w = dec + 3)
+    }
+}
+
+/// Cursor readout: a little finer than the tick labels.
+fn format_value(map: &AxisMap, v: f64, step: f64) -> String {
+    match map.scale {
+        AxisScale::Linear => format!("{v:.*}", decimals(step) + 1),
+        AxisScale::Time   => format_time(v, step / 10.0),
+        AxisScale::Log10  => if (1e-3..1e5).contains(&v.abs()) { format!("{v:.4}") } else { format!("{v:.3e}") },
+    }
+}
+
+// ─── Series ──────────────────────────────────────────────────────────────────
+
+/// Colour and mapping for a series being drawn.
+struct Series {
+    color: Color,
+    mx:    AxisMap,
+    my:    AxisMap,
+}
+
+impl Series {
+    fn px(&self, x: f64, y: f64) -> Option<Vec2> {
+        let p = Vec2::new(self.mx.px(x), self.my.px(y));
+        (p.x.is_finite() && p.y.is_finite()).then_some(p)
+    }
+}
+
+/// Register `label` in the legend. `None` if there is no plot or the
+/// series is hidden.
+fn series_begin(ui: &mut Ui<'_>, label: &str) -> Option<Series> {
+    setup_finish(ui);
+    let p = ui.ctx.plot.as_mut()?;
+    let (text, id_src) = parse_label(label);
+    let sid = p.id.combine(Id::from_str(id_src));
+    let color = match p.legend.iter().find(|e| e.id == sid) {
+        Some(e) => e.color,
+        None => {
+            let color = series_color(p.legend.len());
+            p.legend.push(LegendEntry { id: sid, label: text.to_owned(), color });
+            color
+        }
+    };
+    let (pid, mx, my) = (p.id, p.mx, p.my);
+    let hidden = ui.ctx.plots.get(&pid).is_some_and(|s| s.hidden.contains(&sid));
+    (!hidden).then_some(Series { color, mx, my })
+}
+
+/// Grow the fit extents by the drawable points.
+fn fit_points(ui: &mut Ui<'_>, pts: impl IntoIterator<Item = (f64, f64)>) {
+    let Some(p) = ui.ctx.plot.as_mut() else { return };
+    let ok = |v: f64, scale: AxisScale| v.is_finite() && (scale != AxisScale::Log10 || v > 0.0);
+    for (x, y) in pts {
+        if ok(x, p.x.scale) { p.extents[0].include(x); }
+        if ok(y, p.y.scale) { p.extents[1].include(y); }
+    }
+}
+
+fn points<X: Copy + Into<f64>, Y: Copy + Into<f64>>(xs: &[X], ys: &[Y]) -> Vec<(f64, f64)> {
+    xs.iter().zip(ys).map(|(&x, &y)| (x.into(), y.into())).collect()
+}
+
+/// Polyline through `(xs[i], ys[i])`.
+pub fn plot_line<X: Copy + Into<f64>, Y: Copy + Into<f64>>(ui: &mut Ui<'_>, label: &str, xs: &[X], ys: &[Y]) {
+    let Some(s) = series_begin(ui, label) else { return };
+    let pts = points(xs, ys);
+    fit_points(ui, pts.iter().copied());
+    let mut prev = None;
+    for &(x, y) in &pts {
+        let p = s.px(x, y);
+        if let (Some(a), Some(b)) = (prev, p) { ui.ctx.draw_list.line(a, b, 1.5, s.color); }
+        prev = p;
+    }
+}
+
+/// A marker at each `(xs[i], ys[i])`.
+pub fn plot_scatter<X: Copy + Into<f64>, Y: Copy + Into<f64>>(ui: &mut Ui<'_>, label: &str, xs: &[X], ys: &[Y]) {
+    let Some(s) = series_begin(ui, label) else { return };
+    let pts = points(xs, ys);
+    fit_points(ui, pts.iter().copied());
+    for p in pts.iter().filter_map(|&(x, y)| s.px(x, y)) {
+        ui.ctx.draw_list.filled_circle(p, MARKER_RADIUS, s.color, 8);
+    }
+}
+
+/// Vertical bars from zero, `width` in x-axis units, centred on `xs[i]`.
+pub fn plot_bars<X: Copy + Into<f64>, Y: Copy + Into<f64>>(ui: &mut Ui<'_>, label: &str, xs: &[X], ys: &[Y], width: f64) {
+    let Some(s) = series_begin(ui, label) else { return };
+    let pts = points(xs, ys);
+    let half = width * 0.5;
+    fit_points(ui, pts.iter().flat_map(|&(x, y)| [(x - half, y), (x + half, 0.0)]));
+    // On a log axis bars grow from the bottom edge instead of zero
+    let base = if s.my.scale == AxisScale::Log10 { s.my.p0 } else { s.my.px(0.0) };
+    for &(x, y) in &pts {
+        let (Some(a), Some(b)) = (s.px(x - half, y), s.px(x + half, y)) else { continue };
+        let r = Rect::new(Vec2::new(a.x.min(b.x), a.y.min(base)), Vec2::new(a.x.max(b.x), a.y.max(base)));
+        ui.ctx.draw_list.filled_rect(r, 0.0, s.color);
+    }
+}
+
+/// Step function: each value holds until the next x.
+pub fn plot_stairs<X: Copy + Into<f64>, Y: Copy + Into<f64>>(ui: &mut Ui<'_>, label: &str, xs: &[X], ys: &[Y]) {
+    let Some(s) = series_begin(ui, label) else { return };
+    let pts = points(xs, ys);
+    fit_points(ui, pts.iter().copied());
+    for w in pts.windows(2) {
+        let ((x0, y0), (x1, y1)) = (w[0], w[1]);
+        let (Some(a), Some(b), Some(c)) = (s.px(x0, y0), s.px(x1, y0), s.px(x1, y1)) else { continue };
+        ui.ctx.draw_list.line(a, b, 1.5, s.color);
+        ui.ctx.draw_list.line(b, c, 1.5, s.color);
+    }
+}
+
+/// Area between `ys` and the horizontal line `y_ref`.
+pub fn plot_shaded<X: Copy + Into<f64>, Y: Copy + Into<f64>>(ui: &mut Ui<'_>, label: &str, xs: &[X], ys: &[Y], y_ref: f64) {
+    let Some(s) = series_begin(ui, label) else { return };
+    let top = points(xs, ys);
+    let bot: Vec<(f64, f64)> = top.iter().map(|&(x, _)| (x, y_ref)).collect();
+    shade(ui, &s, &top, &bot);
+}
+
+/// Area between two curves sharing `xs`.
+pub fn plot_shaded_between<X: Copy + Into<f64>, Y: Copy + Into<f64>>(ui: &mut Ui<'_>, label: &str, xs: &[X], ys1: &[Y], ys2: &[Y]) {
+    let Some(s) = series_begin(ui, label) else { return };
+    shade(ui, &s, &points(xs, ys1), &points(xs, ys2));
+}
+
+fn shade(ui: &mut Ui<'_>, s: &Series, top: &[(f64, f64)], bot: &[(f64, f64)]) {
+    fit_points(ui, top.iter().chain(bot).copied());
+    let col = s.color.with_alpha(0.35);
+    let n = top.len().min(bot.len());
+    for i in 1..n {
+        let (Some(t0), Some(b0), Some(t1), Some(b1)) =
+            (s.px(top[i - 1].0, top[i - 1].1), s.px(bot[i - 1].0, bot[i - 1].1), s.px(top[i].0, top[i].1), s.px(bot[i].0, bot[i].1))
+        else { continue };
+        let (d0, d1) = (t0.y - b0.y, t1.y - b1.y);
+        if d0 * d1 < 0.0 {
+            // The curves cross: split into two triangles at the crossing
+            let u = d0 / (d0 - d1);
+            let c = t0.lerp(t1, u);
+            ui.ctx.draw_list.triangle_filled(t0, c, b0, col);
+            ui.ctx.draw_list.triangle_filled(c, t1, b1, col);
+        } else {
+            ui.ctx.draw_list.triangle_filled(t0, t1, b1, col);
+            ui.ctx.draw_list.triangle_filled(t0, b1, b0, col);
+        }
+    }
+}
+
+/// Vertical error bars of `ys[i] ± err[i]`.
+pub fn plot_error_bars<X: Copy + Into<f64>, Y: Copy + Into<f64>>(ui: &mut Ui<'_>, label: &str, xs: &[X], ys: &[Y], err: &[Y]) {
+    let Some(s) = series_begin(ui, label) else { return };
+    let pts: Vec<(f64, f64, f64)> = xs.iter().zip(ys).zip(err)
+        .map(|((&x, &y), &e)| (x.into(), y.into(), e.into().abs()))
+        .collect();
+    fit_points(ui, pts.iter().flat_map(|&(x, y, e)| [(x, y - e), (x, y + e)]));
+    for &(x, y, e) in &pts {
+        let (Some(lo), Some(hi)) = (s.px(x, y - e), s.px(x, y + e)) else { continue };
+        ui.ctx.draw_list.line(lo, hi, 1.0, s.color);
+        for p in [lo, hi] {
+            ui.ctx.draw_list.line(Vec2::new(p.x - ERROR_CAP, p.y), Vec2::new(p.x + ERROR_CAP, p.y), 1.0, s.color);
+        }
+    }
+}
+
+// ─── Annotations ─────────────────────────────────────────────────────────────
+
+/// Vertical line at `*x` that can be dragged. Returns `true` when moved.
+pub fn drag_line_x(ui: &mut Ui<'_>, str_id: &str, x: &mut f64, color: Color) -> bool {
+    drag_line(ui, str_id, x, color, PlotAxis::X)
+}
+
+/// Horizontal line at `*y` that can be dragged. Returns `true` when moved.
+pub fn drag_line_y(ui: &mut Ui<'_>, str_id: &str, y: &mut f64, color: Color) -> bool {
+    drag_line(ui, str_id, y, color, PlotAxis::Y)
+}
+
+fn drag_line(ui: &mut Ui<'_>, str_id: &str, v: &mut f64, color: Color, axis: PlotAxis) -> bool {
+    setup_finish(ui);
+    let Some(p) = ui.ctx.plot.as_ref() else { return false };
+    let (pid, area, over) = (p.id, p.area, p.hovered);
+    let (map, step) = match axis { PlotAxis::X => (p.mx, p.step[0]), PlotAxis::Y => (p.my, p.step[1]) };
+    let lid   = pid.combine(Id::from_str(str_id));
+    let mouse = ui.ctx.input.mouse_pos;
+    let m     = match axis { PlotAxis::X => mouse.x, PlotAxis::Y => mouse.y };
+
+    let hovered = over && (m - map.px(*v)).abs() <= LINE_GRAB;
+    if hovered && ui.ctx.input.mouse_clicked(MouseButton::Left) {
+        state(ui, pid).drag_line = Some(lid);
+        if let Some(p) = ui.ctx.plot.as_mut() { p.claimed = true; }
+    }
+    let dragging = ui.ctx.input.mouse_down(MouseButton::Left) && state(ui, pid).drag_line == Some(lid);
+    let mut changed = false;
+    if dragging {
+        let nv = map.value_at(m);
+        if nv.is_finite() && nv != *v {
+            *v = nv;
+            changed = true;
+        }
+    }
+
+    let px = map.px(*v);
+    let (a, b) = match axis {
+        PlotAxis::X => (Vec2::new(px, area.min.y), Vec2::new(px, area.max.y)),
+        PlotAxis::Y => (Vec2::new(area.min.x, px), Vec2::new(area.max.x, px)),
+    };
+    let thickness = if hovered || dragging { 2.0 } else { 1.0 };
+    ui.ctx.draw_list.line(a, b, thickness, color);
+    if hovered || dragging {
+        let text = format_value(&map, *v, step);
+        let fs = ui.ctx.style.font_size;
+        let w  = ui.text_width(&text);
+        let at = match axis {
+            PlotAxis::X => Vec2::new((px + 3.0).min(area.max.x - w - 2.0), area.min.y + 2.0),
+            PlotAxis::Y => Vec2::new(area.max.x - w - 3.0, (px - fs - 2.0).max(area.min.y)),
+        };
+        ui.ctx.draw_list.filled_rect(Rect::from_min_size(at - Vec2::splat(1.0), Vec2::new(w + 2.0, fs + 2.0)), 0.0, color.with_alpha(0.8));
+        let tc = ui.ctx.style.color(StyleColor::WindowBg);
+        ui.draw_text(&text, at, tc);
+    }
+    changed
+}
+
+// ─── End ─────────────────────────────────────────────────────────────────────
+
+/// Finish the plot: legend, box selection, cursor readout, and fitting
+/// the axes to the data when asked.
+pub fn end_plot(ui: &mut Ui<'_>) {
+    setup_finish(ui);
+    let Some(mut p) = ui.ctx.plot.take() else { return };
+    let fs    = ui.ctx.style.font_size;
+    let mouse = ui.ctx.input.mouse_pos;
+    let area  = p.area;
+
+    // ── Legend ───────────────────────────────────────────────────────────────
+    if !p.flags.contains(PlotFlags::NO_LEGEND) && !p.legend.is_empty() {
+        let row_h  = fs + 4.0;
+        let swatch = fs * 0.7;
+        let w = p.legend.iter().map(|e| ui.text_width(&e.label)).fold(0.0, f32::max) + swatch + PAD * 3.0;
+        let rect = Rect::from_min_size(area.min + Vec2::splat(PAD), Vec2::new(w, row_h * p.legend.len() as f32 + 4.0));
+        let bg     = ui.ctx.style.color(StyleColor::PopupBg);
+        let border = ui.ctx.style.color(StyleColor::Border);
+        ui.ctx.draw_list.filled_rect(rect, 0.0, bg.with_alpha(bg.a * 0.8));
+        ui.ctx.draw_list.rect_outline(rect, 1.0, border);
+
+        let tc = ui.ctx.style.color(StyleColor::Text);
+        let dc = ui.ctx.style.color(StyleColor::TextDisabled);
+        let hc = ui.ctx.style.color(StyleColor::HeaderHovered);
+        let mut toggled = None;
+        for (i, e) in p.legend.iter().enumerate() {
+            let row = Rect::from_min_size(Vec2::new(rect.min.x, rect.min.y + 2.0 + i as f32 * row_h), Vec2::new(w, row_h));
+            let (hovered, _, clicked) = ui.ctx.button_behavior(e.id.combine(Id::from_str("##legend")), row);
+            if hovered {
+                ui.ctx.draw_list.filled_rect(row, 0.0, hc);
+                if ui.ctx.input.mouse_clicked(MouseButton::Left) { p.claimed = true; }
+            }
+            if clicked { toggled = Some(e.id); }
+            let hidden = ui.ctx.plots.get(&p.id).is_some_and(|s| s.hidden.contains(&e.id));
+            let sw = Rect::from_min_size(Vec2::new(row.min.x + PAD, row.min.y + (row_h - swatch) * 0.5), Vec2::splat(swatch));
+            ui.ctx.draw_list.filled_rect(sw, 0.0, if hidden { dc } else { e.color });
+            ui.draw_text(&e.label, Vec2::new(sw.max.x + PAD, row.min.y + 2.0), if hidden { dc } else { tc });
+        }
+        if let Some(sid) = toggled {
+            let st = state(ui, p.id);
+            match st.hidden.iter().position(|&h| h == sid) {
+                Some(i) => { st.hidden.remove(i); }
+                None    => st.hidden.push(sid),
+            }
+        }
+    }
+
+    // ── Box selection ────────────────────────────────────────────────────────
+    if let Some(s) = ui.ctx.plots.get(&p.id).and_then(|st| st.select) {
+        let r   = Rect::new(s.min(mouse), s.max(mouse)).intersect(area);
+        let col = ui.ctx.style.color(StyleColor::PlotSelection);
+        ui.ctx.draw_list.filled_rect(r, 0.0, col.with_alpha(col.a * 0.25));
+        ui.ctx.draw_list.rect_outline(r, 1.0, col);
+    }
+
+    // ── Cursor ───────────────────────────────────────────────────────────────
+    if p.hovered {
+        if p.flags.contains(PlotFlags::CROSSHAIRS) {
+            let col = ui.ctx.style.color(StyleColor::Text).with_alpha(0.5);
+            ui.ctx.draw_list.line(Vec2::new(area.min.x, mouse.y), Vec2::new(area.max.x, mouse.y), 1.0, col);
+            ui.ctx.draw_list.line(Vec2::new(mouse.x, area.min.y), Vec2::new(mouse.x, area.max.y), 1.0, col);
+        }
+        if !p.flags.contains(PlotFlags::NO_MOUSE_TEXT) {
+            let text = format!(
+                "{}, {}",
+                format_value(&p.mx, p.mx.value_at(mouse.x), p.step[0]),
+                format_value(&p.my, p.my.value_at(mouse.y), p.step[1]),
+            );
+            let w  = ui.text_width(&text);
+            let tc = ui.ctx.style.color(StyleColor::Text);
+            ui.draw_text(&text, Vec2::new(area.max.x - w - PAD, area.max.y - fs - PAD), tc);
+        }
+    }
+    ui.ctx.draw_list.pop_clip_rect();
+
+    // ── Pan start / fit ──────────────────────────────────────────────────────
+    let pressed = ui.ctx.input.mouse_clicked(MouseButton::Left);
+    let scales  = [p.x.scale, p.y.scale];
+    let st = state(ui, p.id);
+    if pressed && p.hovered && !p.claimed && !p.flags.contains(PlotFlags::NO_INPUTS) {
+        st.pan = Some(PanAnchor { mouse, x: st.x, y: st.y });
+    }
+    for (i, scale) in scales.into_iter().enumerate() {
+        // With no data yet, keep the request for a later frame
+        if st.fit[i] && !p.extents[i].is_empty() {
+            let lim = p.extents[i].padded(scale).sanitized(scale);
+            if i == 0 { st.x = lim; } else { st.y = lim; }
+            st.fit[i] = false;
+        }
+    }
+}
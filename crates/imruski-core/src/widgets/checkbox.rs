@@ -1,4 +1,5 @@
-//! Checkbox widget.
+//! Boolean controls – checkbox, tri-state / flags checkbox, radio button and
+//! toggle switch.
 
 use crate::{
     id::parse_label,
@@ -7,6 +8,19 @@ use crate::{
     Rect, Vec2,
 };
 
+/// Seconds the toggle knob takes to travel from one side to the other.
+const TOGGLE_ANIM_TIME: f32 = 0.12;
+
+/// Value of a tri-state checkbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    /// Partially set – e.g. some children of a group are checked.
+    Mixed,
+}
+
 pub fn checkbox(ui: &mut Ui<'_>, label: &str, v: &mut bool) -> bool {
     let (text, id_src) = parse_label(label);
     let id = ui.ctx.make_id(id_src);
@@ -60,3 +74,140 @@ pub fn checkbox(ui: &mut Ui<'_>, label: &str, v: &mut bool) -> bool {
 
     clicked
 }
+
+/// Lay out `[box] label` with a square box of `box_w` × font-size.
+/// Returns `(box_rect, hovered, clicked)`; the label is already drawn.
+fn labeled_box(ui: &mut Ui<'_>, label: &str, box_w: f32) -> Option<(Rect, bool, bool)> {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+
+    let fs    = ui.ctx.style.font_size;
+    let sp    = ui.ctx.style.item_spacing;
+    let tw    = ui.text_width(text);
+    let total = Vec2::new(box_w + if text.is_empty() { 0.0 } else { sp.0 + tw }, fs);
+
+    let pos = ui.layout_next(total)?;
+    let box_rect = Rect::from_min_size(pos, Vec2::new(box_w, fs));
+    let (hovered, _, clicked) = ui.ctx.button_behavior(id, Rect::from_min_size(pos, total));
+
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    ui.draw_text(text, Vec2::new(pos.x + box_w + sp.0, pos.y), tc);
+    Some((box_rect, hovered, clicked))
+}
+
+fn draw_check_frame(ui: &mut Ui<'_>, rect: Rect, hovered: bool) {
+    let bg = if hovered { StyleColor::FrameBgHovered } else { StyleColor::FrameBg };
+    let bg       = ui.ctx.style.color(bg);
+    let border   = ui.ctx.style.color(StyleColor::Border);
+    let rounding = ui.ctx.style.frame_rounding;
+    ui.ctx.draw_list.filled_rect(rect, rounding, bg);
+    ui.ctx.draw_list.rect_outline(rect, 1.0, border);
+}
+
+fn draw_check_mark(ui: &mut Ui<'_>, rect: Rect, state: CheckState) {
+    let s  = rect.width();
+    let c  = rect.center();
+    let ck = ui.ctx.style.color(StyleColor::CheckMark);
+    let draw = &mut ui.ctx.draw_list;
+    match state {
+        CheckState::Unchecked => {}
+        CheckState::Checked => {
+            draw.line(Vec2::new(c.x - s * 0.3, c.y), Vec2::new(c.x - s * 0.05, c.y + s * 0.3), 2.0, ck);
+            draw.line(Vec2::new(c.x - s * 0.05, c.y + s * 0.3), Vec2::new(c.x + s * 0.35, c.y - s * 0.25), 2.0, ck);
+        }
+        CheckState::Mixed => {
+            let bar = Rect::from_center_size(c, Vec2::new(s * 0.6, (s * 0.18).max(2.0)));
+            draw.filled_rect(bar, 0.0, ck);
+        }
+    }
+}
+
+/// Checkbox with a third "mixed" state. Clicking a mixed or unchecked box
+/// checks it; clicking a checked box clears it.
+pub fn checkbox_tristate(ui: &mut Ui<'_>, label: &str, state: &mut CheckState) -> bool {
+    let fs = ui.ctx.style.font_size;
+    let Some((rect, hovered, clicked)) = labeled_box(ui, label, fs) else { return false };
+    if clicked {
+        *state = match *state {
+            CheckState::Checked => CheckState::Unchecked,
+            _                   => CheckState::Checked,
+        };
+    }
+    draw_check_frame(ui, rect, hovered);
+    draw_check_mark(ui, rect, *state);
+    clicked
+}
+
+/// Checkbox bound to `flag` inside a `bitflags` value. Shows "mixed" when
+/// `flag` has several bits and only some are set; clicking sets or clears
+/// all of them.
+pub fn checkbox_flags<F>(ui: &mut Ui<'_>, label: &str, flags: &mut F, flag: F) -> bool
+where
+    F: bitflags::Flags + Copy,
+{
+    let mut state = if flags.contains(flag) {
+        CheckState::Checked
+    } else if flags.intersects(flag) {
+        CheckState::Mixed
+    } else {
+        CheckState::Unchecked
+    };
+    let clicked = checkbox_tristate(ui, label, &mut state);
+    if clicked { flags.set(flag, state == CheckState::Checked); }
+    clicked
+}
+
+/// Radio button: shows as selected while `*value == this_value` and sets
+/// it when clicked. Returns `true` when clicked.
+pub fn radio_button<T: PartialEq + Clone>(ui: &mut Ui<'_>, label: &str, value: &mut T, this_value: T) -> bool {
+    let fs = ui.ctx.style.font_size;
+    let Some((rect, hovered, clicked)) = labeled_box(ui, label, fs) else { return false };
+    if clicked { *value = this_value.clone(); }
+
+    let bg = if hovered { StyleColor::FrameBgHovered } else { StyleColor::FrameBg };
+    let bg     = ui.ctx.style.color(bg);
+    let border = ui.ctx.style.color(StyleColor::Border);
+    let ck     = ui.ctx.style.color(StyleColor::CheckMark);
+    let c = rect.center();
+    let r = fs * 0.5;
+    let draw = &mut ui.ctx.draw_list;
+    draw.filled_circle(c, r, border, 16);
+    draw.filled_circle(c, r - 1.0, bg, 16);
+    if *value == this_value {
+        draw.filled_circle(c, r * 0.5, ck, 12);
+    }
+    clicked
+}
+
+/// iOS-style switch. The knob slides over `TOGGLE_ANIM_TIME` seconds.
+///
+/// Storage: `float[0]` knob position (0 = off, 1 = on), `int[0]` set once
+/// initialised.
+pub fn toggle(ui: &mut Ui<'_>, label: &str, v: &mut bool) -> bool {
+    let (_, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+    let fs = ui.ctx.style.font_size;
+    let Some((rect, hovered, clicked)) = labeled_box(ui, label, fs * 1.8) else { return false };
+    if clicked { *v = !*v; }
+
+    let target = if *v { 1.0 } else { 0.0 };
+    let dt     = ui.ctx.delta_time;
+    let s      = ui.ctx.get_storage_mut(id);
+    if s.int[0] == 0 {
+        s.int[0]   = 1;
+        s.float[0] = target;
+    }
+    let step = dt / TOGGLE_ANIM_TIME;
+    s.float[0] = if s.float[0] < target { (s.float[0] + step).min(target) } else { (s.float[0] - step).max(target) };
+    let t = s.float[0];
+
+    let off  = ui.ctx.style.color(if hovered { StyleColor::FrameBgHovered } else { StyleColor::FrameBg });
+    let on   = ui.ctx.style.color(StyleColor::CheckMark);
+    let knob = ui.ctx.style.color(StyleColor::Text);
+    let r    = rect.height() * 0.5;
+    let draw = &mut ui.ctx.draw_list;
+    draw.filled_rect(rect, r, off.lerp(on, t));
+    let kx = rect.min.x + r + (rect.width() - r * 2.0) * t;
+    draw.filled_circle(Vec2::new(kx, rect.min.y + r), r - 2.0, knob, 16);
+    clicked
+}
@@ -0,0 +1,564 @@
+//! Code-editor widget – a multi-line text box with a monospace grid.
+//!
+//! - `code_editor`    – editor sized to the available width, 16 lines tall
+//! - `code_editor_ex` – explicit size plus per-line error markers
+//!
+//! Syntax colouring is delegated to a [`Highlighter`], which splits one line
+//! at a time into coloured byte ranges.
+
+use std::ops::Range;
+
+use crate::{
+    draw_list::TextureId,
+    input::{Key, MouseButton},
+    style::StyleColor,
+    ui::Ui,
+    Color, Rect, Vec2,
+};
+
+/// Spaces inserted by Tab and removed by Shift+Tab.
+const INDENT: &str = "    ";
+const DEFAULT_LINES: f32 = 16.0;
+const GUTTER_PAD: f32 = 6.0;
+const MARKER_W: f32 = 8.0;
+
+// ─── Highlighting ────────────────────────────────────────────────────────────
+
+/// A coloured byte range within a single line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighlightSpan {
+    pub range: Range<usize>,
+    pub color: Color,
+}
+
+/// Tokenizer used by the code editor to colour each line.
+///
+/// Bytes not covered by any span are drawn with `StyleColor::Text`.
+pub trait Highlighter {
+    /// Append the coloured spans of `line` (without its trailing `\n`) to `out`.
+    fn highlight_line(&self, line: &str, out: &mut Vec<HighlightSpan>);
+}
+
+/// Highlighter that leaves every line uncoloured.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlainText;
+
+impl Highlighter for PlainText {
+    fn highlight_line(&self, _line: &str, _out: &mut Vec<HighlightSpan>) {}
+}
+
+/// Keyword / number / string / line-comment highlighter for C-like languages
+/// (HLSL, GLSL, Lua-ish scripts, …).
+#[derive(Debug, Clone)]
+pub struct SimpleHighlighter {
+    pub keywords:     Vec<&'static str>,
+    pub types:        Vec<&'static str>,
+    pub line_comment: &'static str,
+    pub keyword_color: Color,
+    pub type_color:    Color,
+    pub number_color:  Color,
+    pub string_color:  Color,
+    pub comment_color: Color,
+}
+
+impl SimpleHighlighter {
+    pub fn new(keywords: &[&'static str], types: &[&'static str], line_comment: &'static str) -> Self {
+        Self {
+            keywords:      keywords.to_vec(),
+            types:         types.to_vec(),
+            line_comment,
+            keyword_color: Color::from_hex(0xc586c0),
+            type_color:    Color::from_hex(0x4ec9b0),
+            number_color:  Color::from_hex(0xb5cea8),
+            string_color:  Color::from_hex(0xce9178),
+            comment_color: Color::from_hex(0x6a9955),
+        }
+    }
+
+    /// Preset for HLSL shader snippets.
+    pub fn hlsl() -> Self {
+        Self::new(
+            &["if", "else", "for", "while", "do", "return", "break", "continue", "discard",
+              "struct", "cbuffer", "register", "static", "const", "in", "out", "inout",
+              "uniform", "true", "false"],
+            &["void", "bool", "int", "uint", "float", "float2", "float3", "float4",
+              "float4x4", "half", "half4", "Texture2D", "SamplerState"],
+            "//",
+        )
+    }
+}
+
+impl Highlighter for SimpleHighlighter {
+    fn highlight_line(&self, line: &str, out: &mut Vec<HighlightSpan>) {
+        let bytes = line.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            if !self.line_comment.is_empty() && line[i..].starts_with(self.line_comment) {
+                out.push(HighlightSpan { range: i..bytes.len(), color: self.comment_color });
+                return;
+            }
+            if b == b'"' || b == b'\'' {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i] != b {
+                    if bytes[i] == b'\\' { i += 1; }
+                    i += 1;
+                }
+                i = (i + 1).min(bytes.len());
+                out.push(HighlightSpan { range: start..i, color: self.string_color });
+            } else if b.is_ascii_digit() {
+                let start = i;
+                while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'.') { i += 1; }
+                out.push(HighlightSpan { range: start..i, color: self.number_color });
+            } else if b.is_ascii_alphabetic() || b == b'_' {
+                let start = i;
+                while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') { i += 1; }
+                let word = &line[start..i];
+                if self.keywords.contains(&word) {
+                    out.push(HighlightSpan { range: start..i, color: self.keyword_color });
+                } else if self.types.contains(&word) {
+                    out.push(HighlightSpan { range: start..i, color: self.type_color });
+                }
+            } else {
+                i += line[i..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+    }
+}
+
+/// An error / warning attached to a (0-based) line.
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorMarker<'a> {
+    pub line:    usize,
+    pub message: &'a str,
+}
+
+// ─── Widget ──────────────────────────────────────────────────────────────────
+
+pub fn code_editor(ui: &mut Ui<'_>, id: &str, buf: &mut String, highlighter: &dyn Highlighter) -> bool {
+    code_editor_ex(ui, id, buf, highlighter, Vec2::ZERO, &[])
+}
+
+/// Storage layout: `int[0]` cursor byte, `int[1]` selection anchor byte,
+/// `int[2]` preferred column for vertical moves, `float[0]` vertical scroll.
+pub fn code_editor_ex(
+    ui:          &mut Ui<'_>,
+    id_str:      &str,
+    buf:         &mut String,
+    highlighter: &dyn Highlighter,
+    mut size:    Vec2,
+    markers:     &[ErrorMarker<'_>],
+) -> bool {
+    let id = ui.ctx.make_id(id_str);
+
+    let fs     = ui.ctx.style.font_size * ui.scale;
+    let fp     = ui.ctx.style.frame_padding;
+    let line_h = fs + 2.0;
+    let adv    = ui.text_width("M").max(1.0);
+    if size.x <= 0.0 { size.x = ui.available_width(); }
+    if size.y <= 0.0 { size.y = line_h * DEFAULT_LINES + fp.1 * 2.0; }
+
+    let pos = match ui.layout_next(size) { Some(p) => p, None => return false };
+    let box_rect = Rect::from_min_size(pos, size);
+
+    let (hovered, held, _) = ui.ctx.button_behavior(id, box_rect);
+    let focused = ui.ctx.focus_item == Some(id);
+
+    // ── Restore state ────────────────────────────────────────────────────────
+    let (mut cursor, mut anchor, mut pref_col, mut scroll) = ui.ctx.get_storage(id)
+        .map_or((buf.len(), buf.len(), -1, 0.0), |s| {
+            (s.int[0].max(0) as usize, s.int[1].max(0) as usize, s.int[2], s.float[0])
+        });
+    cursor = floor_char_boundary(buf, cursor);
+    anchor = floor_char_boundary(buf, anchor);
+
+    let mut lines = line_starts(buf);
+    let digits    = lines.len().to_string().len().max(2) as f32;
+    let gutter_w  = MARKER_W + digits * adv + GUTTER_PAD * 2.0;
+    let text_x    = pos.x + gutter_w + fp.0;
+    let text_top  = pos.y + fp.1;
+    let visible_h = size.y - fp.1 * 2.0;
+
+    // ── Mouse ────────────────────────────────────────────────────────────────
+    let mouse = ui.ctx.input.mouse_pos;
+    let hit_cursor = |lines: &[usize], buf: &str, scroll: f32| {
+        let row = (((mouse.y - text_top + scroll) / line_h).floor().max(0.0) as usize).min(lines.len() - 1);
+        let col = ((mouse.x - text_x) / adv).round().max(0.0) as usize;
+        offset_at(buf, lines, row, col)
+    };
+    if hovered && ui.ctx.input.mouse_clicked(MouseButton::Left) {
+        cursor = hit_cursor(&lines, buf, scroll);
+        if !ui.ctx.input.shift() { anchor = cursor; }
+        pref_col = -1;
+    } else if held && ui.ctx.input.mouse_down(MouseButton::Left) {
+        cursor = hit_cursor(&lines, buf, scroll);
+    }
+    if hovered && ui.ctx.input.mouse_wheel != 0.0 {
+        scroll -= ui.ctx.input.mouse_wheel * line_h * 3.0;
+    }
+
+    // ── Keyboard ─────────────────────────────────────────────────────────────
+    let mut changed = false;
+    let mut moved   = false;
+    if focused {
+        let input  = &ui.ctx.input;
+        let shift  = input.shift();
+        let ctrl   = input.ctrl();
+        let page   = (visible_h / line_h).floor().max(1.0) as usize;
+        let (row, col) = row_col(buf, &lines, cursor);
+        let want_col   = if pref_col >= 0 { pref_col as usize } else { col };
+
+        let mut target = None;
+        if input.key_pressed(Key::Left)  { target = Some(prev_char_boundary(buf, cursor)); }
+        if input.key_pressed(Key::Right) { target = Some(next_char_boundary(buf, cursor)); }
+        if input.key_pressed(Key::Home)  { target = Some(if ctrl { 0 } else { lines[row] }); }
+        if input.key_pressed(Key::End)   { target = Some(if ctrl { buf.len() } else { line_end(buf, &lines, row) }); }
+        let vertical = [
+            (Key::Up,       row.checked_sub(1)),
+            (Key::Down,     Some(row + 1)),
+            (Key::PageUp,   Some(row.saturating_sub(page))),
+            (Key::PageDown, Some(row + page)),
+        ];
+        for (key, to_row) in vertical {
+            if input.key_pressed(key) {
+                let to_row = to_row.unwrap_or(0).min(lines.len() - 1);
+                target   = Some(offset_at(buf, &lines, to_row, want_col));
+                pref_col = want_col as i32;
+            }
+        }
+        if ctrl && input.key_pressed(Key::A) {
+            anchor = 0;
+            cursor = buf.len();
+            moved  = true;
+        }
+        if let Some(t) = target {
+            let is_vertical = vertical.iter().any(|&(k, _)| input.key_pressed(k));
+            if !is_vertical { pref_col = -1; }
+            if !shift && anchor != cursor && !is_vertical
+                && (input.key_pressed(Key::Left) || input.key_pressed(Key::Right))
+            {
+                // Collapse the selection towards the arrow direction
+                let (lo, hi) = (anchor.min(cursor), anchor.max(cursor));
+                cursor = if input.key_pressed(Key::Left) { lo } else { hi };
+            } else {
+                cursor = t;
+            }
+            if !shift { anchor = cursor; }
+            moved = true;
+        }
+
+        let typed: String = input.text_input.chars().filter(|c| !c.is_control()).collect();
+        let enter     = input.key_pressed(Key::Enter);
+        let tab       = input.key_pressed(Key::Tab);
+        let backspace = input.key_pressed(Key::Backspace);
+        let delete    = input.key_pressed(Key::Delete);
+        let escape    = input.key_pressed(Key::Escape);
+
+        if tab && (shift || lines_spanned(buf, &lines, anchor, cursor) > 1) {
+            // Block (un)indent every line touched by the selection
+            let (first, last) = selection_rows(buf, &lines, anchor, cursor);
+            let (a, c) = indent_rows(buf, &lines, first, last, !shift, anchor, cursor);
+            anchor = a;
+            cursor = c;
+            changed = true;
+        } else if tab {
+            cursor = replace_selection(buf, &mut anchor, cursor, INDENT);
+            changed = true;
+        } else if enter {
+            let (row, _) = row_col(buf, &lines, anchor.min(cursor));
+            let line     = &buf[lines[row]..line_end(buf, &lines, row)];
+            let mut ins  = String::from("\n");
+            ins.extend(line.chars().take_while(|c| *c == ' ' || *c == '\t'));
+            let before = buf[lines[row]..anchor.min(cursor)].trim_end();
+            if before.ends_with(['{', '(', '[']) { ins.push_str(INDENT); }
+            cursor = replace_selection(buf, &mut anchor, cursor, &ins);
+            changed = true;
+        } else if !typed.is_empty() {
+            cursor = replace_selection(buf, &mut anchor, cursor, &typed);
+            changed = true;
+        } else if backspace || delete {
+            if anchor == cursor {
+                if backspace { anchor = prev_char_boundary(buf, cursor); }
+                else         { anchor = next_char_boundary(buf, cursor); }
+            }
+            if anchor != cursor {
+                cursor = replace_selection(buf, &mut anchor, cursor, "");
+                changed = true;
+            }
+        }
+        if escape { ui.ctx.focus_item = None; }
+        if changed { pref_col = -1; moved = true; lines = line_starts(buf); }
+    }
+
+    // ── Scrolling ────────────────────────────────────────────────────────────
+    let (cur_row, cur_col) = row_col(buf, &lines, cursor);
+    if moved {
+        let cy = cur_row as f32 * line_h;
+        if cy < scroll { scroll = cy; }
+        if cy + line_h > scroll + visible_h { scroll = cy + line_h - visible_h; }
+    }
+    let max_scroll = (lines.len() as f32 * line_h - visible_h).max(0.0);
+    scroll = scroll.clamp(0.0, max_scroll);
+
+    {
+        let s = ui.ctx.get_storage_mut(id);
+        s.int[0]   = cursor as i32;
+        s.int[1]   = anchor as i32;
+        s.int[2]   = pref_col;
+        s.float[0] = scroll;
+    }
+
+    // ── Drawing ──────────────────────────────────────────────────────────────
+    let style      = &ui.ctx.style;
+    let rounding   = style.frame_rounding;
+    let bg         = style.color(StyleColor::FrameBg);
+    let gutter_bg  = style.color(StyleColor::ChildBg).lerp(Color::BLACK.with_alpha(0.25), 0.5);
+    let border     = if focused { style.color(StyleColor::SliderGrab) } else { style.color(StyleColor::Border) };
+    let line_hl    = style.color(StyleColor::FrameBgHovered).with_alpha(0.5);
+    let sel_col    = style.color(StyleColor::Header).with_alpha(0.45);
+    let text_col   = style.color(StyleColor::Text);
+    let num_col    = style.color(StyleColor::TextDisabled);
+    let match_col  = style.color(StyleColor::CheckMark);
+    let error_col  = Color::from_hex(0xe05050);
+
+    let gutter_rect = Rect::from_min_size(pos, Vec2::new(gutter_w, size.y));
+    {
+        let draw = &mut ui.ctx.draw_list;
+        draw.filled_rect(box_rect, rounding, bg);
+        draw.filled_rect(gutter_rect, 0.0, gutter_bg);
+        draw.push_clip_rect(box_rect);
+    }
+
+    let first_row = (scroll / line_h).floor() as usize;
+    let last_row  = (((scroll + visible_h) / line_h).ceil() as usize + 1).min(lines.len());
+    let (sel_lo, sel_hi) = (anchor.min(cursor), anchor.max(cursor));
+    let brackets = if focused { matching_brackets(buf, cursor) } else { None };
+    let mut spans = Vec::new();
+
+    for row in first_row..last_row {
+        let y     = text_top + row as f32 * line_h - scroll;
+        let start = lines[row];
+        let end   = line_end(buf, &lines, row);
+        let line  = &buf[start..end];
+        let row_rect = Rect::new(Vec2::new(gutter_rect.max.x, y), Vec2::new(box_rect.max.x, y + line_h));
+
+        if focused && row == cur_row && sel_lo == sel_hi {
+            ui.ctx.draw_list.filled_rect(row_rect, 0.0, line_hl);
+        }
+        if sel_lo < sel_hi && sel_lo <= end && sel_hi >= start {
+            let c0 = buf[start..sel_lo.max(start)].chars().count() as f32;
+            let c1 = buf[start..sel_hi.min(end)].chars().count() as f32;
+            let extra = if sel_hi > end { adv * 0.5 } else { 0.0 };
+            let r = Rect::new(Vec2::new(text_x + c0 * adv, y), Vec2::new(text_x + c1 * adv + extra, y + line_h));
+            ui.ctx.draw_list.filled_rect(r, 0.0, sel_col);
+        }
+        if let Some((a, b)) = brackets {
+            for at in [a, b] {
+                if (start..end).contains(&at) {
+                    let c = buf[start..at].chars().count() as f32;
+                    let r = Rect::from_min_size(Vec2::new(text_x + c * adv, y), Vec2::new(adv, line_h));
+                    ui.ctx.draw_list.rect_outline(r, 1.0, match_col);
+                }
+            }
+        }
+
+        // Error marker + squiggle
+        if let Some(m) = markers.iter().find(|m| m.line == row) {
+            let cy = y + line_h * 0.5;
+            ui.ctx.draw_list.filled_circle(Vec2::new(pos.x + MARKER_W * 0.5 + 2.0, cy), 3.0, error_col, 8);
+            let used  = line.chars().count().max(1) as f32;
+            let base  = y + line_h - 1.0;
+            let mut x = text_x;
+            while x < text_x + used * adv {
+                let nx = (x + 3.0).min(text_x + used * adv);
+                let up = ((x - text_x) / 3.0) as i32 % 2 == 0;
+                let (y0, y1) = if up { (base, base - 2.0) } else { (base - 2.0, base) };
+                ui.ctx.draw_list.line(Vec2::new(x, y0), Vec2::new(nx, y1), 1.0, error_col);
+                x = nx;
+            }
+            let marker_rect = Rect::new(Vec2::new(pos.x, y), Vec2::new(text_x + used * adv, y + line_h));
+            if marker_rect.contains(mouse) {
+                ui.set_tooltip(m.message);
+            }
+        }
+
+        // Line number (right-aligned)
+        let num   = (row + 1).to_string();
+        let num_x = pos.x + gutter_w - GUTTER_PAD - num.len() as f32 * adv;
+        let nc    = if row == cur_row && focused { text_col } else { num_col };
+        draw_mono(ui, &num, Vec2::new(num_x, y + 1.0), adv, nc);
+
+        // Text, span by span
+        spans.clear();
+        highlighter.highlight_line(line, &mut spans);
+        let mut col = 0usize;
+        let mut at  = 0usize;
+        for span in spans.iter().filter(|s| s.range.start < s.range.end && s.range.end <= line.len()) {
+            if span.range.start < at { continue; }
+            let plain = &line[at..span.range.start];
+            draw_mono(ui, plain, Vec2::new(text_x + col as f32 * adv, y + 1.0), adv, text_col);
+            col += plain.chars().count();
+            let coloured = &line[span.range.clone()];
+            draw_mono(ui, coloured, Vec2::new(text_x + col as f32 * adv, y + 1.0), adv, span.color);
+            col += coloured.chars().count();
+            at = span.range.end;
+        }
+        draw_mono(ui, &line[at..], Vec2::new(text_x + col as f32 * adv, y + 1.0), adv, text_col);
+    }
+
+    // Caret
+    if focused && (ui.ctx.input.frame_count / 30) & 1 == 0 {
+        let cx = text_x + cur_col as f32 * adv;
+        let cy = text_top + cur_row as f32 * line_h - scroll;
+        ui.ctx.draw_list.line(Vec2::new(cx, cy), Vec2::new(cx, cy + line_h), 1.0, text_col);
+    }
+
+    {
+        let draw = &mut ui.ctx.draw_list;
+        draw.pop_clip_rect();
+        draw.rect_outline(box_rect, if focused { 2.0 } else { 1.0 }, border);
+    }
+
+    changed
+}
+
+// ─── Rendering helpers ───────────────────────────────────────────────────────
+
+/// Draw `text` with every glyph on a fixed `advance` grid.
+fn draw_mono(ui: &mut Ui<'_>, text: &str, pos: Vec2, advance: f32, col: Color) {
+    if text.is_empty() { return; }
+    let fs = ui.ctx.style.font_size * ui.scale;
+    let mut glyphs = Vec::with_capacity(text.len());
+    for (i, ch) in text.chars().enumerate() {
+        if let Some(g) = ui.font.glyph(ch, fs) {
+            let x     = pos.x + i as f32 * advance + (advance - g.advance_x) * 0.5;
+            let p_min = Vec2::new(x, pos.y + g.offset_y);
+            glyphs.push((p_min, p_min + g.size, g.uv_min, g.uv_max));
+        }
+    }
+    ui.ctx.draw_list.push_texture(TextureId::FONT);
+    ui.ctx.draw_list.add_text_raw(&glyphs, col);
+    ui.ctx.draw_list.pop_texture();
+}
+
+// ─── Buffer helpers ──────────────────────────────────────────────────────────
+
+/// Byte offset of the first character of every line.
+fn line_starts(buf: &str) -> Vec<usize> {
+    std::iter::once(0).chain(buf.match_indices('\n').map(|(i, _)| i + 1)).collect()
+}
+
+fn line_end(buf: &str, lines: &[usize], row: usize) -> usize {
+    lines.get(row + 1).map_or(buf.len(), |&next| next - 1)
+}
+
+fn row_col(buf: &str, lines: &[usize], at: usize) -> (usize, usize) {
+    let row = lines.partition_point(|&s| s <= at).saturating_sub(1);
+    (row, buf[lines[row]..at].chars().count())
+}
+
+/// Byte offset of character column `col` on `row`, clamped to the line end.
+fn offset_at(buf: &str, lines: &[usize], row: usize, col: usize) -> usize {
+    let start = lines[row];
+    let end   = line_end(buf, lines, row);
+    buf[start..end].char_indices().nth(col).map_or(end, |(i, _)| start + i)
+}
+
+fn floor_char_boundary(buf: &str, mut at: usize) -> usize {
+    at = at.min(buf.len());
+    while !buf.is_char_boundary(at) { at -= 1; }
+    at
+}
+
+fn prev_char_boundary(buf: &str, at: usize) -> usize {
+    buf[..at].char_indices().next_back().map_or(0, |(i, _)| i)
+}
+
+fn next_char_boundary(buf: &str, at: usize) -> usize {
+    buf[at..].chars().next().map_or(at, |c| at + c.len_utf8())
+}
+
+/// Replace the `anchor..cursor` selection with `text`; returns the new cursor.
+fn replace_selection(buf: &mut String, anchor: &mut usize, cursor: usize, text: &str) -> usize {
+    let (lo, hi) = ((*anchor).min(cursor), (*anchor).max(cursor));
+    buf.replace_range(lo..hi, text);
+    *anchor = lo + text.len();
+    *anchor
+}
+
+fn selection_rows(buf: &str, lines: &[usize], anchor: usize, cursor: usize) -> (usize, usize) {
+    let (lo, hi) = (anchor.min(cursor), anchor.max(cursor));
+    let first    = row_col(buf, lines, lo).0;
+    let mut last = row_col(buf, lines, hi).0;
+    // A selection ending at column 0 does not include that line
+    if last > first && lines[last] == hi { last -= 1; }
+    (first, last)
+}
+
+fn lines_spanned(buf: &str, lines: &[usize], anchor: usize, cursor: usize) -> usize {
+    if anchor == cursor { return 1; }
+    let (first, last) = selection_rows(buf, lines, anchor, cursor);
+    last - first + 1
+}
+
+/// Indent (or unindent) rows `first..=last`, returning the adjusted
+/// `(anchor, cursor)` so the selection keeps covering the same text.
+fn indent_rows(
+    buf:    &mut String,
+    lines:  &[usize],
+    first:  usize,
+    last:   usize,
+    indent: bool,
+    mut anchor: usize,
+    mut cursor: usize,
+) -> (usize, usize) {
+    for row in (first..=last).rev() {
+        let start = lines[row];
+        let (delta, removed) = if indent {
+            buf.insert_str(start, INDENT);
+            (INDENT.len() as isize, 0)
+        } else {
+            let line = &buf[start..];
+            let n = line.bytes().take(INDENT.len()).take_while(|&b| b == b' ').count();
+            let n = if n == 0 && line.starts_with('\t') { 1 } else { n };
+            buf.replace_range(start..start + n, "");
+            (-(n as isize), n)
+        };
+        for at in [&mut anchor, &mut cursor] {
+            if *at >= start + removed {
+                *at = (*at as isize + delta) as usize;
+            } else if *at > start {
+                *at = start;
+            }
+        }
+    }
+    (anchor, cursor)
+}
+
+/// If the character at (or just before) `cursor` is a bracket, return the byte
+/// offsets of it and its partner.
+fn matching_brackets(buf: &str, cursor: usize) -> Option<(usize, usize)> {
+    const PAIRS: [(u8, u8); 3] = [(b'(', b')'), (b'[', b']'), (b'{', b'}')];
+    let bytes = buf.as_bytes();
+    let candidates = [Some(cursor), cursor.checked_sub(1)];
+    for at in candidates.into_iter().flatten().filter(|&i| i < bytes.len()) {
+        let b = bytes[at];
+        for &(open, close) in &PAIRS {
+            let mut depth = 0i32;
+            if b == open {
+                for (i, &c) in bytes.iter().enumerate().skip(at) {
+                    if c == open { depth += 1; }
+                    if c == close { depth -= 1; if depth == 0 { return Some((at, i)); } }
+                }
+            } else if b == close {
+                for i in (0..=at).rev() {
+                    let c = bytes[i];
+                    if c == close { depth += 1; }
+                    if c == open { depth -= 1; if depth == 0 { return Some((i, at)); } }
+                }
+            }
+        }
+    }
+    None
+}
@@ -1,106 +1,302 @@
 //! Color-editor and color-picker widgets.
 //!
-//! - `color_edit4`   – compact RGBA row with a preview swatch
-//! - `color_picker4` – full HSV wheel + preview
+//! - `color_edit4`   – compact row: swatch + RGB / HSV / HSL / hex inputs
+//! - `color_picker4` – SV square + hue bar (or hue wheel + triangle),
+//!   preset palette and recently picked colours
+//! - `color_button`  – a bare swatch
+//!
+//! Every swatch is a drag-and-drop source for [`PAYLOAD_COLOR`]; editor
+//! rows accept it. Right-click an editor's inputs to switch display mode.
+
+use std::f32::consts::TAU;
 
 use crate::{
-    id::parse_label,
+    drag_drop::PAYLOAD_COLOR,
+    id::{parse_label, Id},
+    input::MouseButton,
     style::StyleColor,
     ui::Ui,
+    widgets::slider::{begin_text_entry, drag_behavior, text_entry, DragArgs, TextEntry},
     Color, Rect, Vec2,
 };
 
-// ─── Color edit ──────────────────────────────────────────────────────────────
+bitflags::bitflags! {
+    /// Options for `color_edit4_ex`, `color_picker4_ex` and `color_button`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct ColorEditFlags: u32 {
+        /// Ignore and don't edit the alpha channel.
+        const NO_ALPHA         = 1 << 0;
+        /// Clicking the swatch doesn't open the picker.
+        const NO_PICKER        = 1 << 1;
+        /// Swatch only, no inputs.
+        const NO_INPUTS        = 1 << 2;
+        /// Swatches are neither drag sources nor drop targets.
+        const NO_DRAG_DROP     = 1 << 3;
+        /// Hide the palette and history rows under the picker.
+        const NO_PALETTE       = 1 << 4;
+        const DISPLAY_RGB      = 1 << 5;
+        const DISPLAY_HSV      = 1 << 6;
+        const DISPLAY_HSL      = 1 << 7;
+        const DISPLAY_HEX      = 1 << 8;
+        /// Hue ring around an SV triangle instead of square + bar.
+        const PICKER_HUE_WHEEL = 1 << 9;
+    }
+}
 
-pub fn color_edit4(ui: &mut Ui<'_>, label: &str, color: &mut [f32; 4], alpha: bool) -> bool {
-    let (text, id_src) = parse_label(label);
-    let id = ui.ctx.make_id(id_src);
+/// Preset colours shown under the picker.
+const PALETTE: [u32; 12] = [
+    0xE53935, 0xFB8C00, 0xFDD835, 0x7CB342, 0x43A047, 0x00897B,
+    0x00ACC1, 0x1E88E5, 0x5E35B1, 0xD81B60, 0xFFFFFF, 0x000000,
+];
+/// Picked colours remembered for the history row.
+const HISTORY_MAX: usize = 12;
+/// Width of the hue bar beside the SV square.
+const HUE_BAR_W:   f32 = 16.0;
+
+// ─── Hex ─────────────────────────────────────────────────────────────────────
+
+/// Parse `#RRGGBB` or `#RRGGBBAA` (the `#` is optional).
+pub fn parse_hex(s: &str) -> Option<[f32; 4]> {
+    let hex = s.trim().trim_start_matches('#');
+    if !matches!(hex.len(), 6 | 8) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) { return None; }
+    let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok().map(|b| b as f32 / 255.0);
+    Some([byte(0)?, byte(2)?, byte(4)?, if hex.len() == 8 { byte(6)? } else { 1.0 }])
+}
 
-    let fs   = ui.ctx.style.font_size;
-    let fp   = ui.ctx.style.frame_padding;
-    let sp   = ui.ctx.style.item_spacing;
-    let h    = fs + fp.1 * 2.0;
-    let tw   = ui.text_width(text);
-    let avail = ui.available_width();
-
-    // Layout: [swatch][R][G][B][A?]  label
-    let swatch_w = h;
-    let n_fields = if alpha { 4 } else { 3 };
-    let fields_w = (avail - swatch_w - sp.0 - tw - sp.0 * (n_fields as f32)) / n_fields as f32;
-    let fields_w = fields_w.max(30.0);
-    let total_w  = swatch_w + sp.0 + fields_w * n_fields as f32 + sp.0 * (n_fields as f32) + tw;
-    let total    = Vec2::new(total_w.min(avail), h);
-
-    let pos = match ui.layout_next(total) { Some(p) => p, None => return false };
-
-    // Colour swatch (opens picker on click)
-    let swatch_rect = Rect::from_min_size(pos, Vec2::new(swatch_w, h));
-    let swatch_col  = Color::new(color[0], color[1], color[2], if alpha { color[3] } else { 1.0 });
-
-    let (sh, _, sc) = ui.ctx.button_behavior(id, swatch_rect);
-    {
+/// `#RRGGBB`, or `#RRGGBBAA` with `alpha`.
+pub fn format_hex(color: [f32; 4], alpha: bool) -> String {
+    let b = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let rgb = format!("#{:02X}{:02X}{:02X}", b(color[0]), b(color[1]), b(color[2]));
+    if alpha { format!("{rgb}{:02X}", b(color[3])) } else { rgb }
+}
+
+// ─── Display modes ───────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DisplayMode { Rgb, Hsv, Hsl, Hex }
+
+impl DisplayMode {
+    const ALL: [Self; 4] = [Self::Rgb, Self::Hsv, Self::Hsl, Self::Hex];
+
+    fn from_flags(flags: ColorEditFlags) -> Self {
+        if flags.contains(ColorEditFlags::DISPLAY_HSV)      { Self::Hsv }
+        else if flags.contains(ColorEditFlags::DISPLAY_HSL) { Self::Hsl }
+        else if flags.contains(ColorEditFlags::DISPLAY_HEX) { Self::Hex }
+        else                                                { Self::Rgb }
+    }
+}
+
+/// HSV of `color`, keeping the hue remembered in `float[0]` of `id` while
+/// the colour itself has none (greys).
+fn stable_hsv(ui: &mut Ui<'_>, id: Id, color: [f32; 4]) -> (f32, f32, f32) {
+    let (h, s, v) = Color::from(color).to_hsv();
+    let st = ui.ctx.get_storage_mut(id);
+    if s > 0.0 && v > 0.0 { st.float[0] = h; (h, s, v) } else { (st.float[0], s, v) }
+}
+
+fn push_history(ui: &mut Ui<'_>, color: [f32; 4]) {
+    let history = &mut ui.ctx.color_history;
+    history.retain(|c| *c != color);
+    history.insert(0, color);
+    history.truncate(HISTORY_MAX);
+}
+
+// ─── Swatches ────────────────────────────────────────────────────────────────
+
+/// Fill `rect` with `color`, over a checkerboard when it is translucent.
+pub(crate) fn draw_swatch(ui: &mut Ui<'_>, rect: Rect, color: [f32; 4], rounding: f32) {
+    if color[3] < 1.0 {
+        let cell = (rect.height() * 0.5).clamp(3.0, 8.0);
         let draw = &mut ui.ctx.draw_list;
-        // Checker background for alpha
-        if alpha && color[3] < 1.0 {
-            let check_col = Color::from_hex(0x808080);
-            draw.filled_rect(swatch_rect, ui.ctx.style.frame_rounding, check_col);
+        draw.filled_rect(rect, rounding, Color::LIGHT_GRAY);
+        let (nx, ny) = ((rect.width() / cell).ceil() as usize, (rect.height() / cell).ceil() as usize);
+        for iy in 0..ny {
+            for ix in (iy % 2..nx).step_by(2) {
+                let min = rect.min + Vec2::new(ix as f32 * cell, iy as f32 * cell);
+                let max = Vec2::new((min.x + cell).min(rect.max.x), (min.y + cell).min(rect.max.y));
+                draw.filled_rect(Rect::new(min, max), 0.0, Color::GRAY);
+            }
         }
-        draw.filled_rect(swatch_rect, ui.ctx.style.frame_rounding, swatch_col);
-        draw.rect_outline(swatch_rect, 1.0, ui.ctx.style.color(StyleColor::Border));
     }
+    ui.ctx.draw_list.filled_rect(rect, rounding, Color::from(color));
+}
 
-    // Toggle picker popup on click
-    if sc {
-        let currently = ui.ctx.get_storage(id).map_or(false, |s| s.active);
-        ui.ctx.get_storage_mut(id).active = !currently;
+/// Clickable swatch that can be dragged away. Returns `true` on click.
+fn swatch_behavior(ui: &mut Ui<'_>, id: Id, rect: Rect, color: [f32; 4], flags: ColorEditFlags) -> bool {
+    let (hovered, _, clicked) = ui.ctx.button_behavior(id, rect);
+    let rounding = ui.ctx.style.frame_rounding;
+    draw_swatch(ui, rect, color, rounding);
+    let border = if hovered { ui.ctx.style.color(StyleColor::Text) } else { ui.ctx.style.color(StyleColor::Border) };
+    ui.ctx.draw_list.rect_outline(rect, 1.0, border);
+
+    let dragging = !flags.contains(ColorEditFlags::NO_DRAG_DROP)
+        && ui.ctx.drag_drop_source(id, PAYLOAD_COLOR, || bytemuck::bytes_of(&color).to_vec());
+    if dragging {
+        let fs = ui.ctx.style.font_size;
+        let preview = Rect::from_min_size(ui.ctx.input.mouse_pos + Vec2::new(12.0, 12.0), Vec2::splat(fs * 1.5));
+        draw_swatch(ui, preview, color, rounding);
+        ui.ctx.draw_list.rect_outline(preview, 1.0, ui.ctx.style.color(StyleColor::Border));
+    } else if hovered {
+        ui.set_tooltip(&format_hex(color, !flags.contains(ColorEditFlags::NO_ALPHA)));
     }
+    clicked && !dragging
+}
 
-    let mut changed = false;
+/// A row of small swatches; returns the one clicked.
+fn swatch_row(ui: &mut Ui<'_>, row_id: Id, pos: Vec2, width: f32, colors: &[[f32; 4]], flags: ColorEditFlags) -> Option<[f32; 4]> {
+    let gap = 2.0;
+    let sz  = (width - gap * (PALETTE.len() - 1) as f32) / PALETTE.len() as f32;
+    let mut picked = None;
+    for (i, &c) in colors.iter().enumerate() {
+        let rect = Rect::from_min_size(Vec2::new(pos.x + i as f32 * (sz + gap), pos.y), Vec2::splat(sz));
+        if swatch_behavior(ui, row_id.combine(Id::from_hash(&i)), rect, c, flags) { picked = Some(c); }
+    }
+    picked
+}
+
+/// Standalone swatch showing `color`; `size` ≤ 0 → frame height square.
+/// Returns `true` when clicked. Drag it onto a colour editor to copy it.
+pub fn color_button(ui: &mut Ui<'_>, desc_id: &str, color: [f32; 4], size: Vec2, flags: ColorEditFlags) -> bool {
+    let (_, id_src) = parse_label(desc_id);
+    let id = ui.ctx.make_id(id_src);
+    let h  = ui.ctx.style.font_size + ui.ctx.style.frame_padding.1 * 2.0;
+    let size = Vec2::new(if size.x > 0.0 { size.x } else { h }, if size.y > 0.0 { size.y } else { h });
+    let pos = match ui.layout_next(size) { Some(p) => p, None => return false };
+    let shown = if flags.contains(ColorEditFlags::NO_ALPHA) { [color[0], color[1], color[2], 1.0] } else { color };
+    swatch_behavior(ui, id, Rect::from_min_size(pos, size), shown, flags)
+}
 
-    // Inline float fields
-    let field_names: &[&str] = if alpha { &["R", "G", "B", "A"] } else { &["R", "G", "B"] };
-    let mut cx = pos.x + swatch_w + sp.0;
-    let cy     = pos.y;
+// ─── Color edit ──────────────────────────────────────────────────────────────
 
-    for (i, &fname) in field_names.iter().enumerate() {
-        let field_id   = id.combine(crate::id::Id::from_hash(&i));
-        let field_rect = Rect::from_min_size(Vec2::new(cx, cy), Vec2::new(fields_w, h));
-        let (fh, fheld, _) = ui.ctx.button_behavior(field_id, field_rect);
+pub fn color_edit4(ui: &mut Ui<'_>, label: &str, color: &mut [f32; 4], alpha: bool) -> bool {
+    let flags = if alpha { ColorEditFlags::empty() } else { ColorEditFlags::NO_ALPHA };
+    color_edit4_ex(ui, label, color, flags)
+}
 
-        if fheld {
-            color[i] = (color[i] + ui.ctx.input.mouse_delta.x * 0.005).clamp(0.0, 1.0);
+/// Swatch plus inputs in the display mode picked by `flags`.
+///
+/// Storage: `active` while the picker popup is open, `int[0]` the display
+/// mode chosen by right-click (0 = from flags), `float[0]` the last hue.
+pub fn color_edit4_ex(ui: &mut Ui<'_>, label: &str, color: &mut [f32; 4], flags: ColorEditFlags) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+
+    let fs    = ui.ctx.style.font_size;
+    let fp    = ui.ctx.style.frame_padding;
+    let sp    = ui.ctx.style.item_spacing;
+    let h     = fs + fp.1 * 2.0;
+    let tw    = ui.text_width(text);
+    let alpha = !flags.contains(ColorEditFlags::NO_ALPHA);
+
+    let mode = match ui.ctx.get_storage(id).map_or(0, |s| s.int[0]) {
+        0 => DisplayMode::from_flags(flags),
+        m => DisplayMode::ALL[(m as usize - 1) % DisplayMode::ALL.len()],
+    };
+    let n_fields: usize = match mode {
+        _ if flags.contains(ColorEditFlags::NO_INPUTS) => 0,
+        DisplayMode::Hex => 1,
+        _                => if alpha { 4 } else { 3 },
+    };
+
+    // Layout: [swatch][field]…[field]  label
+    let inner    = sp.0 * 0.5;
+    let fields_w = if n_fields == 0 { 0.0 } else { (ui.available_width() - h - sp.0 * 2.0 - tw).max(40.0 * n_fields as f32) };
+    let label_w  = if text.is_empty() { 0.0 } else { sp.0 + tw };
+    let total_w  = h + if n_fields == 0 { 0.0 } else { sp.0 + fields_w } + label_w;
+    let pos = match ui.layout_next(Vec2::new(total_w, h)) { Some(p) => p, None => return false };
+    let row_rect = Rect::from_min_size(pos, Vec2::new(total_w - label_w, h));
+
+    let mut changed = false;
+
+    // Swatch: click toggles the picker, drag copies the colour
+    let swatch_rect = Rect::from_min_size(pos, Vec2::splat(h));
+    let swatch_id   = id.combine(Id::from_str("__swatch"));
+    let shown       = if alpha { *color } else { [color[0], color[1], color[2], 1.0] };
+    if swatch_behavior(ui, swatch_id, swatch_rect, shown, flags) && !flags.contains(ColorEditFlags::NO_PICKER) {
+        let st = ui.ctx.get_storage_mut(id);
+        st.active = !st.active;
+    }
+
+    // Drop a colour anywhere on the row
+    if !flags.contains(ColorEditFlags::NO_DRAG_DROP) {
+        let dropped = ui.ctx.drag_drop_target(swatch_id, row_rect, PAYLOAD_COLOR).and_then(|p| p.color());
+        if let Some(c) = dropped {
+            let a = if alpha { c[3] } else { color[3] };
+            *color = [c[0], c[1], c[2], a];
             changed = true;
         }
+    }
 
-        let bg = if fheld { ui.ctx.style.color(StyleColor::FrameBgActive) }
-                 else if fh { ui.ctx.style.color(StyleColor::FrameBgHovered) }
-                 else { ui.ctx.style.color(StyleColor::FrameBg) };
-        let rounding = ui.ctx.style.frame_rounding;
-        {
-            let draw = &mut ui.ctx.draw_list;
-            draw.filled_rect(field_rect, rounding, bg);
-            draw.rect_outline(field_rect, 1.0, ui.ctx.style.color(StyleColor::Border));
+    // Inputs
+    let fields_x = pos.x + h + sp.0;
+    let fields_rect = Rect::from_min_size(Vec2::new(fields_x, pos.y), Vec2::new(fields_w, h));
+    let comp_w = (fields_w - inner * n_fields.saturating_sub(1) as f32) / n_fields.max(1) as f32;
+    let field  = |i: usize| Rect::from_min_size(Vec2::new(fields_x + i as f32 * (comp_w + inner), pos.y), Vec2::new(comp_w, h));
+    let field_id = |i: usize| id.combine(Id::from_hash(&i));
+
+    match mode {
+        _ if n_fields == 0 => {}
+        DisplayMode::Rgb => {
+            const FORMATS: [&str; 4] = ["R:%d", "G:%d", "B:%d", "A:%d"];
+            for i in 0..n_fields {
+                let mut x = (color[i].clamp(0.0, 1.0) * 255.0).round() as i32;
+                let args = DragArgs { speed: 1.0, clamp: Some((0, 255)), format: FORMATS[i], component: None };
+                if drag_behavior(ui, field_id(i), field(i), &mut x, args) {
+                    color[i] = x as f32 / 255.0;
+                    changed = true;
+                }
+            }
+        }
+        DisplayMode::Hsv | DisplayMode::Hsl => {
+            let (hue, s_hsv, v) = stable_hsv(ui, id, *color);
+            let (s, l) = if mode == DisplayMode::Hsv { (s_hsv, v) } else {
+                let (_, s, l) = Color::from(*color).to_hsl();
+                (s, l)
+            };
+            let formats = if mode == DisplayMode::Hsv { ["H:%d", "S:%d", "V:%d"] } else { ["H:%d", "S:%d", "L:%d"] };
+            let mut vals = [(hue * 360.0).round() as i32, (s * 100.0).round() as i32, (l * 100.0).round() as i32];
+            let mut hsx_changed = false;
+            for (i, x) in vals.iter_mut().enumerate() {
+                let max = if i == 0 { 360 } else { 100 };
+                let args = DragArgs { speed: 0.5, clamp: Some((0, max)), format: formats[i], component: None };
+                hsx_changed |= drag_behavior(ui, field_id(i), field(i), x, args);
+            }
+            if hsx_changed {
+                let (h, s, l) = (vals[0] as f32 / 360.0, vals[1] as f32 / 100.0, vals[2] as f32 / 100.0);
+                let c = if mode == DisplayMode::Hsv { Color::from_hsv(h.min(0.9999), s, l) } else { Color::from_hsl(h.min(0.9999), s, l) };
+                *color = [c.r, c.g, c.b, color[3]];
+                ui.ctx.get_storage_mut(id).float[0] = h;
+                changed = true;
+            }
+            if alpha {
+                let mut a = (color[3].clamp(0.0, 1.0) * 255.0).round() as i32;
+                let args = DragArgs { speed: 1.0, clamp: Some((0, 255)), format: "A:%d", component: None };
+                if drag_behavior(ui, field_id(3), field(3), &mut a, args) {
+                    color[3] = a as f32 / 255.0;
+                    changed = true;
+                }
+            }
         }
-        let vstr = format!("{:.2}", color[i]);
-        let tc   = ui.ctx.style.color(StyleColor::Text);
-        let tw2  = ui.text_width(&vstr);
-        let tp   = Vec2::new(cx + (fields_w - tw2) * 0.5, cy + fp.1);
-        ui.draw_text(&vstr, tp, tc);
-        let lp = Vec2::new(cx + 2.0, cy - fs - 1.0);
-        ui.draw_text(fname, lp, tc.with_alpha(0.6));
+        DisplayMode::Hex => changed |= hex_field(ui, field_id(0), field(0), color, alpha),
+    }
 
-        cx += fields_w + sp.0;
+    // Right-click the inputs to cycle RGB → HSV → HSL → Hex
+    if n_fields > 0 && ui.ctx.input.mouse_clicked(MouseButton::Right) && fields_rect.contains(ui.ctx.input.mouse_pos) {
+        let next = DisplayMode::ALL.iter().position(|&m| m == mode).unwrap_or(0) + 1;
+        ui.ctx.get_storage_mut(id).int[0] = (next % DisplayMode::ALL.len()) as i32 + 1;
     }
 
     // Label
     let tc = ui.ctx.style.color(StyleColor::Text);
-    let lp = Vec2::new(cx, pos.y + (h - fs) * 0.5);
+    let lp = Vec2::new(row_rect.max.x + sp.0, pos.y + (h - fs) * 0.5);
     if !text.is_empty() { ui.draw_text(text, lp, tc); }
 
     // Inline picker popup
-    let popup_open = ui.ctx.get_storage(id).map_or(false, |s| s.active);
+    let popup_open = ui.ctx.get_storage(id).is_some_and(|s| s.active);
     if popup_open {
-        let picker_size = Vec2::new(200.0, 220.0);
+        let pad         = 8.0;
+        let inner_w     = 184.0;
+        let picker_size = Vec2::new(inner_w + pad * 2.0, picker_height(ui, inner_w, flags) + pad * 2.0);
         let picker_pos  = Vec2::new(pos.x, pos.y + h + 2.0);
         let picker_rect = Rect::from_min_size(picker_pos, picker_size);
         {
@@ -108,14 +304,11 @@ pub fn color_edit4(ui: &mut Ui<'_>, label: &str, color: &mut [f32; 4], alpha: bo
             draw.filled_rect(picker_rect, 4.0, ui.ctx.style.color(StyleColor::PopupBg));
             draw.rect_outline(picker_rect, 1.0, ui.ctx.style.color(StyleColor::Border));
         }
-        // Embed a small picker
-        let mut col4 = *color;
-        if color_picker_sv_square(ui, id, picker_pos, 180.0, &mut col4) {
-            *color = col4;
+        if picker_body(ui, id, picker_pos + Vec2::splat(pad), inner_w, color, flags) {
             changed = true;
         }
         // Close on click outside
-        if ui.ctx.input.mouse_clicked(crate::input::MouseButton::Left)
+        if ui.ctx.input.mouse_clicked(MouseButton::Left)
             && !picker_rect.contains(ui.ctx.input.mouse_pos)
             && !swatch_rect.contains(ui.ctx.input.mouse_pos)
         {
@@ -126,54 +319,133 @@ pub fn color_edit4(ui: &mut Ui<'_>, label: &str, color: &mut [f32; 4], alpha: bo
     changed
 }
 
+/// Click to type a `#RRGGBB[AA]` colour.
+fn hex_field(ui: &mut Ui<'_>, id: Id, rect: Rect, color: &mut [f32; 4], alpha: bool) -> bool {
+    let (hovered, _, _) = ui.ctx.button_behavior(id, rect);
+    if hovered && ui.ctx.input.mouse_clicked(MouseButton::Left) {
+        begin_text_entry(ui, id, format_hex(*color, alpha));
+    }
+    let mut changed = false;
+    match text_entry(ui, id, rect) {
+        TextEntry::Editing => return false,
+        TextEntry::Commit(text) => {
+            if let Some(c) = parse_hex(&text) {
+                let a = if alpha { c[3] } else { color[3] };
+                changed = [c[0], c[1], c[2], a] != *color;
+                *color = [c[0], c[1], c[2], a];
+            }
+        }
+        TextEntry::Idle | TextEntry::Cancel => {}
+    }
+
+    let fs = ui.ctx.style.font_size;
+    let fp = ui.ctx.style.frame_padding;
+    let bg = if hovered { ui.ctx.style.color(StyleColor::FrameBgHovered) } else { ui.ctx.style.color(StyleColor::FrameBg) };
+    let rounding = ui.ctx.style.frame_rounding;
+    ui.ctx.draw_list.filled_rect(rect, rounding, bg);
+    ui.ctx.draw_list.rect_outline(rect, 1.0, ui.ctx.style.color(StyleColor::Border));
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    ui.draw_text(&format_hex(*color, alpha), Vec2::new(rect.min.x + fp.0, rect.min.y + (rect.height() - fs) * 0.5), tc);
+    changed
+}
+
 // ─── Full picker ─────────────────────────────────────────────────────────────
 
 pub fn color_picker4(ui: &mut Ui<'_>, label: &str, color: &mut [f32; 4]) -> bool {
+    color_picker4_ex(ui, label, color, ColorEditFlags::empty())
+}
+
+pub fn color_picker4_ex(ui: &mut Ui<'_>, label: &str, color: &mut [f32; 4], flags: ColorEditFlags) -> bool {
     let (text, id_src) = parse_label(label);
     let id = ui.ctx.make_id(id_src);
 
-    let size = Vec2::new(ui.available_width().min(240.0), 260.0);
-    let pos  = match ui.layout_next(size) { Some(p) => p, None => return false };
+    let fs    = ui.ctx.style.font_size;
+    let sp    = ui.ctx.style.item_spacing;
+    let width = ui.available_width().min(240.0);
+    let body  = picker_height(ui, width, flags);
+    let label_h = if text.is_empty() { 0.0 } else { sp.1 + fs };
+    let pos = match ui.layout_next(Vec2::new(width, body + label_h)) { Some(p) => p, None => return false };
 
-    // Full picker
-    let mut col4 = *color;
-    let changed  = color_picker_sv_square(ui, id, pos, size.x, &mut col4);
-    if changed { *color = col4; }
+    let changed = picker_body(ui, id, pos, width, color, flags);
 
     // Label
     let tc = ui.ctx.style.color(StyleColor::Text);
-    let lp = Vec2::new(pos.x, pos.y + size.y + 2.0);
+    let lp = Vec2::new(pos.x, pos.y + body + sp.1);
     ui.draw_text(text, lp, tc);
 
     changed
 }
 
+/// Height of [`picker_body`] at `width`.
+fn picker_height(ui: &Ui<'_>, width: f32, flags: ColorEditFlags) -> f32 {
+    let area = width - HUE_BAR_W - 4.0;
+    if flags.contains(ColorEditFlags::NO_PALETTE) { return area; }
+    let sz = (width - 2.0 * (PALETTE.len() - 1) as f32) / PALETTE.len() as f32;
+    area + 2.0 * (ui.ctx.style.item_spacing.1 + sz)
+}
+
+/// Picking area, then the palette and history rows.
+///
+/// Storage of the `__pick` child: `active` while a drag has changed the
+/// colour but not yet been recorded in the history.
+fn picker_body(ui: &mut Ui<'_>, id: Id, pos: Vec2, width: f32, color: &mut [f32; 4], flags: ColorEditFlags) -> bool {
+    let pick_id = id.combine(Id::from_str("__pick"));
+    let mut changed = if flags.contains(ColorEditFlags::PICKER_HUE_WHEEL) {
+        color_picker_hue_wheel(ui, pick_id, pos, width, color)
+    } else {
+        color_picker_sv_square(ui, pick_id, pos, width, color)
+    };
+
+    // Record the colour in the history once the drag that changed it ends
+    let dirty = ui.ctx.get_storage(pick_id).is_some_and(|s| s.active) || changed;
+    let released = !ui.ctx.input.mouse_down(MouseButton::Left);
+    ui.ctx.get_storage_mut(pick_id).active = dirty && !released;
+    if dirty && released { push_history(ui, *color); }
+
+    if flags.contains(ColorEditFlags::NO_PALETTE) { return changed; }
+    let sp   = ui.ctx.style.item_spacing;
+    let sz   = (width - 2.0 * (PALETTE.len() - 1) as f32) / PALETTE.len() as f32;
+    let mut y = pos.y + width - HUE_BAR_W - 4.0 + sp.1;
+
+    let presets: Vec<[f32; 4]> = PALETTE.iter().map(|&hex| Color::from_hex(hex).into()).collect();
+    if let Some(c) = swatch_row(ui, id.combine(Id::from_str("__palette")), Vec2::new(pos.x, y), width, &presets, flags) {
+        *color = [c[0], c[1], c[2], color[3]];
+        changed = true;
+    }
+    y += sz + sp.1;
+    let history = ui.ctx.color_history.clone();
+    if let Some(c) = swatch_row(ui, id.combine(Id::from_str("__history")), Vec2::new(pos.x, y), width, &history, flags) {
+        *color = c;
+        changed = true;
+    }
+    changed
+}
+
 // ─── SV square + H strip picker ──────────────────────────────────────────────
 
 fn color_picker_sv_square(
     ui:    &mut Ui<'_>,
-    id:    crate::id::Id,
+    id:    Id,
     pos:   Vec2,
     width: f32,
     color: &mut [f32; 4],
 ) -> bool {
-    let sq_id   = id.combine(crate::id::Id::from_str("__sv"));
-    let h_id    = id.combine(crate::id::Id::from_str("__hue"));
-    let col_hsv = Color::new(color[0], color[1], color[2], 1.0).to_hsv();
-    let (mut hue, mut sat, mut val) = col_hsv;
+    let sq_id   = id.combine(Id::from_str("__sv"));
+    let h_id    = id.combine(Id::from_str("__hue"));
+    let (mut hue, mut sat, mut val) = stable_hsv(ui, id, *color);
 
-    let sq_size = Vec2::splat(width - 20.0);
+    let sq_size = Vec2::splat(width - HUE_BAR_W - 4.0);
     let sq_rect = Rect::from_min_size(pos, sq_size);
     let h_rect  = Rect::from_min_size(
         Vec2::new(pos.x + sq_size.x + 4.0, pos.y),
-        Vec2::new(16.0, sq_size.y),
+        Vec2::new(HUE_BAR_W, sq_size.y),
     );
 
     let mut changed = false;
 
     // SV interaction
-    let (svh, svheld, _) = ui.ctx.button_behavior(sq_id, sq_rect);
-    if svheld {
+    let (_, svheld, _) = ui.ctx.button_behavior(sq_id, sq_rect);
+    if svheld && ui.ctx.input.mouse_down(MouseButton::Left) {
         let t = (ui.ctx.input.mouse_pos - sq_rect.min) / sq_size;
         sat = t.x.clamp(0.0, 1.0);
         val = (1.0 - t.y).clamp(0.0, 1.0);
@@ -182,28 +454,23 @@ fn color_picker_sv_square(
 
     // Hue strip interaction
     let (_, hheld, _) = ui.ctx.button_behavior(h_id, h_rect);
-    if hheld {
+    if hheld && ui.ctx.input.mouse_down(MouseButton::Left) {
         let t = (ui.ctx.input.mouse_pos.y - h_rect.min.y) / h_rect.height();
-        hue = t.clamp(0.0, 1.0);
+        hue = t.clamp(0.0, 0.9999);
         changed = true;
     }
 
-    // Draw SV square – render 4 corner colors blended
+    // Draw SV square as a grid of cells
     const SEGS: usize = 16;
     for iy in 0..SEGS {
         for ix in 0..SEGS {
             let fx0 = ix as f32 / SEGS as f32;
             let fy0 = iy as f32 / SEGS as f32;
-            let fx1 = (ix + 1) as f32 / SEGS as f32;
-            let fy1 = (iy + 1) as f32 / SEGS as f32;
-            let tl = Color::from_hsv(hue, fx0, 1.0 - fy0);
-            let blended = tl.lerp(Color::WHITE, 0.0); // simplified: use corner colors
             let cell = Rect::from_min_size(
                 sq_rect.min + Vec2::new(sq_size.x * fx0, sq_size.y * fy0),
-                Vec2::new(sq_size.x * (fx1 - fx0) + 1.0, sq_size.y * (fy1 - fy0) + 1.0),
+                Vec2::new(sq_size.x / SEGS as f32 + 1.0, sq_size.y / SEGS as f32 + 1.0),
             );
-            let tl_c = Color::from_hsv(hue, fx0, 1.0 - fy0);
-            ui.ctx.draw_list.filled_rect(cell, 0.0, tl_c);
+            ui.ctx.draw_list.filled_rect(cell, 0.0, Color::from_hsv(hue, fx0, 1.0 - fy0));
         }
     }
     ui.ctx.draw_list.rect_outline(sq_rect, 1.0, Color::BLACK.with_alpha(0.5));
@@ -211,18 +478,17 @@ fn color_picker_sv_square(
     // Crosshair on SV square
     let sv_pt = sq_rect.min + Vec2::new(sq_size.x * sat, sq_size.y * (1.0 - val));
     ui.ctx.draw_list.filled_circle(sv_pt, 5.0, Color::WHITE, 8);
-    ui.ctx.draw_list.filled_circle(sv_pt, 3.0, Color::new(color[0], color[1], color[2], 1.0), 8);
+    ui.ctx.draw_list.filled_circle(sv_pt, 3.0, Color::from_hsv(hue, sat, val), 8);
 
     // Hue strip
     const H_SEGS: usize = 32;
     for i in 0..H_SEGS {
         let t0 = i as f32 / H_SEGS as f32;
         let t1 = (i + 1) as f32 / H_SEGS as f32;
-        let c0 = Color::from_hsv(t0, 1.0, 1.0);
         let y0 = h_rect.min.y + h_rect.height() * t0;
         let y1 = h_rect.min.y + h_rect.height() * t1;
         let band = Rect::new(Vec2::new(h_rect.min.x, y0), Vec2::new(h_rect.max.x, y1 + 1.0));
-        ui.ctx.draw_list.filled_rect(band, 0.0, c0);
+        ui.ctx.draw_list.filled_rect(band, 0.0, Color::from_hsv(t0, 1.0, 1.0));
     }
     // Hue cursor line
     let hy = h_rect.min.y + h_rect.height() * hue;
@@ -237,7 +503,108 @@ fn color_picker_sv_square(
     if changed {
         let c = Color::from_hsv(hue, sat, val);
         color[0] = c.r; color[1] = c.g; color[2] = c.b;
+        ui.ctx.get_storage_mut(id).float[0] = hue;
     }
 
     changed
 }
+
+// ─── Hue wheel + SV triangle picker ──────────────────────────────────────────
+
+/// Hue ring around a triangle whose corners are the pure hue, white and
+/// black. Occupies the same area as the SV square.
+///
+/// Storage of the `__wheel` child: `int[0]` is the part being dragged
+/// (1 = ring, 2 = triangle).
+fn color_picker_hue_wheel(
+    ui:    &mut Ui<'_>,
+    id:    Id,
+    pos:   Vec2,
+    width: f32,
+    color: &mut [f32; 4],
+) -> bool {
+    let wheel_id = id.combine(Id::from_str("__wheel"));
+    let (mut hue, mut sat, mut val) = stable_hsv(ui, id, *color);
+
+    let size   = width - HUE_BAR_W - 4.0;
+    let center = pos + Vec2::new(width * 0.5, size * 0.5);
+    let r_out  = size * 0.5;
+    let ring   = (r_out * 0.16).max(8.0);
+    let r_in   = r_out - ring;
+    let tri_r  = r_in - 3.0;
+    let rect   = Rect::from_min_size(center - Vec2::splat(r_out), Vec2::splat(r_out * 2.0));
+
+    let corners = |hue: f32| {
+        let corner = |k: f32| {
+            let a = hue * TAU + k * TAU / 3.0;
+            center + Vec2::new(a.cos(), a.sin()) * tri_r
+        };
+        [corner(0.0), corner(1.0), corner(2.0)]
+    };
+
+    let (hovered, _, _) = ui.ctx.button_behavior(wheel_id, rect);
+    let m = ui.ctx.input.mouse_pos;
+    if ui.ctx.input.mouse_clicked(MouseButton::Left) {
+        let d = (m - center).length();
+        let part = if !hovered { 0 }
+                   else if d >= r_in - 2.0 && d <= r_out + 2.0 { 1 }
+                   else if barycentric(m, corners(hue)).iter().all(|&w| w >= -0.05) { 2 }
+                   else { 0 };
+        ui.ctx.get_storage_mut(wheel_id).int[0] = part;
+    }
+
+    let mut changed = false;
+    if ui.ctx.input.mouse_down(MouseButton::Left) && ui.ctx.active_item == Some(wheel_id) {
+        match ui.ctx.get_storage(wheel_id).map_or(0, |s| s.int[0]) {
+            1 => {
+                let d = m - center;
+                hue = (d.y.atan2(d.x) / TAU).rem_euclid(1.0).min(0.9999);
+                changed = true;
+            }
+            2 => {
+                let [wa, wb, wc] = barycentric(m, corners(hue)).map(|w| w.max(0.0));
+                let sum = (wa + wb + wc).max(f32::EPSILON);
+                let (wa, wb) = (wa / sum, wb / sum);
+                val = (wa + wb).clamp(0.0, 1.0);
+                sat = if val > 0.0 { (wa / val).clamp(0.0, 1.0) } else { sat };
+                changed = true;
+            }
+            _ => {}
+        }
+    }
+
+    // Ring
+    const RING_SEGS: usize = 64;
+    for i in 0..RING_SEGS {
+        let a0 = TAU * i as f32 / RING_SEGS as f32;
+        let a1 = TAU * (i + 1) as f32 / RING_SEGS as f32 + 0.01;
+        let col = Color::from_hsv((i as f32 + 0.5) / RING_SEGS as f32, 1.0, 1.0);
+        ui.ctx.draw_list.arc(center, (r_in + r_out) * 0.5, a0, a1, ring, col);
+    }
+    let dir = Vec2::new((hue * TAU).cos(), (hue * TAU).sin());
+    ui.ctx.draw_list.line(center + dir * (r_in - 1.0), center + dir * (r_out + 1.0), 3.0, Color::WHITE);
+
+    // Triangle
+    let [a, b, c] = corners(hue);
+    ui.ctx.draw_list.triangle_gradient(a, b, c, [Color::from_hsv(hue, 1.0, 1.0), Color::WHITE, Color::BLACK]);
+    let (wa, wb) = (sat * val, (1.0 - sat) * val);
+    let pt = a * wa + b * wb + c * (1.0 - val);
+    ui.ctx.draw_list.filled_circle(pt, 5.0, Color::WHITE, 8);
+    ui.ctx.draw_list.filled_circle(pt, 3.0, Color::from_hsv(hue, sat, val), 8);
+
+    if changed {
+        let col = Color::from_hsv(hue, sat, val);
+        color[0] = col.r; color[1] = col.g; color[2] = col.b;
+        ui.ctx.get_storage_mut(id).float[0] = hue;
+    }
+    changed
+}
+
+/// Barycentric weights of `p` in triangle `t`.
+fn barycentric(p: Vec2, [a, b, c]: [Vec2; 3]) -> [f32; 3] {
+    let det = (b.y - c.y) * (a.x - c.x) + (c.x - b.x) * (a.y - c.y);
+    if det.abs() < f32::EPSILON { return [1.0, 0.0, 0.0]; }
+    let wa = ((b.y - c.y) * (p.x - c.x) + (c.x - b.x) * (p.y - c.y)) / det;
+    let wb = ((c.y - a.y) * (p.x - c.x) + (a.x - c.x) * (p.y - c.y)) / det;
+    [wa, wb, 1.0 - wa - wb]
+}
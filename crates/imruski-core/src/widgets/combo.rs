@@ -1,9 +1,16 @@
-//! Combo-box (dropdown) widget.
+//! Combo-box (dropdown) widgets.
+//!
+//! - `combo`                 – plain dropdown
+//! - `combo_filtered`        – dropdown with a type-to-filter field
+//! - `input_with_completion` – text input with a suggestion popup
 
 use crate::{
-    id::parse_label,
+    fuzzy::{self, FilterMode},
+    id::{parse_label, Id},
+    input::Key,
     style::StyleColor,
     ui::Ui,
+    widgets::{input_text::input_text, table::scrollbar},
     Rect, Vec2,
 };
 
@@ -120,3 +127,341 @@ pub fn combo(
 
     changed
 }
+
+// ─── Filtered combo / completion ─────────────────────────────────────────────
+
+/// Rows shown before the popup list starts scrolling.
+const POPUP_MAX_ROWS: usize = 8;
+
+/// Indices of `items` that match `filter`, paired with the match; fuzzy
+/// results are ordered best-first.
+fn filter_items(items: &[&str], filter: &str, mode: FilterMode) -> Vec<(usize, fuzzy::Match)> {
+    let mut out: Vec<_> = items.iter().enumerate()
+        .filter_map(|(i, it)| fuzzy::matches(mode, filter, it).map(|m| (i, m)))
+        .collect();
+    if mode == FilterMode::Fuzzy && !filter.is_empty() {
+        out.sort_by_key(|m| std::cmp::Reverse(m.1.score));
+    }
+    out
+}
+
+/// Move `highlight` with the arrow / page keys. Returns `true` if it moved.
+fn navigate(ui: &Ui<'_>, highlight: &mut usize, count: usize) -> bool {
+    if count == 0 { return false; }
+    let input = &ui.ctx.input;
+    let page  = POPUP_MAX_ROWS;
+    let old   = *highlight;
+    if input.key_pressed(Key::Down)     { *highlight = (*highlight + 1).min(count - 1); }
+    if input.key_pressed(Key::Up)       { *highlight = highlight.saturating_sub(1); }
+    if input.key_pressed(Key::PageDown) { *highlight = (*highlight + page).min(count - 1); }
+    if input.key_pressed(Key::PageUp)   { *highlight = highlight.saturating_sub(page); }
+    *highlight = (*highlight).min(count - 1);
+    *highlight != old
+}
+
+/// Keyboard/scroll state of a popup list.
+struct ListNav {
+    /// Highlighted row (index into the filtered list).
+    highlight: usize,
+    /// First visible row.
+    scroll:    usize,
+    /// Scroll so the highlighted row is visible.
+    follow:    bool,
+}
+
+/// Draw a scrolling list of filtered items below `anchor`.
+///
+/// Returns the popup rect and the filtered index of a clicked row, if any.
+fn match_popup(
+    ui:       &mut Ui<'_>,
+    id:       Id,
+    owner:    Id,
+    anchor:   Rect,
+    items:    &[&str],
+    filtered: &[(usize, fuzzy::Match)],
+    nav:      &mut ListNav,
+) -> (Rect, Option<usize>) {
+    let highlight = nav.highlight;
+    let scroll    = &mut nav.scroll;
+    let fs      = ui.ctx.style.font_size;
+    let fp      = ui.ctx.style.frame_padding;
+    let sp      = ui.ctx.style.item_spacing;
+    let sb_w    = ui.ctx.style.scrollbar_size;
+    let item_h  = fs + sp.1;
+    let rows    = filtered.len().clamp(1, POPUP_MAX_ROWS);
+    let popup_rect = Rect::from_min_size(
+        Vec2::new(anchor.min.x, anchor.max.y + 2.0),
+        Vec2::new(anchor.width(), rows as f32 * item_h + fp.1 * 2.0),
+    );
+
+    let max_scroll = filtered.len().saturating_sub(POPUP_MAX_ROWS);
+    if popup_rect.contains(ui.ctx.input.mouse_pos) && ui.ctx.input.mouse_wheel != 0.0 {
+        let step = -ui.ctx.input.mouse_wheel.signum() as isize;
+        *scroll = (*scroll as isize + step).max(0) as usize;
+    }
+    if nav.follow {
+        if highlight < *scroll { *scroll = highlight; }
+        if highlight >= *scroll + POPUP_MAX_ROWS { *scroll = highlight + 1 - POPUP_MAX_ROWS; }
+    }
+    *scroll = (*scroll).min(max_scroll);
+
+    let rounding = ui.ctx.style.frame_rounding;
+    {
+        let popup_bg = ui.ctx.style.color(StyleColor::PopupBg);
+        let border   = ui.ctx.style.color(StyleColor::Border);
+        let draw = &mut ui.ctx.draw_list;
+        draw.filled_rect(popup_rect, rounding, popup_bg);
+        draw.rect_outline(popup_rect, 1.0, border);
+    }
+
+    let tc     = ui.ctx.style.color(StyleColor::Text);
+    let hl_col = ui.ctx.style.color(StyleColor::CheckMark);
+    if filtered.is_empty() {
+        let dc = ui.ctx.style.color(StyleColor::TextDisabled);
+        let tp = Vec2::new(popup_rect.min.x + fp.0, popup_rect.min.y + fp.1 + (item_h - fs) * 0.5);
+        ui.draw_text("No matches", tp, dc);
+        return (popup_rect, None);
+    }
+
+    let has_bar = max_scroll > 0;
+    let row_w   = popup_rect.width() - if has_bar { sb_w } else { 0.0 };
+    let mut clicked = None;
+    for (row, (fi, (item_idx, m))) in filtered.iter().enumerate().skip(*scroll).take(POPUP_MAX_ROWS).enumerate() {
+        let item_pos  = Vec2::new(popup_rect.min.x, popup_rect.min.y + fp.1 + row as f32 * item_h);
+        let item_rect = Rect::from_min_size(item_pos, Vec2::new(row_w, item_h));
+        let item_id   = id.combine(Id::from_hash(item_idx));
+
+        let (hovitem, _, clkitem) = ui.ctx.button_behavior(item_id, item_rect);
+        // Pressing a row focuses it; keep typing going to the owner
+        if ui.ctx.focus_item == Some(item_id) { ui.ctx.focus_item = Some(owner); }
+        if hovitem || fi == highlight {
+            let hc = if fi == highlight { ui.ctx.style.color(StyleColor::HeaderActive) }
+                     else               { ui.ctx.style.color(StyleColor::HeaderHovered) };
+            ui.ctx.draw_list.filled_rect(item_rect, 0.0, hc);
+        }
+        if clkitem { clicked = Some(fi); }
+
+        let tp = Vec2::new(item_pos.x + fp.0, item_pos.y + (item_h - fs) * 0.5);
+        ui.draw_text_highlighted(items[*item_idx], tp, tc, hl_col, &m.positions);
+    }
+
+    if has_bar {
+        let track  = Rect::new(Vec2::new(popup_rect.max.x - sb_w, popup_rect.min.y + 1.0), Vec2::new(popup_rect.max.x - 1.0, popup_rect.max.y - 1.0));
+        let bar_id = id.combine(Id::from_str("##scroll"));
+        let pos    = scrollbar(ui, bar_id, track, true, *scroll as f32, POPUP_MAX_ROWS as f32, filtered.len() as f32);
+        if ui.ctx.focus_item == Some(bar_id) { ui.ctx.focus_item = Some(owner); }
+        *scroll = (pos.round().max(0.0) as usize).min(max_scroll);
+    }
+
+    (popup_rect, clicked)
+}
+
+/// Combo box whose popup filters `items` as the user types.
+///
+/// Storage: `active` open flag, `string` filter text, `int[1]` highlighted
+/// row, `int[2]` first visible row.
+pub fn combo_filtered(
+    ui:       &mut Ui<'_>,
+    label:    &str,
+    selected: &mut usize,
+    items:    &[&str],
+    mode:     FilterMode,
+) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+
+    let fs    = ui.ctx.style.font_size;
+    let fp    = ui.ctx.style.frame_padding;
+    let sp    = ui.ctx.style.item_spacing;
+    let h     = fs + fp.1 * 2.0;
+    let tw    = ui.text_width(text);
+    let box_w = (ui.available_width() - tw - sp.0).max(60.0);
+    let total = Vec2::new(box_w + sp.0 + tw, h);
+
+    let pos = match ui.layout_next(total) { Some(p) => p, None => return false };
+    let box_rect = Rect::from_min_size(pos, Vec2::new(box_w, h));
+
+    let was_open = ui.ctx.get_storage(id).is_some_and(|s| s.active);
+    let (_hov, _, clicked) = ui.ctx.button_behavior(id, box_rect);
+    let open = if clicked { !was_open } else { was_open };
+
+    let mut filter = ui.ctx.get_storage(id).map(|s| s.string.clone()).unwrap_or_default();
+    let (mut highlight, mut scroll) = ui.ctx.get_storage(id)
+        .map_or((0, 0), |s| (s.int[1].max(0) as usize, s.int[2].max(0) as usize));
+
+    let mut filter_changed = false;
+    if open && !was_open {
+        filter.clear();
+        highlight = *selected;
+        ui.ctx.focus_item = Some(id);
+    }
+    if open && ui.ctx.focus_item == Some(id) {
+        let typed: String = ui.ctx.input.text_input.chars().filter(|c| !c.is_control()).collect();
+        if !typed.is_empty() { filter.push_str(&typed); filter_changed = true; }
+        if ui.ctx.input.key_pressed(Key::Backspace) && filter.pop().is_some() { filter_changed = true; }
+    }
+
+    let filtered = if open { filter_items(items, &filter, mode) } else { Vec::new() };
+    if open && !was_open {
+        highlight = filtered.iter().position(|(i, _)| *i == *selected).unwrap_or(0);
+    }
+    if filter_changed { highlight = 0; scroll = 0; }
+
+    let mut changed = false;
+    let mut close   = false;
+    let mut follow  = (open && !was_open) || filter_changed;
+    if open && ui.ctx.focus_item == Some(id) {
+        follow |= navigate(ui, &mut highlight, filtered.len());
+        if ui.ctx.input.key_pressed(Key::Enter) {
+            if let Some(&(idx, _)) = filtered.get(highlight) {
+                changed   = idx != *selected;
+                *selected = idx;
+            }
+            close = true;
+        }
+        if ui.ctx.input.key_pressed(Key::Escape) { close = true; }
+    }
+
+    // Draw combo box
+    let bg       = ui.ctx.style.color(StyleColor::FrameBg);
+    let rounding = ui.ctx.style.frame_rounding;
+    let tc       = ui.ctx.style.color(StyleColor::Text);
+    let dc       = ui.ctx.style.color(StyleColor::TextDisabled);
+    {
+        let border = ui.ctx.style.color(StyleColor::Border);
+        let draw = &mut ui.ctx.draw_list;
+        draw.filled_rect(box_rect, rounding, bg);
+        draw.rect_outline(box_rect, 1.0, border);
+
+        let ax = pos.x + box_w - fp.0 - 6.0;
+        let ay = pos.y + h * 0.5;
+        draw.triangle_filled(
+            Vec2::new(ax - 4.0, ay - 2.0),
+            Vec2::new(ax + 4.0, ay - 2.0),
+            Vec2::new(ax, ay + 4.0),
+            tc,
+        );
+    }
+
+    // While open the box doubles as the filter field
+    let tp  = Vec2::new(pos.x + fp.0, pos.y + fp.1);
+    let cur = items.get(*selected).copied().unwrap_or("");
+    if open && !filter.is_empty() {
+        ui.draw_text(&filter, tp, tc);
+    } else {
+        ui.draw_text(cur, tp, if open { dc } else { tc });
+    }
+    if open && (ui.ctx.input.frame_count / 30) & 1 == 0 {
+        let cx = tp.x + ui.text_width(&filter);
+        ui.ctx.draw_list.line(Vec2::new(cx, pos.y + fp.1), Vec2::new(cx, pos.y + h - fp.1), 1.0, tc);
+    }
+
+    if !text.is_empty() {
+        let lp = Vec2::new(pos.x + box_w + sp.0, pos.y + (h - fs) * 0.5);
+        ui.draw_text(text, lp, tc);
+    }
+
+    if open && !close {
+        let mut nav = ListNav { highlight, scroll, follow };
+        let (popup_rect, clicked_row) = match_popup(ui, id, id, box_rect, items, &filtered, &mut nav);
+        scroll = nav.scroll;
+        if let Some(fi) = clicked_row {
+            let idx   = filtered[fi].0;
+            changed   = idx != *selected;
+            *selected = idx;
+            close     = true;
+        }
+        if ui.ctx.input.mouse_clicked(crate::input::MouseButton::Left)
+            && !popup_rect.contains(ui.ctx.input.mouse_pos)
+            && !box_rect.contains(ui.ctx.input.mouse_pos)
+        {
+            close = true;
+        }
+    }
+
+    if close && ui.ctx.focus_item == Some(id) { ui.ctx.focus_item = None; }
+    let s = ui.ctx.get_storage_mut(id);
+    s.active = open && !close;
+    s.string = filter;
+    s.int[1] = highlight as i32;
+    s.int[2] = scroll as i32;
+
+    changed
+}
+
+/// Single-line text input that shows matching `items` while focused.
+///
+/// Up/Down pick a suggestion; Enter or Tab copies it into `buf`.
+/// Storage (on a child ID): `int[1]` highlighted row, `int[2]` first visible
+/// row, `string` the text the suggestions were computed for.
+pub fn input_with_completion(
+    ui:    &mut Ui<'_>,
+    label: &str,
+    buf:   &mut String,
+    items: &[&str],
+    mode:  FilterMode,
+) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id      = ui.ctx.make_id(id_src);
+    let list_id = id.combine(Id::from_str("__completion"));
+
+    let fs    = ui.ctx.style.font_size;
+    let fp    = ui.ctx.style.frame_padding;
+    let sp    = ui.ctx.style.item_spacing;
+    let h     = fs + fp.1 * 2.0;
+    let tw    = ui.text_width(text);
+    let box_w = (ui.available_width() - tw - sp.0).max(60.0);
+
+    let (mut highlight, mut scroll, shown_for) = ui.ctx.get_storage(list_id)
+        .map_or((0, 0, String::new()), |s| (s.int[1].max(0) as usize, s.int[2].max(0) as usize, s.string.clone()));
+
+    // Accept / navigate before the text field consumes Enter
+    let mut changed  = false;
+    let focused      = ui.ctx.focus_item == Some(id);
+    let mut filtered = filter_items(items, buf, mode);
+    let suggesting   = |buf: &str, filtered: &[(usize, fuzzy::Match)]| match filtered {
+        []          => false,
+        [(only, _)] => !buf.is_empty() && items[*only] != buf,
+        _           => !buf.is_empty(),
+    };
+    let mut follow = false;
+    if focused && suggesting(buf, &filtered) {
+        if *buf != shown_for { highlight = 0; scroll = 0; }
+        follow = navigate(ui, &mut highlight, filtered.len());
+        let tab = ui.ctx.input.key_pressed(Key::Tab);
+        if tab || ui.ctx.input.key_pressed(Key::Enter) {
+            if let Some(&(idx, _)) = filtered.get(highlight) {
+                buf.clear();
+                buf.push_str(items[idx]);
+                changed = true;
+            }
+        }
+    }
+
+    changed |= input_text(ui, label, buf, false);
+
+    let focused = ui.ctx.focus_item == Some(id);
+    if changed { filtered = filter_items(items, buf, mode); }
+    if focused && suggesting(buf, &filtered) {
+        if *buf != shown_for && !follow { highlight = 0; scroll = 0; }
+        let max = ui.ctx.current_window().map(|w| w.layout.last_item_max);
+        if let Some(max) = max {
+            let box_rect = Rect::new(Vec2::new(max.x - tw - sp.0 - box_w, max.y - h), Vec2::new(max.x - tw - sp.0, max.y));
+            let mut nav = ListNav { highlight, scroll, follow };
+            let (_, clicked_row) = match_popup(ui, list_id, id, box_rect, items, &filtered, &mut nav);
+            scroll = nav.scroll;
+            if let Some(fi) = clicked_row {
+                buf.clear();
+                buf.push_str(items[filtered[fi].0]);
+                changed = true;
+            }
+        }
+    }
+
+    let s = ui.ctx.get_storage_mut(list_id);
+    s.int[1] = highlight as i32;
+    s.int[2] = scroll as i32;
+    s.string = buf.clone();
+
+    changed
+}
@@ -0,0 +1,227 @@
+//! Command palette – a centered popup that fuzzy-searches registered commands.
+//!
+//! Commands are registered with `Context::register_command`. With an empty
+//! query, recently used commands are listed first; otherwise results are
+//! ranked by fuzzy score with a small boost for recent ones.
+
+use crate::{
+    fuzzy,
+    id::Id,
+    input::{Key, MouseButton},
+    style::StyleColor,
+    ui::Ui,
+    Color, Rect, Vec2,
+};
+
+const MAX_ROWS:    usize = 10;
+const MAX_WIDTH:   f32   = 520.0;
+const ROW_PAD:     f32   = 8.0;
+const CATEGORY_SEP: &str = ": ";
+
+/// One ranked result: command index, match against `"category: name"`.
+struct Hit {
+    cmd:   usize,
+    found: fuzzy::Match,
+}
+
+/// Shows the palette while `*open` is true. Returns the chosen command's ID
+/// and closes the palette; Escape or a click outside closes without a choice.
+///
+/// Storage: `active` open last frame, `string` query, `int[1]` highlighted
+/// row, `int[2]` first visible row.
+pub fn command_palette(ui: &mut Ui<'_>, open: &mut bool) -> Option<Id> {
+    let id = ui.ctx.make_id("##command_palette");
+    if !*open {
+        if ui.ctx.get_storage(id).is_some_and(|s| s.active) {
+            ui.ctx.get_storage_mut(id).active = false;
+            if ui.ctx.focus_item == Some(id) { ui.ctx.focus_item = None; }
+        }
+        return None;
+    }
+
+    let just_opened = !ui.ctx.get_storage(id).is_some_and(|s| s.active);
+    let (mut query, mut highlight, mut scroll) = match ui.ctx.get_storage(id) {
+        Some(s) if !just_opened => (s.string.clone(), s.int[1].max(0) as usize, s.int[2].max(0) as usize),
+        _ => (String::new(), 0, 0),
+    };
+    if just_opened { ui.ctx.focus_item = Some(id); }
+
+    // ── Input ────────────────────────────────────────────────────────────────
+    let mut query_changed = false;
+    let mut close  = false;
+    let mut chosen = None;
+    if ui.ctx.focus_item == Some(id) {
+        let typed: String = ui.ctx.input.text_input.chars().filter(|c| !c.is_control()).collect();
+        if !typed.is_empty() { query.push_str(&typed); query_changed = true; }
+        if ui.ctx.input.key_pressed(Key::Backspace) && query.pop().is_some() { query_changed = true; }
+        if ui.ctx.input.key_pressed(Key::Escape) { close = true; }
+    }
+    if query_changed { highlight = 0; scroll = 0; }
+
+    let hits = rank(ui, &query);
+
+    let mut follow = just_opened || query_changed;
+    if ui.ctx.focus_item == Some(id) && !hits.is_empty() {
+        let input = &ui.ctx.input;
+        let last  = hits.len() - 1;
+        let old   = highlight;
+        if input.key_pressed(Key::Down)     { highlight = (highlight + 1).min(last); }
+        if input.key_pressed(Key::Up)       { highlight = highlight.saturating_sub(1); }
+        if input.key_pressed(Key::PageDown) { highlight = (highlight + MAX_ROWS).min(last); }
+        if input.key_pressed(Key::PageUp)   { highlight = highlight.saturating_sub(MAX_ROWS); }
+        follow |= highlight != old;
+        if input.key_pressed(Key::Enter) {
+            chosen = hits.get(highlight).map(|h| h.cmd);
+        }
+    }
+    highlight = highlight.min(hits.len().saturating_sub(1));
+
+    // ── Layout ───────────────────────────────────────────────────────────────
+    let fs      = ui.ctx.style.font_size;
+    let fp      = ui.ctx.style.frame_padding;
+    let display = ui.ctx.input.display_size;
+    let width   = MAX_WIDTH.min(display.x - 40.0).max(160.0);
+    let field_h = fs + fp.1 * 2.0 + 4.0;
+    let row_h   = fs + ROW_PAD;
+    let rows    = hits.len().clamp(1, MAX_ROWS);
+    let height  = fp.1 * 3.0 + field_h + rows as f32 * row_h;
+    let panel   = Rect::from_min_size(
+        Vec2::new((display.x - width) * 0.5, (display.y * 0.15).floor()),
+        Vec2::new(width, height),
+    );
+    let field = Rect::from_min_size(
+        panel.min + Vec2::new(fp.1, fp.1),
+        Vec2::new(width - fp.1 * 2.0, field_h),
+    );
+    let list_top = field.max.y + fp.1;
+
+    let max_scroll = hits.len().saturating_sub(MAX_ROWS);
+    if panel.contains(ui.ctx.input.mouse_pos) && ui.ctx.input.mouse_wheel != 0.0 {
+        let step = -ui.ctx.input.mouse_wheel.signum() as isize;
+        scroll = (scroll as isize + step).max(0) as usize;
+    }
+    if follow {
+        if highlight < scroll { scroll = highlight; }
+        if highlight >= scroll + MAX_ROWS { scroll = highlight + 1 - MAX_ROWS; }
+    }
+    scroll = scroll.min(max_scroll);
+
+    // ── Drawing ──────────────────────────────────────────────────────────────
+    let rounding = ui.ctx.style.frame_rounding;
+    let tc       = ui.ctx.style.color(StyleColor::Text);
+    let dc       = ui.ctx.style.color(StyleColor::TextDisabled);
+    let hl_col   = ui.ctx.style.color(StyleColor::CheckMark);
+    {
+        let style = &ui.ctx.style;
+        let draw  = &mut ui.ctx.draw_list;
+        draw.filled_rect(Rect::from_min_size(Vec2::ZERO, display), 0.0, Color::BLACK.with_alpha(0.35));
+        draw.filled_rect(panel, style.window_rounding, style.color(StyleColor::PopupBg));
+        draw.rect_outline(panel, 1.0, style.color(StyleColor::Border));
+        draw.filled_rect(field, rounding, style.color(StyleColor::FrameBg));
+        draw.rect_outline(field, 1.0, style.color(StyleColor::SliderGrab));
+    }
+
+    let tp = Vec2::new(field.min.x + fp.0, field.min.y + (field_h - fs) * 0.5);
+    if query.is_empty() {
+        ui.draw_text("Type a command...", tp, dc);
+    } else {
+        ui.draw_text(&query, tp, tc);
+    }
+    if (ui.ctx.input.frame_count / 30) & 1 == 0 {
+        let cx = tp.x + ui.text_width(&query);
+        ui.ctx.draw_list.line(Vec2::new(cx, tp.y), Vec2::new(cx, tp.y + fs), 1.0, tc);
+    }
+
+    if hits.is_empty() {
+        let msg = if ui.ctx.commands.is_empty() { "No commands registered" } else { "No matching commands" };
+        ui.draw_text(msg, Vec2::new(panel.min.x + fp.0 * 2.0, list_top + (row_h - fs) * 0.5), dc);
+    }
+
+    for (row, (hi, hit)) in hits.iter().enumerate().skip(scroll).take(MAX_ROWS).enumerate() {
+        let row_rect = Rect::from_min_size(
+            Vec2::new(panel.min.x + fp.1, list_top + row as f32 * row_h),
+            Vec2::new(width - fp.1 * 2.0, row_h),
+        );
+        let row_id = id.combine(ui.ctx.commands[hit.cmd].id);
+        let (hovered, _, clicked) = ui.ctx.button_behavior(row_id, row_rect);
+        // Pressing a row focuses it; keep typing going to the palette
+        if ui.ctx.focus_item == Some(row_id) { ui.ctx.focus_item = Some(id); }
+        if hovered && ui.ctx.input.mouse_delta != Vec2::ZERO { highlight = hi; }
+        if clicked { chosen = Some(hit.cmd); }
+        if hi == highlight {
+            let hc = ui.ctx.style.color(StyleColor::HeaderActive);
+            ui.ctx.draw_list.filled_rect(row_rect, rounding, hc);
+        } else if hovered {
+            let hc = ui.ctx.style.color(StyleColor::HeaderHovered);
+            ui.ctx.draw_list.filled_rect(row_rect, rounding, hc);
+        }
+
+        let cmd      = &ui.ctx.commands[hit.cmd];
+        let category = cmd.category.clone();
+        let name     = cmd.name.clone();
+        let shortcut = cmd.shortcut.map(|c| c.to_string());
+        let ty       = row_rect.min.y + (row_h - fs) * 0.5;
+        let mut x    = row_rect.min.x + fp.0;
+
+        // Highlight positions index into "category: name"
+        let cat_chars = if category.is_empty() { 0 } else { category.chars().count() + CATEGORY_SEP.chars().count() };
+        if !category.is_empty() {
+            let cat_pos: Vec<usize> = hit.found.positions.iter().copied().filter(|&p| p < cat_chars).collect();
+            let prefix = format!("{category}{CATEGORY_SEP}");
+            ui.draw_text_highlighted(&prefix, Vec2::new(x, ty), dc, hl_col, &cat_pos);
+            x += ui.text_width(&prefix);
+        }
+        let name_pos: Vec<usize> = hit.found.positions.iter()
+            .filter(|&&p| p >= cat_chars)
+            .map(|&p| p - cat_chars)
+            .collect();
+        ui.draw_text_highlighted(&name, Vec2::new(x, ty), tc, hl_col, &name_pos);
+
+        if let Some(sc) = shortcut {
+            let sw = ui.text_width(&sc);
+            ui.draw_text(&sc, Vec2::new(row_rect.max.x - fp.0 - sw, ty), dc);
+        }
+    }
+
+    if ui.ctx.input.mouse_clicked(MouseButton::Left) && !panel.contains(ui.ctx.input.mouse_pos) {
+        close = true;
+    }
+
+    let chosen_id = chosen.map(|i| ui.ctx.commands[i].id);
+    if let Some(cid) = chosen_id {
+        ui.ctx.push_recent_command(cid);
+        close = true;
+    }
+    if close {
+        *open = false;
+        if ui.ctx.focus_item == Some(id) { ui.ctx.focus_item = None; }
+    }
+
+    let s = ui.ctx.get_storage_mut(id);
+    s.active = !close;
+    s.string = query;
+    s.int[1] = highlight as i32;
+    s.int[2] = scroll as i32;
+
+    chosen_id
+}
+
+/// Rank commands for `query`; recent commands get a small boost and lead
+/// the list when the query is empty.
+fn rank(ui: &Ui<'_>, query: &str) -> Vec<Hit> {
+    let recent = &ui.ctx.recent_commands;
+    let recency = |id: Id| recent.iter().position(|&r| r == id).map_or(0, |p| (recent.len() - p) as i32);
+
+    let mut hits: Vec<(i32, Hit)> = ui.ctx.commands.iter().enumerate()
+        .filter_map(|(i, c)| {
+            let text = if c.category.is_empty() { c.name.clone() }
+                       else { format!("{}{CATEGORY_SEP}{}", c.category, c.name) };
+            let found = fuzzy::fuzzy_match(query, &text)?;
+            let boost = recency(c.id);
+            let key   = if query.is_empty() { boost } else { found.score * 4 + boost };
+            Some((key, Hit { cmd: i, found }))
+        })
+        .collect();
+    hits.sort_by_key(|(key, _)| std::cmp::Reverse(*key));
+    hits.into_iter().map(|(_, h)| h).collect()
+}
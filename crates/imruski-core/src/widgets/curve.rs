@@ -0,0 +1,171 @@
+//! Easing-curve editor.
+//!
+//! Bézier curves show their two control handles; linear curves show their
+//! points – click empty space to add one, right-click a point to remove it
+//! (two always remain). Hold Ctrl while dragging to snap to the grid.
+
+use crate::{
+    curve::{cubic, Curve},
+    id::parse_label,
+    input::MouseButton,
+    style::StyleColor,
+    ui::Ui,
+    Color, Rect, Vec2,
+};
+
+/// Grid cells per unit; Ctrl-drag snaps to these lines.
+const GRID_DIV:  usize = 10;
+/// Values shown below 0 and above 1 so overshoot stays visible.
+const Y_MARGIN:  f32 = 0.25;
+/// Point radius, also its pick tolerance.
+const POINT_R:   f32 = 4.0;
+/// Line segments used to draw a Bézier curve.
+const BEZIER_SEGS: usize = 48;
+
+/// Storage: `int[0]` is the point being dragged + 1 (0 = none).
+pub fn curve_edit(ui: &mut Ui<'_>, str_id: &str, curve: &mut Curve) -> bool {
+    let (_, id_src) = parse_label(str_id);
+    let id = ui.ctx.make_id(id_src);
+
+    let w = ui.available_width().min(320.0);
+    let size = Vec2::new(w, w * 0.75);
+    let pos = match ui.layout_next(size) { Some(p) => p, None => return false };
+    let rect = Rect::from_min_size(pos, size);
+
+    // Curve space (x 0..1, y -margin..1+margin, up) ↔ screen
+    let span = 1.0 + Y_MARGIN * 2.0;
+    let to_screen = |p: Vec2| Vec2::new(rect.min.x + p.x * size.x, rect.max.y - (p.y + Y_MARGIN) / span * size.y);
+    let to_curve  = |s: Vec2| Vec2::new((s.x - rect.min.x) / size.x, (rect.max.y - s.y) / size.y * span - Y_MARGIN);
+
+    let (hovered, _, _) = ui.ctx.button_behavior(id, rect);
+    let m = ui.ctx.input.mouse_pos;
+    let snap = ui.ctx.input.ctrl();
+    let target = |s: Vec2| {
+        let mut p = to_curve(s);
+        if snap {
+            let step = 1.0 / GRID_DIV as f32;
+            p = Vec2::new((p.x / step).round() * step, (p.y / step).round() * step);
+        }
+        Vec2::new(p.x.clamp(0.0, 1.0), p.y.clamp(-Y_MARGIN, 1.0 + Y_MARGIN))
+    };
+
+    // Editable points in curve space
+    let points: Vec<Vec2> = match curve {
+        Curve::Bezier { p1, p2 } => vec![*p1, *p2],
+        Curve::Linear(pts)       => pts.clone(),
+    };
+    let nearest = points.iter().enumerate()
+        .map(|(i, &p)| (i, (to_screen(p) - m).length()))
+        .filter(|&(_, d)| d <= POINT_R + 2.0)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i);
+
+    // ── Input ────────────────────────────────────────────────────────────────
+    let mut changed = false;
+    let mut dragging = ui.ctx.get_storage(id).and_then(|s| (s.int[0] > 0).then(|| s.int[0] as usize - 1));
+    if hovered && ui.ctx.input.mouse_clicked(MouseButton::Left) {
+        dragging = nearest;
+        if let (None, Curve::Linear(pts)) = (nearest, &mut *curve) {
+            let p = target(m);
+            let i = pts.partition_point(|q| q.x <= p.x);
+            pts.insert(i, p);
+            dragging = Some(i);
+            changed = true;
+        }
+    }
+    if hovered && ui.ctx.input.mouse_clicked(MouseButton::Right) {
+        if let (Some(i), Curve::Linear(pts)) = (nearest, &mut *curve) {
+            if pts.len() > 2 {
+                pts.remove(i);
+                dragging = None;
+                changed = true;
+            }
+        }
+    }
+    if !ui.ctx.input.mouse_down(MouseButton::Left) { dragging = None; }
+    if let Some(i) = dragging {
+        let p = target(m);
+        match curve {
+            Curve::Bezier { p1, p2 } => {
+                let handle = if i == 0 { p1 } else { p2 };
+                if *handle != p { *handle = p; changed = true; }
+            }
+            Curve::Linear(pts) if i < pts.len() => {
+                if pts[i] != p {
+                    pts.remove(i);
+                    let ni = pts.partition_point(|q| q.x <= p.x);
+                    pts.insert(ni, p);
+                    dragging = Some(ni);
+                    changed = true;
+                }
+            }
+            Curve::Linear(_) => dragging = None,
+        }
+    }
+    ui.ctx.get_storage_mut(id).int[0] = dragging.map_or(0, |i| i as i32 + 1);
+
+    // ── Grid ─────────────────────────────────────────────────────────────────
+    let bg    = ui.ctx.style.color(StyleColor::FrameBg);
+    let grid  = ui.ctx.style.color(StyleColor::PlotGrid);
+    let frame = ui.ctx.style.color(StyleColor::Border);
+    let line  = ui.ctx.style.color(StyleColor::PlotLines);
+    let point = ui.ctx.style.color(StyleColor::SliderGrab);
+    let hot   = ui.ctx.style.color(StyleColor::SliderGrabActive);
+    let rounding = ui.ctx.style.frame_rounding;
+
+    ui.ctx.draw_list.filled_rect(rect, rounding, bg);
+    ui.ctx.draw_list.push_clip_rect(rect);
+    for k in 0..=GRID_DIV {
+        let t = k as f32 / GRID_DIV as f32;
+        let x = to_screen(Vec2::new(t, 0.0)).x;
+        let y = to_screen(Vec2::new(0.0, t)).y;
+        ui.ctx.draw_list.line(Vec2::new(x, rect.min.y), Vec2::new(x, rect.max.y), 1.0, grid);
+        ui.ctx.draw_list.line(Vec2::new(rect.min.x, y), Vec2::new(rect.max.x, y), 1.0, grid);
+    }
+    // The 0 and 1 levels stand out
+    for level in [0.0, 1.0] {
+        let y = to_screen(Vec2::new(0.0, level)).y;
+        ui.ctx.draw_list.line(Vec2::new(rect.min.x, y), Vec2::new(rect.max.x, y), 1.0, frame);
+    }
+
+    // ── Curve ────────────────────────────────────────────────────────────────
+    let (start, end) = (Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+    let path: Vec<Vec2> = match &*curve {
+        Curve::Bezier { p1, p2 } => (0..=BEZIER_SEGS)
+            .map(|k| {
+                let s = k as f32 / BEZIER_SEGS as f32;
+                Vec2::new(cubic(p1.x, p2.x, s), cubic(p1.y, p2.y, s))
+            })
+            .collect(),
+        Curve::Linear(pts) => {
+            let (first, last) = (pts.first().map_or(0.0, |p| p.y), pts.last().map_or(1.0, |p| p.y));
+            std::iter::once(Vec2::new(0.0, first)).chain(pts.iter().copied()).chain(std::iter::once(Vec2::new(1.0, last))).collect()
+        }
+    };
+    for seg in path.windows(2) {
+        ui.ctx.draw_list.line(to_screen(seg[0]), to_screen(seg[1]), 2.0, line);
+    }
+
+    // ── Points ───────────────────────────────────────────────────────────────
+    if let Curve::Bezier { p1, p2 } = &*curve {
+        ui.ctx.draw_list.line(to_screen(start), to_screen(*p1), 1.0, point);
+        ui.ctx.draw_list.line(to_screen(end), to_screen(*p2), 1.0, point);
+    }
+    let points: Vec<Vec2> = match &*curve {
+        Curve::Bezier { p1, p2 } => vec![*p1, *p2],
+        Curve::Linear(pts)       => pts.clone(),
+    };
+    for (i, &p) in points.iter().enumerate() {
+        let col = if dragging == Some(i) || (dragging.is_none() && nearest == Some(i)) { hot } else { point };
+        ui.ctx.draw_list.filled_circle(to_screen(p), POINT_R + 1.0, Color::BLACK.with_alpha(0.6), 12);
+        ui.ctx.draw_list.filled_circle(to_screen(p), POINT_R, col, 12);
+    }
+    ui.ctx.draw_list.pop_clip_rect();
+    ui.ctx.draw_list.rect_outline(rect, 1.0, frame);
+
+    if let Some(p) = dragging.and_then(|i| points.get(i)) {
+        ui.set_tooltip(&format!("{:.2}, {:.2}", p.x, p.y));
+    }
+
+    changed
+}
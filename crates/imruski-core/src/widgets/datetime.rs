@@ -0,0 +1,266 @@
+//! Date picker and time input.
+//!
+//! - `date_picker`  – date field with a month-grid popup
+//! - `time_input`   – hour / minute / second spinners
+//!
+//! Both edit the [`calendar`](crate::calendar) types.
+
+use crate::{
+    calendar::{Date, Time, Weekday, MONTH_NAMES},
+    id::{parse_label, Id},
+    input::MouseButton,
+    style::StyleColor,
+    ui::Ui,
+    widgets::slider::{component_row, drag_behavior, DragArgs},
+    Rect, Vec2,
+};
+
+/// Limits and layout for `date_picker_ex`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DatePickerOptions {
+    /// Earliest selectable date.
+    pub min:        Option<Date>,
+    /// Latest selectable date.
+    pub max:        Option<Date>,
+    /// First column of the month grid.
+    pub week_start: Weekday,
+}
+
+impl DatePickerOptions {
+    fn allows(&self, d: Date) -> bool {
+        d >= self.min.unwrap_or(d) && d <= self.max.unwrap_or(d)
+    }
+}
+
+/// Day cell size relative to the font size.
+const CELL_SCALE: f32 = 1.9;
+const POPUP_PAD:  f32 = 6.0;
+
+// ─── Date picker ─────────────────────────────────────────────────────────────
+
+pub fn date_picker(ui: &mut Ui<'_>, label: &str, date: &mut Date) -> bool {
+    date_picker_ex(ui, label, date, &DatePickerOptions::default())
+}
+
+/// Storage: `active` while the popup is open, `int[0]` / `int[1]` the
+/// year and month shown in it.
+pub fn date_picker_ex(ui: &mut Ui<'_>, label: &str, date: &mut Date, opts: &DatePickerOptions) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+
+    let fs    = ui.ctx.style.font_size;
+    let fp    = ui.ctx.style.frame_padding;
+    let sp    = ui.ctx.style.item_spacing;
+    let h     = fs + fp.1 * 2.0;
+    let tw    = ui.text_width(text);
+    let box_w = (ui.available_width() - tw - sp.0).max(100.0);
+
+    let pos = match ui.layout_next(Vec2::new(box_w + sp.0 + tw, h)) { Some(p) => p, None => return false };
+    let box_rect = Rect::from_min_size(pos, Vec2::new(box_w, h));
+
+    let (hovered, _, clicked) = ui.ctx.button_behavior(id, box_rect);
+    if clicked {
+        let st = ui.ctx.get_storage_mut(id);
+        st.active = !st.active;
+        st.int[0] = date.year();
+        st.int[1] = i32::from(date.month());
+    }
+
+    // ── Field ────────────────────────────────────────────────────────────────
+    let bg = if hovered { ui.ctx.style.color(StyleColor::FrameBgHovered) } else { ui.ctx.style.color(StyleColor::FrameBg) };
+    let rounding = ui.ctx.style.frame_rounding;
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    ui.ctx.draw_list.filled_rect(box_rect, rounding, bg);
+    ui.ctx.draw_list.rect_outline(box_rect, 1.0, ui.ctx.style.color(StyleColor::Border));
+    let shown = format!("{} {}", date, &date.weekday().name()[..3]);
+    ui.draw_text(&shown, Vec2::new(pos.x + fp.0, pos.y + fp.1), tc);
+    // Calendar glyph: a page with a header strip
+    let icon = Rect::from_min_size(Vec2::new(box_rect.max.x - fp.0 - fs * 0.8, pos.y + (h - fs * 0.8) * 0.5), Vec2::splat(fs * 0.8));
+    ui.ctx.draw_list.rect_outline(icon, 1.0, tc);
+    ui.ctx.draw_list.filled_rect(Rect::new(icon.min, Vec2::new(icon.max.x, icon.min.y + icon.height() * 0.3)), 0.0, tc);
+    if !text.is_empty() {
+        ui.draw_text(text, Vec2::new(box_rect.max.x + sp.0, pos.y + (h - fs) * 0.5), tc);
+    }
+
+    if !ui.ctx.get_storage(id).is_some_and(|s| s.active) { return false; }
+    let popup_pos = Vec2::new(pos.x, box_rect.max.y + 2.0);
+    let (changed, popup) = calendar_popup(ui, id, popup_pos, date, opts);
+    let clicked_outside = ui.ctx.input.mouse_clicked(MouseButton::Left)
+        && !popup.contains(ui.ctx.input.mouse_pos)
+        && !box_rect.contains(ui.ctx.input.mouse_pos);
+    if changed || clicked_outside {
+        ui.ctx.get_storage_mut(id).active = false;
+    }
+    changed
+}
+
+/// Month grid with year / month navigation. Returns whether a day was
+/// picked and the popup rect.
+fn calendar_popup(ui: &mut Ui<'_>, id: Id, pos: Vec2, date: &mut Date, opts: &DatePickerOptions) -> (bool, Rect) {
+    let fs   = ui.ctx.style.font_size;
+    let cell = (fs * CELL_SCALE).round();
+    let row_h = cell;
+    let grid_w = cell * 7.0;
+    // Header, weekday names, six weeks, "Today"
+    let popup = Rect::from_min_size(pos, Vec2::new(grid_w + POPUP_PAD * 2.0, row_h * 9.0 + POPUP_PAD * 2.0));
+    let rounding = ui.ctx.style.frame_rounding;
+    ui.ctx.draw_list.filled_rect(popup, 4.0, ui.ctx.style.color(StyleColor::PopupBg));
+    ui.ctx.draw_list.rect_outline(popup, 1.0, ui.ctx.style.color(StyleColor::Border));
+
+    let (vy, vm) = ui.ctx.get_storage(id).map_or((date.year(), date.month()), |s| (s.int[0], s.int[1].clamp(1, 12) as u8));
+    let view = Date::new(vy, vm, 1).unwrap_or_else(|| date.first_of_month());
+    let origin = pos + Vec2::splat(POPUP_PAD);
+    let tc  = ui.ctx.style.color(StyleColor::Text);
+    let dim = ui.ctx.style.color(StyleColor::TextDisabled);
+
+    // ── Header: «  ‹  Month Year  ›  » ──────────────────────────────────────
+    let mut new_view = view;
+    let nav = [(-12, 0.0, "__prev_year"), (-1, 1.0, "__prev_month"), (1, 5.0, "__next_month"), (12, 6.0, "__next_year")];
+    for (step, col, key) in nav {
+        let r = Rect::from_min_size(Vec2::new(origin.x + col * cell, origin.y), Vec2::splat(cell));
+        let (hov, _, _) = ui.ctx.button_behavior(id.combine(Id::from_str(key)), r);
+        if hov {
+            let hc = ui.ctx.style.color(StyleColor::HeaderHovered);
+            ui.ctx.draw_list.filled_rect(r, rounding, hc);
+            if ui.ctx.input.mouse_clicked(MouseButton::Left) { new_view = view.add_months(step); }
+        }
+        let c = r.center();
+        let dir = if step < 0 { -1.0 } else { 1.0 };
+        let offsets: &[f32] = if step.abs() == 12 { &[-3.0, 3.0] } else { &[0.0] };
+        for &o in offsets {
+            let tip = Vec2::new(c.x + o + dir * 3.0, c.y);
+            let back = c.x + o - dir * 3.0;
+            ui.ctx.draw_list.triangle_filled(tip, Vec2::new(back, c.y - 4.0), Vec2::new(back, c.y + 4.0), tc);
+        }
+    }
+    let title = format!("{} {}", MONTH_NAMES[view.month() as usize - 1], view.year());
+    let title_w = ui.text_width(&title);
+    ui.draw_text(&title, Vec2::new(origin.x + (grid_w - title_w) * 0.5, origin.y + (row_h - fs) * 0.5), tc);
+    // Mouse wheel over the popup flips months too
+    let wheel = ui.ctx.input.mouse_wheel;
+    if wheel != 0.0 && popup.contains(ui.ctx.input.mouse_pos) {
+        new_view = view.add_months(if wheel > 0.0 { -1 } else { 1 });
+    }
+
+    // ── Weekday names ────────────────────────────────────────────────────────
+    let names_y = origin.y + row_h;
+    for c in 0..7 {
+        let name = opts.week_start.plus(c).short_name();
+        let w = ui.text_width(name);
+        ui.draw_text(name, Vec2::new(origin.x + c as f32 * cell + (cell - w) * 0.5, names_y + (row_h - fs) * 0.5), dim);
+    }
+
+    // ── Days ─────────────────────────────────────────────────────────────────
+    let today  = Date::today();
+    let lead   = (view.weekday().index() + 7 - opts.week_start.index()) % 7;
+    let first  = view.add_days(-(lead as i64));
+    let grid_y = names_y + row_h;
+    let mut picked = None;
+    for i in 0..42 {
+        let d = first.add_days(i as i64);
+        let r = Rect::from_min_size(Vec2::new(origin.x + (i % 7) as f32 * cell, grid_y + (i / 7) as f32 * row_h), Vec2::splat(cell));
+        let inner = Rect::new(r.min + Vec2::splat(1.0), r.max - Vec2::splat(1.0));
+        let enabled = opts.allows(d);
+        let hov = enabled && ui.ctx.button_behavior(id.combine(Id::from_hash(&i)), r).0;
+        if d == *date {
+            let c = ui.ctx.style.color(StyleColor::HeaderActive);
+            ui.ctx.draw_list.filled_rect(inner, rounding, c);
+        } else if hov {
+            let c = ui.ctx.style.color(StyleColor::HeaderHovered);
+            ui.ctx.draw_list.filled_rect(inner, rounding, c);
+        }
+        if d == today {
+            let c = ui.ctx.style.color(StyleColor::SliderGrab);
+            ui.ctx.draw_list.rect_outline(inner, 1.0, c);
+        }
+        if hov && ui.ctx.input.mouse_clicked(MouseButton::Left) { picked = Some(d); }
+
+        let num = d.day().to_string();
+        let w = ui.text_width(&num);
+        let col = if !enabled {
+            dim.with_alpha(dim.a * 0.5)
+        } else if d.month() != view.month() {
+            dim
+        } else {
+            tc
+        };
+        ui.draw_text(&num, Vec2::new(r.min.x + (cell - w) * 0.5, r.min.y + (row_h - fs) * 0.5), col);
+    }
+
+    // ── Today ────────────────────────────────────────────────────────────────
+    let label = "Today";
+    let lw = ui.text_width(label) + 12.0;
+    let today_rect = Rect::from_min_size(Vec2::new(origin.x + (grid_w - lw) * 0.5, grid_y + row_h * 6.0 + 2.0), Vec2::new(lw, row_h - 4.0));
+    let today_ok = opts.allows(today);
+    let (hov, _, _) = ui.ctx.button_behavior(id.combine(Id::from_str("__today")), today_rect);
+    let bc = if hov && today_ok { StyleColor::ButtonHovered } else { StyleColor::Button };
+    let bc = ui.ctx.style.color(bc);
+    ui.ctx.draw_list.filled_rect(today_rect, rounding, bc);
+    ui.draw_text(label, Vec2::new(today_rect.min.x + 6.0, today_rect.min.y + (today_rect.height() - fs) * 0.5), if today_ok { tc } else { dim });
+    if hov && today_ok && ui.ctx.input.mouse_clicked(MouseButton::Left) { picked = Some(today); }
+
+    let st = ui.ctx.get_storage_mut(id);
+    st.int[0] = new_view.year();
+    st.int[1] = i32::from(new_view.month());
+    match picked {
+        Some(d) if d != *date => { *date = d; (true, popup) }
+        // Re-picking the current day still closes the popup
+        Some(_) => { ui.ctx.get_storage_mut(id).active = false; (false, popup) }
+        None    => (false, popup),
+    }
+}
+
+// ─── Time input ──────────────────────────────────────────────────────────────
+
+/// Three boxes for hours, minutes and seconds. Drag or Ctrl+click a box
+/// like a drag widget; its arrows (or the mouse wheel) step it, wrapping
+/// without carrying into the next unit.
+pub fn time_input(ui: &mut Ui<'_>, label: &str, time: &mut Time) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+    let Some((rects, label_pos)) = component_row(ui, text, 3) else { return false };
+
+    const UNITS: [(&str, i32); 3] = [("%02dh", 24), ("%02dm", 60), ("%02ds", 60)];
+    let mut parts = [i32::from(time.hour()), i32::from(time.minute()), i32::from(time.second())];
+    let mut changed = false;
+    for (i, (&(format, modulo), &rect)) in UNITS.iter().zip(&rects).enumerate() {
+        let field_id = id.combine(Id::from_hash(&i));
+        let arrow_w  = (rect.height() * 0.6).min(rect.width() * 0.4);
+        let value_r  = Rect::new(rect.min, Vec2::new(rect.max.x - arrow_w, rect.max.y));
+        let args = DragArgs { speed: 0.1, clamp: Some((0, modulo - 1)), format, component: None };
+        changed |= drag_behavior(ui, field_id, value_r, &mut parts[i], args);
+
+        // ── Spinner arrows ───────────────────────────────────────────────────
+        let mid = rect.center().y;
+        let up   = Rect::new(Vec2::new(value_r.max.x, rect.min.y), Vec2::new(rect.max.x, mid));
+        let down = Rect::new(Vec2::new(value_r.max.x, mid), rect.max);
+        let mut step = 0;
+        for (r, dir, key) in [(up, 1, "__up"), (down, -1, "__down")] {
+            let (hov, _, _) = ui.ctx.button_behavior(field_id.combine(Id::from_str(key)), r);
+            let bg = if hov { ui.ctx.style.color(StyleColor::FrameBgHovered) } else { ui.ctx.style.color(StyleColor::FrameBg) };
+            ui.ctx.draw_list.filled_rect(r, 0.0, bg);
+            if hov && ui.ctx.input.mouse_clicked(MouseButton::Left) { step = dir; }
+            let (c, s) = (r.center(), (r.height() * 0.3).min(4.0));
+            let dy = s * dir as f32;
+            let tc = ui.ctx.style.color(StyleColor::Text);
+            ui.ctx.draw_list.triangle_filled(Vec2::new(c.x, c.y - dy), Vec2::new(c.x + s, c.y + dy * 0.6), Vec2::new(c.x - s, c.y + dy * 0.6), tc);
+        }
+        let wheel = ui.ctx.input.mouse_wheel;
+        if wheel != 0.0 && rect.contains(ui.ctx.input.mouse_pos) {
+            step = if wheel > 0.0 { 1 } else { -1 };
+        }
+        if step != 0 {
+            parts[i] = (parts[i] + step).rem_euclid(modulo);
+            changed = true;
+        }
+        let border = ui.ctx.style.color(StyleColor::Border);
+        ui.ctx.draw_list.rect_outline(rect, 1.0, border);
+    }
+
+    if changed {
+        if let Some(t) = Time::new(parts[0] as u8, parts[1] as u8, parts[2] as u8) { *time = t; }
+    }
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    ui.draw_text(text, label_pos, tc);
+    changed
+}
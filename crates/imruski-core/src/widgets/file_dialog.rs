@@ -0,0 +1,682 @@
+//! File dialog – an open / save / folder picker over `std::fs`.
+//!
+//! ```ignore
+//! let mut dlg = FileDialog::new(FileDialogMode::Open)
+//!     .with_filter("Images", &["png", "jpg"])
+//!     .with_multi_select();
+//! if ui.button("Open…") { dlg.open(); }
+//! // after the app's other windows, so the dialog draws on top
+//! if let Some(paths) = ui.file_dialog(&mut dlg) { load(&paths); }
+//! ```
+//!
+//! The dialog owns its state; keep it alive across frames. It shows
+//! breadcrumbs, an editable path, bookmarks ("Places"), a sortable listing
+//! with extension filters and optional hidden files, a new-folder row and,
+//! when saving, overwrite confirmation.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    calendar::{Date, Time},
+    clipper::ListClipper,
+    context::WindowState,
+    id::{parse_label, Id},
+    input::{Key, MouseButton},
+    selection::Selection,
+    ui::Ui,
+    widgets::table::{SortDirection, TableColumnFlags, TableFlags},
+    Color, Vec2, WindowFlags,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FileDialogMode {
+    /// Pick existing files.
+    #[default]
+    Open,
+    /// Pick a file name to write; asks before replacing an existing file.
+    Save,
+    /// Pick directories.
+    SelectFolder,
+}
+
+const DEFAULT_SIZE: Vec2 = Vec2 { x: 640.0, y: 420.0 };
+const SIZE_COL_W:   f32  = 80.0;
+const DATE_COL_W:   f32  = 120.0;
+/// Footer rows below the listing: file name, filter / buttons, status.
+const FOOTER_ROWS:  f32  = 3.0;
+
+/// A named set of extensions (lowercase, without the dot); empty = all files.
+#[derive(Debug, Clone)]
+struct Filter {
+    name:       String,
+    extensions: Vec<String>,
+}
+
+impl Filter {
+    fn matches(&self, path: &Path) -> bool {
+        self.extensions.is_empty()
+            || path.extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| self.extensions.iter().any(|x| x.eq_ignore_ascii_case(e)))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    name:     String,
+    path:     PathBuf,
+    is_dir:   bool,
+    size:     u64,
+    modified: Option<SystemTime>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileDialog {
+    title:        String,
+    mode:         FileDialogMode,
+    multi_select: bool,
+    open:         bool,
+    dir:          PathBuf,
+    /// Text of the editable path field.
+    path_buf:     String,
+    file_name:    String,
+    filters:      Vec<Filter>,
+    filter:       usize,
+    show_hidden:  bool,
+    /// Listing of `dir` after filtering, in display order.
+    entries:      Vec<Entry>,
+    /// `entries` must be re-read from disk.
+    stale:        bool,
+    /// Indices into `entries`.
+    selection:    Selection,
+    sort:         (usize, SortDirection),
+    bookmarks:    Vec<(String, PathBuf)>,
+    /// Name being typed into the new-folder row.
+    new_folder:   Option<String>,
+    /// Existing file waiting for "Replace".
+    confirm:      Option<PathBuf>,
+    error:        Option<String>,
+}
+
+impl FileDialog {
+    /// Closed dialog in the current working directory.
+    pub fn new(mode: FileDialogMode) -> Self {
+        let title = match mode {
+            FileDialogMode::Open         => "Open File",
+            FileDialogMode::Save         => "Save File",
+            FileDialogMode::SelectFolder => "Select Folder",
+        };
+        let dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        Self {
+            title:        title.to_owned(),
+            mode,
+            multi_select: false,
+            open:         false,
+            path_buf:     dir.display().to_string(),
+            dir,
+            file_name:    String::new(),
+            filters:      Vec::new(),
+            filter:       0,
+            show_hidden:  false,
+            entries:      Vec::new(),
+            stale:        true,
+            selection:    Selection::new(),
+            sort:         (0, SortDirection::Ascending),
+            bookmarks:    default_bookmarks(),
+            new_folder:   None,
+            confirm:      None,
+            error:        None,
+        }
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self { self.title = title.to_owned(); self }
+
+    /// Add an extension filter, e.g. `("Images", &["png", "jpg"])`. The
+    /// first one added is active; an empty list matches every file.
+    pub fn with_filter(mut self, name: &str, extensions: &[&str]) -> Self {
+        self.filters.push(Filter {
+            name:       name.to_owned(),
+            extensions: extensions.iter().map(|e| e.trim_start_matches('.').to_ascii_lowercase()).collect(),
+        });
+        self
+    }
+
+    /// Allow picking several entries (Ctrl / Shift+click). Ignored when saving.
+    pub fn with_multi_select(mut self) -> Self { self.multi_select = true; self }
+
+    /// Start in `dir` instead of the working directory.
+    pub fn with_directory(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.set_dir(dir.into());
+        self
+    }
+
+    /// Pre-fill the file name field.
+    pub fn with_file_name(mut self, name: &str) -> Self { self.file_name = name.to_owned(); self }
+
+    /// Add an entry to the "Places" list.
+    pub fn with_bookmark(mut self, name: &str, path: impl Into<PathBuf>) -> Self {
+        self.bookmarks.push((name.to_owned(), path.into()));
+        self
+    }
+
+    /// Show the dialog from the next `show`, re-reading the directory.
+    pub fn open(&mut self) {
+        self.open       = true;
+        self.stale      = true;
+        self.new_folder = None;
+        self.confirm    = None;
+        self.error      = None;
+    }
+
+    pub fn is_open(&self) -> bool { self.open }
+
+    pub fn mode(&self) -> FileDialogMode { self.mode }
+
+    /// Directory currently listed.
+    pub fn directory(&self) -> &Path { &self.dir }
+
+    /// Draw the dialog while it is open. Returns the chosen paths once, on
+    /// the frame the user confirms; the dialog then closes. Closing or
+    /// cancelling returns `None`, like every other frame.
+    pub fn show(&mut self, ui: &mut Ui<'_>) -> Option<Vec<PathBuf>> {
+        if !self.open { return None; }
+        if self.stale { self.refresh(); }
+
+        let label = format!("{0}##{0}__file_dialog", self.title);
+        let (_, id_src) = parse_label(&label);
+        let win_id = ui.ctx.make_id(id_src);
+        let display = ui.ctx.input.display_size;
+        ui.ctx.windows.entry(win_id).or_insert_with(|| {
+            let size = Vec2::new(DEFAULT_SIZE.x.min(display.x), DEFAULT_SIZE.y.min(display.y));
+            WindowState::new((display - size) * 0.5, size, WindowFlags::empty())
+        });
+
+        let mut open = true;
+        let visible = ui.begin(&label, Some(&mut open), WindowFlags::empty());
+        let mut result = None;
+        if visible {
+            ui.push_id_str(id_src);
+            result = self.contents(ui, win_id);
+            ui.pop_id();
+        }
+        ui.end();
+
+        if !open { self.open = false; }
+        if result.is_some() { self.open = false; }
+        result
+    }
+
+    // ── Rows ─────────────────────────────────────────────────────────────────
+
+    fn contents(&mut self, ui: &mut Ui<'_>, win_id: Id) -> Option<Vec<PathBuf>> {
+        self.breadcrumbs(ui);
+        self.path_field(ui);
+        self.toolbar(ui);
+        let result = self.listing(ui, win_id);
+        result.or_else(|| self.footer(ui))
+    }
+
+    fn breadcrumbs(&mut self, ui: &mut Ui<'_>) {
+        let crumbs: Vec<(String, PathBuf)> = self.dir.ancestors()
+            .map(|p| (crumb_name(p), p.to_path_buf()))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .collect();
+
+        // Drop leading crumbs until the rest fits, keeping at least the last
+        let sp     = ui.ctx.style.item_spacing.0;
+        let avail  = ui.available_width() - ui.text_width("Up") - sp;
+        let widths: Vec<f32> = crumbs.iter().map(|(n, _)| ui.text_width(n) + sp).collect();
+        let mut first = 0;
+        let ellipsis = ui.text_width("...") + sp;
+        while first + 1 < crumbs.len() && widths[first..].iter().sum::<f32>() + ellipsis > avail {
+            first += 1;
+        }
+
+        let mut target = None;
+        if ui.small_button("Up") {
+            target = self.dir.parent().map(Path::to_path_buf);
+        }
+        if first > 0 {
+            ui.same_line(-1.0);
+            ui.text_disabled("...");
+        }
+        for (i, (name, path)) in crumbs.iter().enumerate().skip(first) {
+            ui.same_line(-1.0);
+            if ui.small_button(&format!("{name}##crumb{i}")) {
+                target = Some(path.clone());
+            }
+        }
+        if let Some(t) = target { self.navigate(t); }
+    }
+
+    fn path_field(&mut self, ui: &mut Ui<'_>) {
+        let buf = &mut self.path_buf;
+        if !enter_in(ui, "path", |ui| { ui.input_text("##path", buf); }) { return; }
+
+        let path = PathBuf::from(self.path_buf.trim());
+        if path.is_dir() {
+            self.navigate(path);
+        } else if let (Some(parent), Some(name)) = (path.parent().filter(|p| p.is_dir()), path.file_name()) {
+            self.file_name = name.to_string_lossy().into_owned();
+            self.navigate(parent.to_path_buf());
+        } else {
+            self.error    = Some(format!("No such directory: {}", path.display()));
+            self.path_buf = self.dir.display().to_string();
+        }
+    }
+
+    fn toolbar(&mut self, ui: &mut Ui<'_>) {
+        let mut names: Vec<&str> = vec!["Places"];
+        names.extend(self.bookmarks.iter().map(|(n, _)| n.as_str()));
+        let mut place = 0;
+        let places_w = ui.text_width("Current directory") + ui.ctx.style.font_size * 3.0;
+        narrowed(ui, places_w, |ui| ui.combo("##places", &mut place, &names));
+        if place > 0 {
+            let p = self.bookmarks[place - 1].1.clone();
+            self.navigate(p);
+        }
+
+        ui.same_line(-1.0);
+        if ui.button("New folder") && self.new_folder.is_none() {
+            self.new_folder = Some(String::new());
+            self.confirm    = None;
+        }
+        ui.same_line(-1.0);
+        if ui.button("Refresh") { self.stale = true; }
+        ui.same_line(-1.0);
+        if ui.checkbox("Hidden files", &mut self.show_hidden) { self.stale = true; }
+        if self.stale { self.refresh(); }
+    }
+
+    fn listing(&mut self, ui: &mut Ui<'_>, win_id: Id) -> Option<Vec<PathBuf>> {
+        let fs  = ui.ctx.style.font_size;
+        let fp  = ui.ctx.style.frame_padding;
+        let sp  = ui.ctx.style.item_spacing;
+        let pad = ui.ctx.style.window_padding;
+        let bottom = ui.ctx.windows.get(&win_id).map_or(0.0, |w| w.pos.y + w.size.y - pad.1);
+        let top    = ui.ctx.current_layout_mut().map_or(0.0, |l| l.cursor.y);
+        let footer = (fs + fp.1 * 2.0 + sp.1) * FOOTER_ROWS;
+        let height = (bottom - top - footer).max(fs * 4.0);
+
+        let flags = TableFlags::SORTABLE | TableFlags::SCROLL_Y | TableFlags::ROW_BG
+            | TableFlags::RESIZABLE | TableFlags::BORDERS_OUTER;
+        if !ui.begin_table("entries", 3, flags, Vec2::new(0.0, height)) { return None; }
+        ui.table_setup_column("Name", TableColumnFlags::DEFAULT_SORT | TableColumnFlags::NO_HIDE, 0.0);
+        ui.table_setup_column("Size", TableColumnFlags::WIDTH_FIXED, SIZE_COL_W);
+        ui.table_setup_column("Modified", TableColumnFlags::WIDTH_FIXED, DATE_COL_W);
+        ui.table_setup_scroll_freeze(0, 1);
+        if let Some(specs) = ui.table_sort_specs() {
+            if let Some(s) = specs.specs.first() {
+                let sort = (s.column_index, s.direction);
+                if sort != self.sort {
+                    self.sort = sort;
+                    self.sort_entries();
+                }
+            }
+        }
+        ui.table_headers_row();
+
+        let scope_id = ui.ctx.make_id("files");
+        let mut ms = ui.begin_multi_select("files", self.entries.len());
+        let mut activated = None;
+        // Only the rows in view are formatted and submitted
+        let mut clipper = ListClipper::new(self.entries.len(), 0.0);
+        while let Some(rows) = clipper.step(ui) {
+            for i in rows {
+                let e = &self.entries[i];
+                let label = if e.is_dir { format!("{}/", e.name) } else { e.name.clone() };
+                let size  = if e.is_dir { String::new() } else { format_size(e.size) };
+                let date  = e.modified.map(format_time).unwrap_or_default();
+
+                ui.table_next_row();
+                ui.table_next_column();
+                let selected = self.selection.contains(i);
+                ui.multi_selectable(&mut ms, i, &label, selected);
+                let row_id = scope_id.combine(Id::from_hash(&i));
+                if ui.ctx.is_hot(row_id) && ui.ctx.input.mouse_double_clicked(MouseButton::Left) {
+                    activated = Some(i);
+                }
+                ui.table_next_column();
+                ui.text(&size);
+                ui.table_next_column();
+                ui.text(&date);
+            }
+        }
+        let cursor   = ms.cursor();
+        let requests = ui.end_multi_select(ms);
+        ui.end_table();
+
+        if !requests.is_empty() {
+            self.selection.apply_requests(&requests, self.entries.len());
+            if !self.allow_multi() {
+                self.selection.clear();
+                if let Some(c) = cursor { self.selection.set(c, true); }
+            }
+            self.selection_changed();
+        }
+        let enter = ui.ctx.focus_item == Some(scope_id) && ui.ctx.input.key_pressed(Key::Enter);
+        let i = activated.or(cursor.filter(|_| enter))?;
+        // Double-click always enters a folder; Enter selects it in folder mode
+        if self.entries[i].is_dir && (activated.is_some() || self.mode != FileDialogMode::SelectFolder) {
+            let p = self.entries[i].path.clone();
+            self.navigate(p);
+            return None;
+        }
+        self.confirm_choice()
+    }
+
+    fn footer(&mut self, ui: &mut Ui<'_>) -> Option<Vec<PathBuf>> {
+        let mut result = None;
+
+        if let Some(mut name) = self.new_folder.take() {
+            let enter = enter_in(ui, "new_folder", |ui| { ui.input_text("Folder name##new_folder", &mut name); });
+            let create = ui.button("Create") || enter;
+            ui.same_line(-1.0);
+            let cancel = ui.button("Cancel##new_folder");
+            if create && !name.trim().is_empty() {
+                let path = self.dir.join(name.trim());
+                match fs::create_dir(&path) {
+                    Ok(()) => {
+                        self.stale = true;
+                        self.error = None;
+                    }
+                    Err(e) => self.error = Some(format!("Cannot create {}: {e}", path.display())),
+                }
+            } else if !cancel {
+                self.new_folder = Some(name);
+            }
+        } else if let Some(path) = self.confirm.clone() {
+            let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+            ui.text(&format!("\"{name}\" already exists. Replace it?"));
+            if ui.button("Replace") {
+                self.confirm = None;
+                result = Some(vec![path]);
+            }
+            ui.same_line(-1.0);
+            if ui.button("Cancel##confirm") { self.confirm = None; }
+        } else {
+            let label = if self.mode == FileDialogMode::SelectFolder { "Folder##name" } else { "File name##name" };
+            let name = &mut self.file_name;
+            if enter_in(ui, "name", |ui| { ui.input_text(label, name); }) {
+                result = self.confirm_choice();
+            }
+
+            let mut filter_names: Vec<&str> = self.filters.iter().map(|f| f.name.as_str()).collect();
+            if filter_names.is_empty() { filter_names.push("All files"); }
+            let filter_w = (ui.available_width() * 0.5).max(80.0);
+            let mut filter = self.filter;
+            if narrowed(ui, filter_w, |ui| ui.combo("##filter", &mut filter, &filter_names)) && filter != self.filter {
+                self.filter = filter;
+                self.stale  = true;
+            }
+
+            ui.same_line(-1.0);
+            let confirm = match self.mode {
+                FileDialogMode::Open         => "Open",
+                FileDialogMode::Save         => "Save",
+                FileDialogMode::SelectFolder => "Select",
+            };
+            if ui.button(confirm) { result = result.or_else(|| self.confirm_choice()); }
+            ui.same_line(-1.0);
+            if ui.button("Cancel") { self.open = false; }
+        }
+
+        if let Some(err) = &self.error {
+            ui.text_colored(Color::from_hex(0xe05050), err);
+        }
+        if self.stale { self.refresh(); }
+        result
+    }
+
+    // ── Actions ──────────────────────────────────────────────────────────────
+
+    fn allow_multi(&self) -> bool { self.multi_select && self.mode != FileDialogMode::Save }
+
+    fn navigate(&mut self, dir: PathBuf) {
+        self.set_dir(dir);
+        self.refresh();
+    }
+
+    fn set_dir(&mut self, dir: PathBuf) {
+        self.path_buf = dir.display().to_string();
+        self.dir      = dir;
+        self.stale    = true;
+        self.selection.clear();
+    }
+
+    /// Mirror a single selection into the name field.
+    fn selection_changed(&mut self) {
+        let mut it = self.selection.iter();
+        if let (Some(i), None) = (it.next(), it.next()) {
+            let e = &self.entries[i];
+            if e.is_dir == (self.mode == FileDialogMode::SelectFolder) {
+                self.file_name = e.name.clone();
+            }
+        }
+    }
+
+    /// Resolve the selection / name field into the result, or navigate,
+    /// ask for overwrite confirmation or report an error instead.
+    fn confirm_choice(&mut self) -> Option<Vec<PathBuf>> {
+        self.error = None;
+        let selected: Vec<&Entry> = self.selection.iter().map(|i| &self.entries[i]).collect();
+
+        match self.mode {
+            FileDialogMode::SelectFolder => {
+                let dirs: Vec<PathBuf> = selected.iter().filter(|e| e.is_dir).map(|e| e.path.clone()).collect();
+                if !dirs.is_empty() { return Some(dirs); }
+                let name = self.file_name.trim();
+                let path = if name.is_empty() { self.dir.clone() } else { self.dir.join(name) };
+                if path.is_dir() { return Some(vec![path]); }
+                self.error = Some(format!("No such folder: {}", path.display()));
+                None
+            }
+            FileDialogMode::Open => {
+                if let [e] = selected.as_slice() {
+                    if e.is_dir {
+                        let p = e.path.clone();
+                        self.navigate(p);
+                        return None;
+                    }
+                }
+                let files: Vec<PathBuf> = selected.iter().filter(|e| !e.is_dir).map(|e| e.path.clone()).collect();
+                if selected.len() > 1 && !files.is_empty() { return Some(files); }
+                let name = self.file_name.trim();
+                if name.is_empty() {
+                    self.error = Some("No file selected".to_owned());
+                    return None;
+                }
+                let path = self.dir.join(name);
+                if path.is_dir() {
+                    self.navigate(path);
+                    None
+                } else if path.is_file() {
+                    Some(vec![path])
+                } else {
+                    self.error = Some(format!("No such file: {}", path.display()));
+                    None
+                }
+            }
+            FileDialogMode::Save => {
+                let mut name = self.file_name.trim().to_owned();
+                if name.is_empty() {
+                    self.error = Some("Enter a file name".to_owned());
+                    return None;
+                }
+                let path = self.dir.join(&name);
+                if path.is_dir() {
+                    self.file_name.clear();
+                    self.navigate(path);
+                    return None;
+                }
+                if Path::new(&name).extension().is_none() {
+                    if let Some(ext) = self.filters.get(self.filter).and_then(|f| f.extensions.first()) {
+                        name = format!("{name}.{ext}");
+                    }
+                }
+                let path = self.dir.join(name);
+                if path.exists() {
+                    self.confirm    = Some(path);
+                    self.new_folder = None;
+                    None
+                } else {
+                    Some(vec![path])
+                }
+            }
+        }
+    }
+
+    /// Re-read `dir`, keeping the selection by path.
+    fn refresh(&mut self) {
+        self.stale = false;
+        let keep: Vec<PathBuf> = self.selection.iter().map(|i| self.entries[i].path.clone()).collect();
+        self.entries.clear();
+
+        let read = match fs::read_dir(&self.dir) {
+            Ok(r)  => r,
+            Err(e) => {
+                self.error = Some(format!("Cannot read {}: {e}", self.dir.display()));
+                self.selection.clear();
+                return;
+            }
+        };
+        let filter = self.filters.get(self.filter);
+        for item in read.flatten() {
+            let path = item.path();
+            // Follow symlinks so linked folders can be entered
+            let Ok(meta) = fs::metadata(&path).or_else(|_| item.metadata()) else { continue };
+            let name = item.file_name().to_string_lossy().into_owned();
+            if !self.show_hidden && is_hidden(&name, &meta) { continue; }
+            let is_dir = meta.is_dir();
+            if !is_dir && (self.mode == FileDialogMode::SelectFolder || filter.is_some_and(|f| !f.matches(&path))) {
+                continue;
+            }
+            self.entries.push(Entry { name, path, is_dir, size: meta.len(), modified: meta.modified().ok() });
+        }
+        self.sort_entries();
+        self.select_paths(&keep);
+    }
+
+    /// Directories first, then by the active column.
+    fn sort_entries(&mut self) {
+        let keep: Vec<PathBuf> = self.selection.iter()
+            .filter_map(|i| self.entries.get(i).map(|e| e.path.clone()))
+            .collect();
+        let (col, dir) = self.sort;
+        self.entries.sort_by(|a, b| {
+            let ord = match col {
+                1 => a.size.cmp(&b.size),
+                2 => a.modified.cmp(&b.modified),
+                _ => std::cmp::Ordering::Equal,
+            }
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+            let ord = if dir == SortDirection::Descending { ord.reverse() } else { ord };
+            b.is_dir.cmp(&a.is_dir).then(ord)
+        });
+        self.select_paths(&keep);
+    }
+
+    fn select_paths(&mut self, paths: &[PathBuf]) {
+        self.selection.clear();
+        for (i, e) in self.entries.iter().enumerate() {
+            if paths.contains(&e.path) { self.selection.set(i, true); }
+        }
+    }
+}
+
+impl Default for FileDialog {
+    fn default() -> Self { Self::new(FileDialogMode::Open) }
+}
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+/// Run `f` with the layout narrowed so that a full-width widget at the
+/// cursor is `width` wide.
+fn narrowed<R>(ui: &mut Ui<'_>, width: f32, f: impl FnOnce(&mut Ui<'_>) -> R) -> R {
+    let saved = ui.ctx.current_layout_mut().map(|l| {
+        let full = l.content_size.x;
+        l.content_size.x = (l.cursor.x - l.start_x + width).min(full);
+        full
+    });
+    let r = f(ui);
+    if let (Some(full), Some(l)) = (saved, ui.ctx.current_layout_mut()) { l.content_size.x = full; }
+    r
+}
+
+/// Submit the text field `id_src` through `f`; `true` when Enter was
+/// pressed in it.
+fn enter_in(ui: &mut Ui<'_>, id_src: &str, f: impl FnOnce(&mut Ui<'_>)) -> bool {
+    let id = ui.ctx.make_id(id_src);
+    let was_focused = ui.ctx.focus_item == Some(id);
+    f(ui);
+    was_focused && ui.ctx.input.key_pressed(Key::Enter)
+}
+
+/// Home, working directory and filesystem roots.
+fn default_bookmarks() -> Vec<(String, PathBuf)> {
+    let mut marks = Vec::new();
+    if let Some(home) = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE")) {
+        marks.push(("Home".to_owned(), PathBuf::from(home)));
+    }
+    if let Ok(cwd) = std::env::current_dir() {
+        marks.push(("Current directory".to_owned(), cwd));
+    }
+    if cfg!(windows) {
+        for letter in b'A'..=b'Z' {
+            let root = PathBuf::from(format!("{}:\\", letter as char));
+            if root.exists() { marks.push((format!("{}:", letter as char), root)); }
+        }
+    } else {
+        marks.push(("/".to_owned(), PathBuf::from("/")));
+    }
+    marks
+}
+
+/// Label for one breadcrumb: the last component, or the root itself.
+fn crumb_name(path: &Path) -> String {
+    match path.file_name() {
+        Some(n) => n.to_string_lossy().into_owned(),
+        None    => path.display().to_string(),
+    }
+}
+
+#[cfg(windows)]
+fn is_hidden(name: &str, meta: &fs::Metadata) -> bool {
+    use std::os::windows::fs::MetadataExt;
+    const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+    name.starts_with('.') || meta.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0
+}
+
+#[cfg(not(windows))]
+fn is_hidden(name: &str, _meta: &fs::Metadata) -> bool {
+    name.starts_with('.')
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 { return format!("{bytes} B"); }
+    let mut v = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while v >= 1024.0 && unit + 1 < UNITS.len() {
+        v /= 1024.0;
+        unit += 1;
+    }
+    format!("{v:.1} {}", UNITS[unit])
+}
+
+/// `YYYY-MM-DD HH:MM`, UTC.
+fn format_time(t: SystemTime) -> String {
+    let secs = match t.duration_since(UNIX_EPOCH) {
+        Ok(d)  => d.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs() as i64),
+    };
+    let date = Date::from_days_since_epoch(secs.div_euclid(86_400));
+    let time = Time::from_seconds(secs.rem_euclid(86_400) as u32);
+    format!("{date} {:02}:{:02}", time.hour(), time.minute())
+}
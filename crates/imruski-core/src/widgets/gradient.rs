@@ -0,0 +1,142 @@
+//! Gradient editor: a preview bar with draggable stop handles underneath.
+//!
+//! Click the bar to add a stop, drag a handle to move it, right-click a
+//! handle or drag it well below the bar to remove it. The selected stop's
+//! colour is edited with a colour editor under the handles.
+
+use crate::{
+    gradient::Gradient,
+    id::parse_label,
+    input::MouseButton,
+    style::StyleColor,
+    ui::Ui,
+    widgets::color_picker::{color_edit4_ex, draw_swatch, ColorEditFlags},
+    Color, Rect, Vec2,
+};
+
+/// Half-width of a stop handle, also its pick tolerance.
+const HANDLE_HALF_W: f32 = 5.0;
+/// Drag this far below the handles to remove a stop.
+const REMOVE_DIST:   f32 = 24.0;
+
+/// Storage: `int[0]` is the selected stop + 1 (0 = none), `int[1]` the
+/// stop being dragged + 1.
+pub fn gradient_edit(ui: &mut Ui<'_>, str_id: &str, gradient: &mut Gradient) -> bool {
+    let (_, id_src) = parse_label(str_id);
+    let id = ui.ctx.make_id(id_src);
+
+    let fs = ui.ctx.style.font_size;
+    let fp = ui.ctx.style.frame_padding;
+    let h  = fs + fp.1 * 2.0;
+    let w  = ui.available_width();
+    let handle_h = fs * 0.8;
+
+    let pos = match ui.layout_next(Vec2::new(w, h + handle_h + 2.0)) { Some(p) => p, None => return false };
+    let bar   = Rect::from_min_size(pos, Vec2::new(w, h));
+    let strip = Rect::from_min_size(Vec2::new(pos.x - HANDLE_HALF_W, bar.max.y), Vec2::new(w + HANDLE_HALF_W * 2.0, handle_h + 2.0));
+    let area  = Rect::new(bar.min - Vec2::new(HANDLE_HALF_W, 0.0), strip.max);
+    let x_of  = |t: f32| bar.min.x + t * w;
+
+    let (hovered, _, _) = ui.ctx.button_behavior(id, area);
+    let m = ui.ctx.input.mouse_pos;
+    let handle_at = |g: &Gradient| {
+        g.stops().iter().enumerate()
+            .map(|(i, s)| (i, (x_of(s.pos) - m.x).abs()))
+            .filter(|&(_, d)| d <= HANDLE_HALF_W + 1.0)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    };
+
+    let mut changed = false;
+    let index = |v: i32| (v > 0).then(|| v as usize - 1);
+    let (mut selected, mut dragging) = ui.ctx.get_storage(id).map_or((None, None), |s| (index(s.int[0]), index(s.int[1])));
+
+    // ── Input ────────────────────────────────────────────────────────────────
+    if hovered && ui.ctx.input.mouse_clicked(MouseButton::Left) {
+        if let Some(i) = handle_at(gradient).filter(|_| strip.contains(m)) {
+            selected = Some(i);
+            dragging = Some(i);
+        } else if bar.contains(m) {
+            let t = ((m.x - bar.min.x) / w).clamp(0.0, 1.0);
+            let i = gradient.add_stop(t, gradient.sample(t));
+            selected = Some(i);
+            dragging = Some(i);
+            changed = true;
+        }
+    }
+    if hovered && ui.ctx.input.mouse_clicked(MouseButton::Right) && strip.contains(m) {
+        if let Some(i) = handle_at(gradient) {
+            if gradient.remove_stop(i).is_some() {
+                selected = None;
+                changed = true;
+            }
+        }
+    }
+    if !ui.ctx.input.mouse_down(MouseButton::Left) { dragging = None; }
+    if let Some(i) = dragging.filter(|&i| i < gradient.len()) {
+        if m.y > strip.max.y + REMOVE_DIST && gradient.remove_stop(i).is_some() {
+            selected = None;
+            dragging = None;
+            changed = true;
+        } else {
+            let t = ((m.x - bar.min.x) / w).clamp(0.0, 1.0);
+            if t != gradient.stops()[i].pos {
+                let ni = gradient.set_stop_pos(i, t);
+                selected = ni;
+                dragging = ni;
+                changed = true;
+            }
+        }
+    }
+    let selected = selected.filter(|&i| i < gradient.len());
+    let st = ui.ctx.get_storage_mut(id);
+    st.int[0] = selected.map_or(0, |i| i as i32 + 1);
+    st.int[1] = dragging.map_or(0, |i| i as i32 + 1);
+
+    // ── Bar ──────────────────────────────────────────────────────────────────
+    draw_swatch(ui, bar, [0.0; 4], 0.0);
+    let stops = gradient.stops();
+    let first = stops.first().map_or(Color::TRANSPARENT, |s| s.color);
+    let last  = stops.last().map_or(Color::TRANSPARENT, |s| s.color);
+    let mut segments = vec![(0.0, first)];
+    segments.extend(stops.iter().map(|s| (s.pos, s.color)));
+    segments.push((1.0, last));
+    for pair in segments.windows(2) {
+        let ((t0, c0), (t1, c1)) = (pair[0], pair[1]);
+        if t1 <= t0 { continue; }
+        let (x0, x1) = (x_of(t0), x_of(t1));
+        let draw = &mut ui.ctx.draw_list;
+        draw.triangle_gradient(Vec2::new(x0, bar.min.y), Vec2::new(x1, bar.min.y), Vec2::new(x1, bar.max.y), [c0, c1, c1]);
+        draw.triangle_gradient(Vec2::new(x0, bar.min.y), Vec2::new(x1, bar.max.y), Vec2::new(x0, bar.max.y), [c0, c1, c0]);
+    }
+    ui.ctx.draw_list.rect_outline(bar, 1.0, ui.ctx.style.color(StyleColor::Border));
+
+    // ── Handles ──────────────────────────────────────────────────────────────
+    let border = ui.ctx.style.color(StyleColor::Border);
+    let active = ui.ctx.style.color(StyleColor::Text);
+    for (i, s) in gradient.stops().iter().enumerate() {
+        let x   = x_of(s.pos);
+        let top = strip.min.y;
+        let col = if selected == Some(i) { active } else { border };
+        let body = Rect::new(Vec2::new(x - HANDLE_HALF_W, top + handle_h * 0.4), Vec2::new(x + HANDLE_HALF_W, top + handle_h + 2.0));
+        ui.ctx.draw_list.triangle_filled(Vec2::new(x, top), Vec2::new(x + HANDLE_HALF_W, body.min.y), Vec2::new(x - HANDLE_HALF_W, body.min.y), col);
+        ui.ctx.draw_list.filled_rect(body, 0.0, col);
+        let inner = Rect::new(body.min + Vec2::splat(1.5), body.max - Vec2::splat(1.5));
+        draw_swatch(ui, inner, s.color.into(), 0.0);
+    }
+
+    // ── Selected stop colour ─────────────────────────────────────────────────
+    if let Some(i) = selected {
+        let mut c: [f32; 4] = gradient.stops()[i].color.into();
+        ui.push_id_str("__stop");
+        let label = format!("Stop {}##color", i + 1);
+        if color_edit4_ex(ui, &label, &mut c, ColorEditFlags::empty()) {
+            gradient.set_stop_color(i, c.into());
+            changed = true;
+        }
+        ui.pop_id();
+    }
+
+    changed
+}
+
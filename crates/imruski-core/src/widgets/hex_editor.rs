@@ -0,0 +1,353 @@
+//! Hex viewer / editor for byte buffers.
+//!
+//! ```ignore
+//! let opts = HexEditorOptions {
+//!     highlights: vec![HexHighlight { range: 0..4, color: Color::from_hex(0x3060c0) }],
+//!     ..Default::default()
+//! };
+//! ui.hex_editor_ex("packet", &mut bytes, 0x1000, &opts);
+//! ```
+//!
+//! Only the visible rows are drawn, so multi-megabyte buffers are fine.
+//! Click a byte (or its character) to focus the editor, then type hex digits
+//! – or text on the character side – to overwrite bytes in place.
+
+use std::ops::Range;
+
+use crate::{
+    id::{parse_label, Id},
+    input::{Key, MouseButton},
+    style::StyleColor,
+    ui::Ui,
+    widgets::table::scrollbar,
+    Color, Rect, Vec2,
+};
+
+/// Bytes `range` (offsets into the buffer) drawn over `color`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HexHighlight {
+    pub range: Range<usize>,
+    pub color: Color,
+}
+
+/// Layout and behaviour for `hex_editor_ex`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HexEditorOptions {
+    /// Bytes per row.
+    pub columns:      usize,
+    /// Visible rows; the rest scrolls.
+    pub rows:         usize,
+    /// Printable-character column next to the hex bytes.
+    pub show_ascii:   bool,
+    /// Values at the cursor as integers and floats.
+    pub show_preview: bool,
+    pub read_only:    bool,
+    /// Drawn in order, later ones on top.
+    pub highlights:   Vec<HexHighlight>,
+}
+
+impl Default for HexEditorOptions {
+    fn default() -> Self {
+        Self {
+            columns:      16,
+            rows:         16,
+            show_ascii:   true,
+            show_preview: true,
+            read_only:    false,
+            highlights:   Vec::new(),
+        }
+    }
+}
+
+/// Storage flag bits in `int[2]`.
+const LOW_NIBBLE: i32 = 1 << 0;
+const ASCII_SIDE: i32 = 1 << 1;
+const BIG_ENDIAN: i32 = 1 << 2;
+const WHEEL_ROWS: usize = 3;
+/// Extra gap every 8 bytes.
+const GROUP_GAP:  f32 = 6.0;
+
+/// Hex editor with default options; `true` when a byte changed.
+pub fn hex_editor(ui: &mut Ui<'_>, str_id: &str, data: &mut [u8], base_addr: u64) -> bool {
+    hex_editor_ex(ui, str_id, data, base_addr, &HexEditorOptions::default())
+}
+
+/// Address column, hex bytes, character column and a "Go to" field, with
+/// the data preview underneath. Addresses start at `base_addr`.
+///
+/// Arrows, Page Up / Down and Home / End (Ctrl for the whole buffer) move
+/// the cursor; Tab switches between the hex and character sides.
+///
+/// Storage: `int[0]` cursor offset, `int[1]` first visible row, `int[2]`
+/// nibble / side / endianness flags, `string` the go-to text.
+pub fn hex_editor_ex(
+    ui:        &mut Ui<'_>,
+    str_id:    &str,
+    data:      &mut [u8],
+    base_addr: u64,
+    opts:      &HexEditorOptions,
+) -> bool {
+    let (_, id_src) = parse_label(str_id);
+    let id = ui.ctx.make_id(id_src);
+
+    let cols       = opts.columns.max(1);
+    let rows       = opts.rows.max(1);
+    let total_rows = data.len().div_ceil(cols);
+    let last       = data.len().saturating_sub(1);
+
+    let s = ui.ctx.get_storage_mut(id);
+    let (mut cursor, mut first, mut flags) = (s.int[0].max(0) as usize, s.int[1].max(0) as usize, s.int[2]);
+    let mut goto = std::mem::take(&mut s.string);
+    cursor = cursor.min(last);
+    let mut follow = false;
+
+    // ── Go to ────────────────────────────────────────────────────────────────
+    ui.push_id_str(id_src);
+    let goto_id = ui.ctx.make_id("goto");
+    let goto_focused = ui.ctx.focus_item == Some(goto_id);
+    ui.input_text("Go to##goto", &mut goto);
+    ui.pop_id();
+    if goto_focused && ui.ctx.input.key_pressed(Key::Enter) {
+        let text = goto.trim().trim_start_matches("0x").trim_start_matches("0X");
+        if let Some(off) = u64::from_str_radix(text, 16).ok().and_then(|a| a.checked_sub(base_addr)) {
+            if (off as usize) < data.len() {
+                cursor = off as usize;
+                flags &= !LOW_NIBBLE;
+                follow = true;
+                ui.ctx.focus_item = Some(id);
+            }
+        }
+    }
+
+    // ── Geometry ─────────────────────────────────────────────────────────────
+    let fs    = ui.ctx.style.font_size;
+    let fp    = ui.ctx.style.frame_padding;
+    let sp    = ui.ctx.style.item_spacing;
+    let row_h = fs + 2.0;
+    let digit = ui.text_width("0");
+    let addr_digits = format!("{:X}", base_addr + data.len() as u64).len().max(8);
+    let addr_w  = digit * addr_digits as f32;
+    let byte_w  = ui.text_width("00") + digit;
+    let char_w  = ui.text_width("W");
+    let groups  = (cols - 1) / 8;
+    let hex_w   = byte_w * cols as f32 + GROUP_GAP * groups as f32;
+    let ascii_w = if opts.show_ascii { char_w * cols as f32 + sp.0 * 2.0 } else { 0.0 };
+    let sb_w    = ui.ctx.style.scrollbar_size;
+    let size = Vec2::new(
+        fp.0 * 2.0 + addr_w + sp.0 * 2.0 + hex_w + ascii_w + sb_w,
+        fp.1 * 2.0 + row_h * rows as f32,
+    );
+    let Some(pos) = ui.layout_next(size) else {
+        ui.ctx.get_storage_mut(id).string = goto;
+        return false;
+    };
+    let frame = Rect::from_min_size(pos, size);
+    let inner = Rect::new(pos + Vec2::new(fp.0, fp.1), Vec2::new(frame.max.x - sb_w, frame.max.y - fp.1));
+    let hex_x   = inner.min.x + addr_w + sp.0 * 2.0;
+    let ascii_x = hex_x + hex_w + sp.0 * 2.0;
+    let byte_x  = |c: usize| hex_x + byte_w * c as f32 + GROUP_GAP * (c / 8) as f32;
+
+    // ── Mouse ────────────────────────────────────────────────────────────────
+    let (hovered, _, _) = ui.ctx.button_behavior(id, inner);
+    if hovered && ui.ctx.input.mouse_clicked(MouseButton::Left) {
+        let m   = ui.ctx.input.mouse_pos;
+        let row = first + ((m.y - inner.min.y) / row_h) as usize;
+        let hit = if opts.show_ascii && m.x >= ascii_x - sp.0 {
+            Some((((m.x - ascii_x) / char_w).max(0.0) as usize, true))
+        } else if m.x >= hex_x {
+            (0..cols).rev().find(|&c| m.x >= byte_x(c)).map(|c| (c, false))
+        } else {
+            None
+        };
+        if let Some((col, ascii)) = hit {
+            let off = row * cols + col.min(cols - 1);
+            if off < data.len() {
+                cursor = off;
+                flags = (flags & BIG_ENDIAN) | if ascii { ASCII_SIDE } else { 0 };
+            }
+        }
+    }
+    if hovered && ui.ctx.input.mouse_wheel != 0.0 {
+        let step = WHEEL_ROWS as isize * -ui.ctx.input.mouse_wheel.signum() as isize;
+        first = (first as isize + step).max(0) as usize;
+    }
+
+    // ── Keyboard ─────────────────────────────────────────────────────────────
+    let mut changed = false;
+    if ui.ctx.focus_item == Some(id) && !data.is_empty() {
+        let input = &ui.ctx.input;
+        let row_start = cursor - cursor % cols;
+        let target = if input.key_pressed(Key::Left) {
+            Some(cursor.saturating_sub(1))
+        } else if input.key_pressed(Key::Right) {
+            Some((cursor + 1).min(last))
+        } else if input.key_pressed(Key::Up) {
+            Some(cursor.checked_sub(cols).unwrap_or(cursor))
+        } else if input.key_pressed(Key::Down) {
+            Some(if cursor + cols <= last { cursor + cols } else { cursor })
+        } else if input.key_pressed(Key::PageUp) {
+            Some(cursor.saturating_sub(cols * rows))
+        } else if input.key_pressed(Key::PageDown) {
+            Some((cursor + cols * rows).min(last))
+        } else if input.key_pressed(Key::Home) {
+            Some(if input.ctrl() { 0 } else { row_start })
+        } else if input.key_pressed(Key::End) {
+            Some(if input.ctrl() { last } else { (row_start + cols - 1).min(last) })
+        } else {
+            None
+        };
+        if let Some(t) = target {
+            cursor = t;
+            flags &= !LOW_NIBBLE;
+            follow = true;
+        }
+        if input.key_pressed(Key::Tab) && opts.show_ascii {
+            flags = (flags ^ ASCII_SIDE) & !LOW_NIBBLE;
+        }
+        if input.key_pressed(Key::Escape) {
+            ui.ctx.focus_item = None;
+        }
+
+        if !opts.read_only {
+            let typed = ui.ctx.input.text_input.clone();
+            for ch in typed.chars() {
+                if flags & ASCII_SIDE != 0 {
+                    if !ch.is_ascii() || ch.is_ascii_control() { continue; }
+                    data[cursor] = ch as u8;
+                } else {
+                    let Some(v) = ch.to_digit(16) else { continue };
+                    let v = v as u8;
+                    data[cursor] = if flags & LOW_NIBBLE == 0 {
+                        (data[cursor] & 0x0f) | (v << 4)
+                    } else {
+                        (data[cursor] & 0xf0) | v
+                    };
+                    flags ^= LOW_NIBBLE;
+                    if flags & LOW_NIBBLE != 0 {
+                        changed = true;
+                        continue;
+                    }
+                }
+                changed = true;
+                cursor = (cursor + 1).min(last);
+                follow = true;
+            }
+        }
+    }
+
+    let max_first = total_rows.saturating_sub(rows);
+    if follow {
+        let r = cursor / cols;
+        if r < first { first = r; }
+        if r >= first + rows { first = r + 1 - rows; }
+    }
+    first = first.min(max_first);
+
+    // ── Drawing ──────────────────────────────────────────────────────────────
+    let rounding  = ui.ctx.style.frame_rounding;
+    let bg        = ui.ctx.style.color(StyleColor::FrameBg);
+    let border    = ui.ctx.style.color(StyleColor::Border);
+    let text_col  = ui.ctx.style.color(StyleColor::Text);
+    let dim_col   = ui.ctx.style.color(StyleColor::TextDisabled);
+    let cursor_col = ui.ctx.style.color(StyleColor::SliderGrab);
+    let focused   = ui.ctx.focus_item == Some(id);
+    ui.ctx.draw_list.filled_rect(frame, rounding, bg);
+    ui.ctx.draw_list.rect_outline(frame, 1.0, border);
+    ui.ctx.draw_list.push_clip_rect(inner);
+
+    let end_row = (first + rows).min(total_rows);
+    for row in first..end_row {
+        let y = inner.min.y + (row - first) as f32 * row_h;
+        let ty = y + (row_h - fs) * 0.5;
+        let addr = base_addr + (row * cols) as u64;
+        ui.draw_text(&format!("{addr:0addr_digits$X}"), Vec2::new(inner.min.x, ty), dim_col);
+
+        for c in 0..cols {
+            let off = row * cols + c;
+            if off >= data.len() { break; }
+            let cell  = Rect::from_min_size(Vec2::new(byte_x(c) - digit * 0.5, y), Vec2::new(byte_w, row_h));
+            let acell = Rect::from_min_size(Vec2::new(ascii_x + char_w * c as f32, y), Vec2::new(char_w, row_h));
+            for h in opts.highlights.iter().filter(|h| h.range.contains(&off)) {
+                ui.ctx.draw_list.filled_rect(cell, 0.0, h.color);
+                if opts.show_ascii { ui.ctx.draw_list.filled_rect(acell, 0.0, h.color); }
+            }
+            if off == cursor {
+                let (main, other) = if flags & ASCII_SIDE != 0 { (acell, cell) } else { (cell, acell) };
+                if focused {
+                    ui.ctx.draw_list.filled_rect(main, 0.0, cursor_col.with_alpha(0.35));
+                }
+                ui.ctx.draw_list.rect_outline(main, 1.0, cursor_col);
+                if opts.show_ascii { ui.ctx.draw_list.rect_outline(other, 1.0, cursor_col.with_alpha(0.5)); }
+            }
+            let b = data[off];
+            let col = if b == 0 { dim_col } else { text_col };
+            ui.draw_text(&format!("{b:02X}"), Vec2::new(byte_x(c), ty), col);
+            if opts.show_ascii {
+                let printable = b.is_ascii_graphic() || b == b' ';
+                let (ch, col) = if printable { (b as char, text_col) } else { ('.', dim_col) };
+                ui.draw_text(&ch.to_string(), Vec2::new(acell.min.x, ty), col);
+            }
+        }
+    }
+    ui.ctx.draw_list.pop_clip_rect();
+
+    if opts.show_ascii {
+        let x = ascii_x - sp.0;
+        let sep = ui.ctx.style.color(StyleColor::Separator);
+        ui.ctx.draw_list.line(Vec2::new(x, inner.min.y), Vec2::new(x, inner.max.y), 1.0, sep);
+    }
+    if max_first > 0 {
+        let track = Rect::new(Vec2::new(inner.max.x, frame.min.y + 1.0), Vec2::new(frame.max.x - 1.0, frame.max.y - 1.0));
+        let scroll = scrollbar(ui, id.combine(Id::from_str("##vscroll")), track, true, first as f32, rows as f32, total_rows as f32);
+        first = (scroll.round().max(0.0) as usize).min(max_first);
+    }
+
+    // ── Preview ──────────────────────────────────────────────────────────────
+    if opts.show_preview {
+        let mut big = flags & BIG_ENDIAN != 0;
+        ui.push_id_str(id_src);
+        if ui.checkbox("Big endian", &mut big) {
+            flags ^= BIG_ENDIAN;
+        }
+        ui.pop_id();
+        ui.text(&format!("Offset  {:0addr_digits$X}", base_addr + cursor as u64));
+        for line in preview_lines(&data[cursor.min(data.len())..], big) {
+            ui.text(&line);
+        }
+    }
+
+    let s = ui.ctx.get_storage_mut(id);
+    s.int[0] = cursor as i32;
+    s.int[1] = first as i32;
+    s.int[2] = flags;
+    s.string = goto;
+    changed
+}
+
+/// Integer and float readings of the bytes at the cursor; `-` where the
+/// buffer ends too early.
+fn preview_lines(bytes: &[u8], big: bool) -> Vec<String> {
+    fn take<const N: usize>(b: &[u8], big: bool) -> Option<[u8; N]> {
+        let mut a: [u8; N] = b.get(..N)?.try_into().ok()?;
+        if big { a.reverse(); }
+        Some(a)
+    }
+    let show = |v: Option<String>| v.unwrap_or_else(|| "-".to_owned());
+    vec![
+        format!("u8   {:<22} i8   {}",
+            show(take::<1>(bytes, big).map(|a| u8::from_le_bytes(a).to_string())),
+            show(take::<1>(bytes, big).map(|a| i8::from_le_bytes(a).to_string()))),
+        format!("u16  {:<22} i16  {}",
+            show(take::<2>(bytes, big).map(|a| u16::from_le_bytes(a).to_string())),
+            show(take::<2>(bytes, big).map(|a| i16::from_le_bytes(a).to_string()))),
+        format!("u32  {:<22} i32  {}",
+            show(take::<4>(bytes, big).map(|a| u32::from_le_bytes(a).to_string())),
+            show(take::<4>(bytes, big).map(|a| i32::from_le_bytes(a).to_string()))),
+        format!("u64  {:<22} i64  {}",
+            show(take::<8>(bytes, big).map(|a| u64::from_le_bytes(a).to_string())),
+            show(take::<8>(bytes, big).map(|a| i64::from_le_bytes(a).to_string()))),
+        format!("f32  {:<22} f64  {}",
+            show(take::<4>(bytes, big).map(|a| f32::from_le_bytes(a).to_string())),
+            show(take::<8>(bytes, big).map(|a| f64::from_le_bytes(a).to_string()))),
+    ]
+}
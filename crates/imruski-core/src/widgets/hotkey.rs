@@ -0,0 +1,99 @@
+//! Hotkey-capture widget – click, then press the key combination to record.
+
+use crate::{
+    id::parse_label,
+    input::{Key, KeyChord},
+    style::StyleColor,
+    ui::Ui,
+    Rect, Vec2,
+};
+
+/// Returns `true` when a new chord was recorded. Escape cancels recording.
+pub fn hotkey_input(ui: &mut Ui<'_>, label: &str, chord: &mut KeyChord) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+    // Keeps the recording alive past `new_frame`
+    if ui.ctx.hotkey_capture == Some(id) { ui.ctx.hotkey_capture_seen = true; }
+
+    let fs    = ui.ctx.style.font_size;
+    let fp    = ui.ctx.style.frame_padding;
+    let sp    = ui.ctx.style.item_spacing;
+    let h     = fs + fp.1 * 2.0;
+    let tw    = ui.text_width(text);
+    let box_w = (ui.available_width() - tw - sp.0).max(80.0);
+    let total = Vec2::new(box_w + sp.0 + tw, h);
+
+    let pos = match ui.layout_next(total) { Some(p) => p, None => return false };
+    let box_rect = Rect::from_min_size(pos, Vec2::new(box_w, h));
+
+    let (hovered, _, clicked) = ui.ctx.button_behavior(id, box_rect);
+    let mut capturing = ui.ctx.hotkey_capture == Some(id);
+    if clicked && !capturing {
+        capturing = true;
+        ui.ctx.hotkey_capture      = Some(id);
+        ui.ctx.hotkey_capture_seen = true;
+        ui.ctx.focus_item          = Some(id);
+    } else if capturing
+        && ui.ctx.input.mouse_clicked(crate::input::MouseButton::Left)
+        && !box_rect.contains(ui.ctx.input.mouse_pos)
+    {
+        capturing = false;
+    }
+
+    let mut changed = false;
+    if capturing {
+        let input = &ui.ctx.input;
+        if input.key_pressed(Key::Escape) && input.modifiers.is_empty() {
+            capturing = false;
+        } else if let Some(key) = Key::ALL.iter().copied().find(|&k| !k.is_modifier() && input.key_pressed(k)) {
+            let new = KeyChord::new(key, input.modifiers);
+            changed   = new != *chord;
+            *chord    = new;
+            capturing = false;
+        }
+        if !capturing {
+            ui.ctx.hotkey_capture = None;
+            if ui.ctx.focus_item == Some(id) { ui.ctx.focus_item = None; }
+            // The recorded keystroke must not also trigger shortcuts or typing
+            ui.ctx.input.keys_pressed = [false; Key::COUNT as usize];
+            ui.ctx.input.text_input.clear();
+        }
+    }
+
+    let bg = if capturing     { ui.ctx.style.color(StyleColor::FrameBgActive) }
+             else if hovered  { ui.ctx.style.color(StyleColor::FrameBgHovered) }
+             else             { ui.ctx.style.color(StyleColor::FrameBg) };
+    let border = if capturing { ui.ctx.style.color(StyleColor::SliderGrab) }
+                 else         { ui.ctx.style.color(StyleColor::Border) };
+    let rounding = ui.ctx.style.frame_rounding;
+    {
+        let draw = &mut ui.ctx.draw_list;
+        draw.filled_rect(box_rect, rounding, bg);
+        draw.rect_outline(box_rect, if capturing { 2.0 } else { 1.0 }, border);
+    }
+
+    // While recording, preview the modifiers currently held
+    let shown = if capturing {
+        let mods = ui.ctx.input.modifiers;
+        if mods.is_empty() {
+            "Press a key...".to_owned()
+        } else {
+            let held = KeyChord::new(Key::A, mods).to_string();
+            format!("{}...", held.trim_end_matches(Key::A.name()))
+        }
+    } else {
+        chord.to_string()
+    };
+    let tc = ui.ctx.style.color(if capturing { StyleColor::TextDisabled } else { StyleColor::Text });
+    let vw = ui.text_width(&shown);
+    let vp = Vec2::new(pos.x + (box_w - vw) * 0.5, pos.y + (h - fs) * 0.5);
+    ui.draw_text(&shown, vp, tc);
+
+    if !text.is_empty() {
+        let lc = ui.ctx.style.color(StyleColor::Text);
+        let lp = Vec2::new(pos.x + box_w + sp.0, pos.y + (h - fs) * 0.5);
+        ui.draw_text(text, lp, lc);
+    }
+
+    changed
+}
@@ -0,0 +1,80 @@
+//! Image and image-button widgets.
+//!
+//! UVs select the part of the texture shown – `(Vec2::ZERO, Vec2::ONE)` for
+//! all of it, or a rect from a [`SpriteSheet`](crate::SpriteSheet).
+
+use crate::{
+    draw_list::TextureId,
+    id::parse_label,
+    input::MouseButton,
+    style::StyleColor,
+    ui::Ui,
+    Color, Rect, Vec2,
+};
+
+pub fn image(ui: &mut Ui<'_>, texture: TextureId, size: Vec2) {
+    image_ex(ui, texture, size, Vec2::ZERO, Vec2::ONE, Color::WHITE, Color::TRANSPARENT);
+}
+
+/// `size` is the image itself; a visible `border_col` adds a 1 px frame
+/// around it.
+pub fn image_ex(ui: &mut Ui<'_>, texture: TextureId, size: Vec2, uv0: Vec2, uv1: Vec2, tint: Color, border_col: Color) {
+    let border = if border_col.a > 0.0 { 1.0 } else { 0.0 };
+    let pos = match ui.layout_next(size + Vec2::splat(border * 2.0)) { Some(p) => p, None => return };
+    let img = Rect::from_min_size(pos + Vec2::splat(border), size);
+    ui.ctx.draw_list.image_quad(texture, img.min, img.max, uv0, uv1, tint);
+    if border > 0.0 {
+        ui.ctx.draw_list.rect_outline(Rect::new(pos, img.max + Vec2::splat(border)), 1.0, border_col);
+    }
+}
+
+/// Button showing the whole texture; `true` when clicked.
+pub fn image_button(ui: &mut Ui<'_>, str_id: &str, texture: TextureId, size: Vec2) -> bool {
+    image_button_ex(ui, str_id, texture, size, Vec2::ZERO, Vec2::ONE, Color::TRANSPARENT, Color::WHITE)
+}
+
+/// Image framed by `frame_padding`, drawn over `bg_col` and tinted by
+/// `tint`; the frame takes the button colours on hover and press.
+#[allow(clippy::too_many_arguments)]
+pub fn image_button_ex(
+    ui:      &mut Ui<'_>,
+    str_id:  &str,
+    texture: TextureId,
+    size:    Vec2,
+    uv0:     Vec2,
+    uv1:     Vec2,
+    bg_col:  Color,
+    tint:    Color,
+) -> bool {
+    let (_, id_src) = parse_label(str_id);
+    let id = ui.ctx.make_id(id_src);
+
+    let fp  = ui.ctx.style.frame_padding;
+    let pad = Vec2::new(fp.0, fp.1);
+    let pos = match ui.layout_next(size + pad * 2.0) { Some(p) => p, None => return false };
+    let rect = Rect::from_min_size(pos, size + pad * 2.0);
+    let img  = Rect::from_min_size(pos + pad, size);
+
+    let (hovered, held, clicked) = ui.ctx.button_behavior(id, rect);
+    let pressed = hovered && ui.ctx.input.mouse_clicked(MouseButton::Left);
+    let down    = (held || pressed) && ui.ctx.input.mouse_down(MouseButton::Left);
+
+    let frame_col = if down && hovered {
+        ui.ctx.style.color(StyleColor::ButtonActive)
+    } else if hovered {
+        ui.ctx.style.color(StyleColor::ButtonHovered)
+    } else {
+        ui.ctx.style.color(StyleColor::Button)
+    };
+    let rounding = ui.ctx.style.frame_rounding;
+
+    let draw = &mut ui.ctx.draw_list;
+    draw.filled_rect(rect, rounding, frame_col);
+    if bg_col.a > 0.0 {
+        draw.filled_rect(img, 0.0, bg_col);
+    }
+    draw.image_quad(texture, img.min, img.max, uv0, uv1, tint);
+    draw.rect_outline(rect, 1.0, Color::from_hex(0x222222).with_alpha(0.6));
+
+    clicked
+}
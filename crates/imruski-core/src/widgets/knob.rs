@@ -0,0 +1,93 @@
+//! Rotary knob for audio-style parameters.
+//!
+//! Drag vertically to turn: up increases. A full sweep of the range takes
+//! [`KNOB_DRAG_PX`] pixels; Shift is 10× faster, Alt 10× slower.
+
+use std::f32::consts::PI;
+
+use crate::{
+    id::parse_label,
+    input::MouseButton,
+    style::StyleColor,
+    ui::Ui,
+    widgets::slider::format_numeric,
+    Rect, Vec2,
+};
+
+/// Vertical drag distance covering the whole range.
+pub const KNOB_DRAG_PX: f32 = 200.0;
+
+/// Angle of the minimum (7:30) and the sweep to the maximum (4:30).
+const ANGLE_MIN:   f32 = PI * 0.75;
+const ANGLE_SWEEP: f32 = PI * 1.5;
+/// Tick marks around the dial, both ends included.
+const TICKS:       usize = 11;
+
+/// Knob over `min..=max` with the label below; the value (shown with the
+/// printf-style `format`) appears as a tooltip while hovered or turning.
+pub fn knob(ui: &mut Ui<'_>, label: &str, v: &mut f32, min: f32, max: f32, format: &str) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+
+    let fs     = ui.ctx.style.font_size;
+    let sp     = ui.ctx.style.item_spacing;
+    let radius = fs * 1.4;
+    let tick   = fs * 0.3;
+    let d      = (radius + tick) * 2.0;
+    let tw     = ui.text_width(text);
+    let w      = d.max(tw);
+    let label_h = if text.is_empty() { 0.0 } else { sp.1 + fs };
+
+    let pos = match ui.layout_next(Vec2::new(w, d + label_h)) { Some(p) => p, None => return false };
+    let dial   = Rect::from_min_size(Vec2::new(pos.x + (w - d) * 0.5, pos.y), Vec2::new(d, d));
+    let center = dial.min + Vec2::new(d * 0.5, d * 0.5);
+
+    let (hovered, held, _) = ui.ctx.button_behavior(id, dial);
+    let pressed  = hovered && ui.ctx.input.mouse_clicked(MouseButton::Left);
+    let dragging = (held || pressed) && ui.ctx.input.mouse_down(MouseButton::Left);
+    let mut changed = false;
+    if dragging {
+        let input = &ui.ctx.input;
+        let mult  = if input.shift() { 10.0 } else if input.alt() { 0.1 } else { 1.0 };
+        let dv    = -input.mouse_delta.y / KNOB_DRAG_PX * (max - min) * mult;
+        if dv != 0.0 {
+            let nv = (*v + dv).clamp(min.min(max), min.max(max));
+            if nv != *v { *v = nv; changed = true; }
+        }
+    }
+
+    // ── Drawing ──────────────────────────────────────────────────────────────
+    let t = if max != min { ((*v - min) / (max - min)).clamp(0.0, 1.0) } else { 0.0 };
+    let angle = ANGLE_MIN + ANGLE_SWEEP * t;
+
+    let track_col = ui.ctx.style.color(StyleColor::FrameBg);
+    let fill_col  = if dragging { ui.ctx.style.color(StyleColor::SliderGrabActive) }
+                    else        { ui.ctx.style.color(StyleColor::SliderGrab) };
+    let body_col  = if dragging     { ui.ctx.style.color(StyleColor::ButtonActive) }
+                    else if hovered { ui.ctx.style.color(StyleColor::ButtonHovered) }
+                    else            { ui.ctx.style.color(StyleColor::Button) };
+    let tick_col  = ui.ctx.style.color(StyleColor::TextDisabled);
+    let tc        = ui.ctx.style.color(StyleColor::Text);
+    let ring      = radius * 0.18;
+
+    let draw = &mut ui.ctx.draw_list;
+    for i in 0..TICKS {
+        let a   = ANGLE_MIN + ANGLE_SWEEP * i as f32 / (TICKS - 1) as f32;
+        let dir = Vec2::new(a.cos(), a.sin());
+        draw.line(center + dir * (radius + 2.0), center + dir * (radius + tick), 1.0, tick_col);
+    }
+    draw.arc(center, radius - ring * 0.5, ANGLE_MIN, ANGLE_MIN + ANGLE_SWEEP, ring, track_col);
+    draw.arc(center, radius - ring * 0.5, ANGLE_MIN, angle, ring, fill_col);
+    draw.filled_circle(center, radius * 0.68, body_col, 24);
+    let dir = Vec2::new(angle.cos(), angle.sin());
+    draw.line(center + dir * (radius * 0.2), center + dir * (radius * 0.62), 2.0, tc);
+
+    if !text.is_empty() {
+        ui.draw_text(text, Vec2::new(pos.x + (w - tw) * 0.5, dial.max.y + sp.1), tc);
+    }
+    if hovered || dragging {
+        ui.set_tooltip(&format_numeric(format, *v));
+    }
+
+    changed
+}
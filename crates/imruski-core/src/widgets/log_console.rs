@@ -0,0 +1,220 @@
+//! Log console – scrolling, filterable view of a [`LogBuffer`].
+//!
+//! The console keeps its own copy of the lines, so several consoles can
+//! read one buffer and "Clear" only empties this view. Lines come from
+//! [`ImruskiLogger`](crate::logger::ImruskiLogger) or
+//! `LogBuffer::push_message`.
+
+use std::collections::VecDeque;
+
+use log::Level;
+
+use crate::{
+    calendar::Time,
+    logger::{LogBuffer, LogLine},
+    style::StyleColor,
+    ui::Ui,
+    widgets::table::scrollbar,
+    Color, Rect, Vec2,
+};
+
+const LEVELS:     [Level; 5] = [Level::Error, Level::Warn, Level::Info, Level::Debug, Level::Trace];
+const WHEEL_ROWS: usize = 3;
+/// Rows the body keeps even in a short window.
+const MIN_ROWS:   f32 = 4.0;
+
+#[derive(Debug, Clone)]
+pub struct LogConsole {
+    buffer:      LogBuffer,
+    lines:       VecDeque<LogLine>,
+    max_lines:   usize,
+    /// `LogBuffer::pushed` at the last sync.
+    seen:        u64,
+    filter:      String,
+    /// `filter` lower-cased, refreshed when it is edited.
+    needle:      Vec<char>,
+    /// Shown flag per entry of `LEVELS`.
+    levels:      [bool; 5],
+    auto_scroll: bool,
+    /// Scroll position is at the newest line; cleared by scrolling up.
+    following:   bool,
+    first:       usize,
+}
+
+impl LogConsole {
+    /// Console showing everything `buffer` receives from now on, plus what
+    /// it already holds.
+    pub fn new(buffer: LogBuffer) -> Self {
+        Self {
+            buffer,
+            lines:       VecDeque::new(),
+            max_lines:   10_000,
+            seen:        0,
+            filter:      String::new(),
+            needle:      Vec::new(),
+            levels:      [true; 5],
+            auto_scroll: true,
+            following:   true,
+            first:       0,
+        }
+    }
+
+    /// Keep at most `n` lines in this view (default 10 000).
+    pub fn with_max_lines(mut self, n: usize) -> Self { self.max_lines = n.max(1); self }
+
+    pub fn buffer(&self) -> &LogBuffer { &self.buffer }
+
+    /// Forget the lines shown so far; the buffer is untouched.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.first     = 0;
+        self.following = true;
+    }
+
+    /// Show or hide one level.
+    pub fn set_level_visible(&mut self, level: Level, visible: bool) {
+        self.levels[level_index(level)] = visible;
+    }
+
+    /// Toolbar (level toggles, filter, Clear, Copy, Auto-scroll) over the
+    /// line list, which fills the rest of the window.
+    pub fn show(&mut self, ui: &mut Ui<'_>) {
+        self.sync();
+
+        // ── Toolbar ──────────────────────────────────────────────────────────
+        for (i, &level) in LEVELS.iter().enumerate() {
+            if i > 0 { ui.same_line(-1.0); }
+            let tok = ui.push_style_color(StyleColor::Text, level_color(ui, level));
+            ui.checkbox(level_name(level), &mut self.levels[i]);
+            ui.pop_style_color(tok);
+        }
+        if ui.button("Clear") { self.clear(); }
+        ui.same_line(-1.0);
+        let copy = ui.button("Copy");
+        ui.same_line(-1.0);
+        if ui.checkbox("Auto-scroll", &mut self.auto_scroll) && self.auto_scroll {
+            self.following = true;
+        }
+        if ui.input_text("Filter##filter", &mut self.filter) {
+            self.needle = self.filter.chars().map(fold).collect();
+        }
+
+        let rows: Vec<usize> = (0..self.lines.len()).filter(|&i| self.matches(&self.lines[i])).collect();
+        if copy {
+            let text: Vec<String> = rows.iter().map(|&i| format_line(&self.lines[i])).collect();
+            ui.ctx.set_clipboard_text(text.join("\n"));
+        }
+
+        // ── Lines ────────────────────────────────────────────────────────────
+        let fs    = ui.ctx.style.font_size;
+        let fp    = ui.ctx.style.frame_padding;
+        let pad   = ui.ctx.style.window_padding;
+        let row_h = fs + 2.0;
+        let bottom = ui.ctx.window_stack.last()
+            .and_then(|w| ui.ctx.windows.get(&w.id))
+            .map_or(0.0, |w| w.pos.y + w.size.y - pad.1);
+        let top    = ui.ctx.current_layout_mut().map_or(0.0, |l| l.cursor.y);
+        let height = (bottom - top).max(row_h * MIN_ROWS + fp.1 * 2.0);
+        let width  = ui.available_width();
+        let Some(pos) = ui.layout_next(Vec2::new(width, height)) else { return };
+
+        let frame   = Rect::from_min_size(pos, Vec2::new(width, height));
+        let visible = (((height - fp.1 * 2.0) / row_h).floor() as usize).max(1);
+        let max_first = rows.len().saturating_sub(visible);
+        let sb_w    = if max_first > 0 { ui.ctx.style.scrollbar_size } else { 0.0 };
+        let inner   = Rect::new(frame.min + Vec2::new(fp.0, fp.1), Vec2::new(frame.max.x - fp.0 - sb_w, frame.max.y - fp.1));
+
+        if frame.contains(ui.ctx.input.mouse_pos) && ui.ctx.input.mouse_wheel != 0.0 {
+            let step = WHEEL_ROWS as isize * -ui.ctx.input.mouse_wheel.signum() as isize;
+            self.first = (self.first.min(max_first) as isize + step).max(0) as usize;
+            self.following = self.first >= max_first;
+        }
+        if self.auto_scroll && self.following { self.first = max_first; }
+        self.first = self.first.min(max_first);
+
+        let bg     = ui.ctx.style.color(StyleColor::FrameBg);
+        let border = ui.ctx.style.color(StyleColor::Border);
+        let rounding = ui.ctx.style.frame_rounding;
+        ui.ctx.draw_list.filled_rect(frame, rounding, bg);
+        ui.ctx.draw_list.rect_outline(frame, 1.0, border);
+
+        ui.ctx.draw_list.push_clip_rect(inner);
+        for (r, &i) in rows.iter().skip(self.first).take(visible).enumerate() {
+            let line = &self.lines[i];
+            let col  = level_color(ui, line.level);
+            let y    = inner.min.y + r as f32 * row_h + (row_h - fs) * 0.5;
+            ui.draw_text(&format_line(line), Vec2::new(inner.min.x, y), col);
+        }
+        ui.ctx.draw_list.pop_clip_rect();
+
+        if max_first > 0 {
+            let id     = ui.ctx.make_id("##log_scroll");
+            let track  = Rect::new(Vec2::new(inner.max.x + fp.0, frame.min.y + 1.0), Vec2::new(frame.max.x - 1.0, frame.max.y - 1.0));
+            let scroll = scrollbar(ui, id, track, true, self.first as f32, visible as f32, rows.len() as f32);
+            if (scroll - self.first as f32).abs() >= 0.5 {
+                self.first     = (scroll.round() as usize).min(max_first);
+                self.following = self.first >= max_first;
+            }
+        }
+    }
+
+    /// Copy lines pushed to the buffer since the last frame.
+    fn sync(&mut self) {
+        let (new, pushed) = self.buffer.lines_since(self.seen);
+        self.seen = pushed;
+        self.lines.extend(new);
+        let excess = self.lines.len().saturating_sub(self.max_lines);
+        if excess > 0 {
+            self.lines.drain(..excess);
+            self.first = self.first.saturating_sub(excess);
+        }
+    }
+
+    fn matches(&self, line: &LogLine) -> bool {
+        self.levels[level_index(line.level)]
+            && (self.needle.is_empty()
+                || contains_folded(&line.message, &self.needle)
+                || contains_folded(&line.target, &self.needle))
+    }
+}
+
+/// Lower-case a single char, keeping one char per char.
+fn fold(c: char) -> char { c.to_lowercase().next().unwrap_or(c) }
+
+/// Case-insensitive substring test against an already folded `needle`.
+fn contains_folded(hay: &str, needle: &[char]) -> bool {
+    hay.char_indices().any(|(i, _)| {
+        let mut rest = hay[i..].chars();
+        needle.iter().all(|&n| rest.next().map(fold) == Some(n))
+    })
+}
+
+fn level_index(level: Level) -> usize { level as usize - 1 }
+
+fn level_name(level: Level) -> &'static str {
+    match level {
+        Level::Error => "Error",
+        Level::Warn  => "Warn",
+        Level::Info  => "Info",
+        Level::Debug => "Debug",
+        Level::Trace => "Trace",
+    }
+}
+
+fn level_color(ui: &Ui<'_>, level: Level) -> Color {
+    match level {
+        Level::Error => Color::from_hex(0xe05050),
+        Level::Warn  => Color::from_hex(0xe0b040),
+        Level::Info  => ui.ctx.style.color(StyleColor::Text),
+        Level::Debug => Color::from_hex(0x6fa8dc),
+        Level::Trace => ui.ctx.style.color(StyleColor::TextDisabled),
+    }
+}
+
+/// `HH:MM:SS LEVEL target: message` (UTC), with newlines flattened.
+fn format_line(line: &LogLine) -> String {
+    let secs = line.time.duration_since(std::time::UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let time = Time::from_seconds((secs % 86_400) as u32);
+    let message = line.message.replace('\n', " ");
+    format!("{time} {:<5} {}: {message}", line.level, line.target)
+}
@@ -1,11 +1,30 @@
 pub mod button;
+pub mod chart;
 pub mod checkbox;
+pub mod code_editor;
 pub mod color_picker;
 pub mod combo;
+pub mod command_palette;
+pub mod curve;
+pub mod datetime;
+pub mod file_dialog;
+pub mod gradient;
+pub mod hex_editor;
+pub mod hotkey;
+pub mod image;
 pub mod input_text;
+pub mod knob;
+pub mod log_console;
+pub mod node_editor;
+pub mod plot;
 pub mod progress_bar;
+pub mod realtime;
+pub mod selectable;
 pub mod separator;
 pub mod slider;
+pub mod table;
 pub mod tabs;
 pub mod text;
+pub mod timeline;
+pub mod tree;
 pub mod window;
@@ -0,0 +1,637 @@
+//! Node graph editor – `begin_node_editor` … `end_node_editor` with nodes,
+//! typed pins and Bézier links.
+//!
+//! ```ignore
+//! ui.begin_node_editor("shader", Vec2::ZERO);
+//! for n in &graph.nodes {
+//!     ui.begin_node(n.id, &n.title);
+//!     for p in &n.inputs  { ui.node_input(p.id, &p.name, p.kind); }
+//!     for p in &n.outputs { ui.node_output(p.id, &p.name, p.kind); }
+//!     ui.end_node();
+//! }
+//! for l in &graph.links { ui.node_link(l.id, l.from, l.to); }
+//! let r = ui.end_node_editor();
+//! if let Some((from, to)) = r.link_created { graph.connect(from, to); }
+//! for id in r.links_deleted { graph.disconnect(id); }
+//! ```
+//!
+//! The app owns the graph; node, pin and link ids are its own `u64`s (pin
+//! ids unique within the editor). Nodes are laid out and drawn in
+//! `end_node_editor`, which also reports the user's edits.
+//!
+//! Left-drag moves nodes, draws links from pins or box-selects on empty
+//! canvas (Ctrl adds to the selection). Middle- or right-drag pans, the
+//! wheel zooms. Dragging a link off an input detaches it; Delete removes
+//! the selected links. Node positions, pan and zoom are kept on the
+//! [`Context`](crate::Context) and written by `save_settings`.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    id::{parse_label, Id},
+    input::{Key, MouseButton},
+    style::StyleColor,
+    ui::Ui,
+    Color, Rect, Vec2,
+};
+
+bitflags::bitflags! {
+    /// Options for `begin_node_editor_ex`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct NodeEditorFlags: u32 {
+        /// Overview of the whole graph in the bottom-right corner; click or
+        /// drag it to move the view.
+        const MINIMAP       = 1 << 0;
+        const NO_ZOOM       = 1 << 1;
+        const NO_BOX_SELECT = 1 << 2;
+    }
+}
+
+/// A pin as seen by link validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodePin {
+    pub id:     u64,
+    pub node:   u64,
+    /// App-defined type, e.g. float / vec3 / texture.
+    pub kind:   u32,
+    pub output: bool,
+}
+
+/// What the user did in the editor this frame.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NodeEditorResponse {
+    /// `(output pin, input pin)` of a link the user just drew.
+    pub link_created:   Option<(u64, u64)>,
+    /// Links deleted with the Delete key or detached from their input.
+    pub links_deleted:  Vec<u64>,
+    pub selected_nodes: Vec<u64>,
+    pub hovered_node:   Option<u64>,
+    pub hovered_link:   Option<u64>,
+}
+
+pub(crate) const ZOOM_RANGE: (f32, f32) = (0.25, 3.0);
+const GRID_STEP:     f32 = 32.0;
+const NODE_PAD:      f32 = 8.0;
+const MIN_NODE_W:    f32 = 100.0;
+const PIN_RADIUS:    f32 = 4.5;
+/// Extra grab distance around pins and links, in screen pixels.
+const HIT_SLOP:      f32 = 4.0;
+const LINK_SEGMENTS: usize = 24;
+const MINIMAP_FRAC:  f32 = 0.2;
+/// Offset between nodes placed without a stored position.
+const CASCADE:       f32 = 30.0;
+
+const PIN_COLORS: [u32; 8] = [0x9ad04f, 0x4fa3d0, 0xd0a44f, 0xc44fd0, 0xd05a4f, 0x4fd0b5, 0xd0d04f, 0x8a8ad0];
+
+// ─── Persistent state ────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Default)]
+enum Drag {
+    #[default]
+    None,
+    Nodes,
+    /// From this pin to the mouse.
+    Link(u64),
+    /// Box select from this canvas point.
+    Box(Vec2),
+    Pan,
+    Minimap,
+}
+
+/// Per-editor state kept across frames (positions and view also in settings).
+#[derive(Debug, Clone)]
+pub(crate) struct NodeEditorState {
+    /// Canvas position of each node's top-left corner.
+    pub positions: BTreeMap<u64, Vec2>,
+    /// Screen offset of the canvas origin from the editor's corner.
+    pub pan:       Vec2,
+    pub zoom:      f32,
+    /// Draw order, back to front; clicked nodes move to the end.
+    order:         Vec<u64>,
+    selected:      Vec<u64>,
+    links_sel:     Vec<u64>,
+    drag:          Drag,
+}
+
+impl Default for NodeEditorState {
+    fn default() -> Self {
+        Self {
+            positions: BTreeMap::new(),
+            pan:       Vec2::ZERO,
+            zoom:      1.0,
+            order:     Vec::new(),
+            selected:  Vec::new(),
+            links_sel: Vec::new(),
+            drag:      Drag::None,
+        }
+    }
+}
+
+// ─── Per-frame state ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone)]
+struct PinDecl {
+    id:    u64,
+    label: String,
+    kind:  u32,
+}
+
+#[derive(Debug, Clone)]
+struct NodeDecl {
+    id:      u64,
+    title:   String,
+    inputs:  Vec<PinDecl>,
+    outputs: Vec<PinDecl>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LinkDecl {
+    id:   u64,
+    from: u64,
+    to:   u64,
+}
+
+/// The editor being submitted.
+#[derive(Debug, Clone)]
+pub(crate) struct NodeEditorFrame {
+    id:    Id,
+    flags: NodeEditorFlags,
+    rect:  Rect,
+    nodes: Vec<NodeDecl>,
+    links: Vec<LinkDecl>,
+    /// Inside `begin_node` … `end_node`.
+    open:  bool,
+}
+
+/// A node laid out on screen.
+struct NodeGeom {
+    id:      u64,
+    rect:    Rect,
+    title_h: f32,
+}
+
+/// A pin laid out on screen.
+#[derive(Clone, Copy)]
+struct PinGeom {
+    pin: NodePin,
+    pos: Vec2,
+}
+
+// ─── Submission ──────────────────────────────────────────────────────────────
+
+/// Node editor with a minimap. `size` components `<= 0` fill the available
+/// width / the rest of the window.
+pub fn begin_node_editor(ui: &mut Ui<'_>, str_id: &str, size: Vec2) {
+    begin_node_editor_ex(ui, str_id, size, NodeEditorFlags::MINIMAP);
+}
+
+pub fn begin_node_editor_ex(ui: &mut Ui<'_>, str_id: &str, size: Vec2, flags: NodeEditorFlags) {
+    let (_, id_src) = parse_label(str_id);
+    let id = ui.ctx.make_id(id_src);
+
+    let pad    = ui.ctx.style.window_padding;
+    let bottom = ui.ctx.window_stack.last()
+        .and_then(|w| ui.ctx.windows.get(&w.id))
+        .map_or(0.0, |w| w.pos.y + w.size.y - pad.1);
+    let top = ui.ctx.current_layout_mut().map_or(0.0, |l| l.cursor.y);
+    let w = if size.x > 0.0 { size.x } else { ui.available_width() };
+    let h = if size.y > 0.0 { size.y } else { (bottom - top).max(ui.ctx.style.font_size * 10.0) };
+    // Outside a window: node calls and `end_node_editor` do nothing
+    ui.ctx.node_editor = None;
+    let Some(pos) = ui.layout_next(Vec2::new(w, h)) else { return };
+
+    ui.ctx.node_editors.entry(id).or_default();
+    ui.ctx.node_editor = Some(NodeEditorFrame {
+        id, flags,
+        rect:  Rect::from_min_size(pos, Vec2::new(w, h)),
+        nodes: Vec::new(),
+        links: Vec::new(),
+        open:  false,
+    });
+}
+
+/// Start node `id`; add pins with `node_input` / `node_output`, then call
+/// `end_node`.
+pub fn begin_node(ui: &mut Ui<'_>, id: u64, title: &str) {
+    let Some(f) = ui.ctx.node_editor.as_mut() else { return };
+    f.nodes.push(NodeDecl { id, title: title.to_owned(), inputs: Vec::new(), outputs: Vec::new() });
+    f.open = true;
+}
+
+/// Input pin `id` of the current node, of app-defined type `kind`.
+pub fn node_input(ui: &mut Ui<'_>, id: u64, label: &str, kind: u32) {
+    if let Some(n) = current_node(ui) { n.inputs.push(PinDecl { id, label: label.to_owned(), kind }); }
+}
+
+/// Output pin `id` of the current node.
+pub fn node_output(ui: &mut Ui<'_>, id: u64, label: &str, kind: u32) {
+    if let Some(n) = current_node(ui) { n.outputs.push(PinDecl { id, label: label.to_owned(), kind }); }
+}
+
+pub fn end_node(ui: &mut Ui<'_>) {
+    if let Some(f) = ui.ctx.node_editor.as_mut() { f.open = false; }
+}
+
+/// Link `id` from output pin `from` to input pin `to`.
+pub fn node_link(ui: &mut Ui<'_>, id: u64, from: u64, to: u64) {
+    if let Some(f) = ui.ctx.node_editor.as_mut() { f.links.push(LinkDecl { id, from, to }); }
+}
+
+/// Move node `id` to canvas position `pos` (its top-left corner).
+pub fn set_node_position(ui: &mut Ui<'_>, id: u64, pos: Vec2) {
+    let Some(f) = ui.ctx.node_editor.as_ref() else { return };
+    let eid = f.id;
+    ui.ctx.node_editors.entry(eid).or_default().positions.insert(id, pos);
+}
+
+/// Canvas position of node `id` in the current editor, once it has one.
+pub fn node_position(ui: &Ui<'_>, id: u64) -> Option<Vec2> {
+    let f = ui.ctx.node_editor.as_ref()?;
+    ui.ctx.node_editors.get(&f.id)?.positions.get(&id).copied()
+}
+
+fn current_node<'a>(ui: &'a mut Ui<'_>) -> Option<&'a mut NodeDecl> {
+    let f = ui.ctx.node_editor.as_mut()?;
+    if !f.open { return None; }
+    f.nodes.last_mut()
+}
+
+/// Finish the editor; links are valid between pins of the same `kind`.
+pub fn end_node_editor(ui: &mut Ui<'_>) -> NodeEditorResponse {
+    end_node_editor_with(ui, |a, b| a.kind == b.kind)
+}
+
+/// Finish the editor; `validate(output, input)` decides whether a link may
+/// be drawn between two pins of different nodes.
+pub fn end_node_editor_with(ui: &mut Ui<'_>, validate: impl Fn(&NodePin, &NodePin) -> bool) -> NodeEditorResponse {
+    let mut resp = NodeEditorResponse::default();
+    let Some(f) = ui.ctx.node_editor.take() else { return resp };
+    let mut st = ui.ctx.node_editors.remove(&f.id).unwrap_or_default();
+
+    let rect = f.rect;
+    let fs   = ui.ctx.style.font_size;
+    let input_mouse = ui.ctx.input.mouse_pos;
+
+    // ── View: pan / zoom ─────────────────────────────────────────────────────
+    let (hovered, _, _) = ui.ctx.button_behavior(f.id, rect);
+    if hovered && ui.ctx.input.mouse_wheel != 0.0 && !f.flags.contains(NodeEditorFlags::NO_ZOOM) {
+        let z   = (st.zoom * 1.1f32.powf(ui.ctx.input.mouse_wheel)).clamp(ZOOM_RANGE.0, ZOOM_RANGE.1);
+        let c   = (input_mouse - rect.min - st.pan) / st.zoom;
+        st.pan  = input_mouse - rect.min - c * z;
+        st.zoom = z;
+    }
+    let pan_pressed = hovered
+        && (ui.ctx.input.mouse_clicked(MouseButton::Middle) || ui.ctx.input.mouse_clicked(MouseButton::Right));
+    if pan_pressed { st.drag = Drag::Pan; }
+    if matches!(st.drag, Drag::Pan) {
+        if ui.ctx.input.mouse_down(MouseButton::Middle) || ui.ctx.input.mouse_down(MouseButton::Right) {
+            if !pan_pressed { st.pan += ui.ctx.input.mouse_delta; }
+        } else {
+            st.drag = Drag::None;
+        }
+    }
+
+    let z      = st.zoom;
+    let origin = rect.min + st.pan;
+    let to_screen = |p: Vec2| origin + p * z;
+    let to_canvas = |s: Vec2| (s - origin) / z;
+
+    // ── Layout ───────────────────────────────────────────────────────────────
+    for n in &f.nodes {
+        if !st.positions.contains_key(&n.id) {
+            let k = st.positions.len() as f32;
+            st.positions.insert(n.id, to_canvas(rect.min) + Vec2::splat(NODE_PAD * 2.0 + CASCADE * k));
+        }
+        if !st.order.contains(&n.id) { st.order.push(n.id); }
+    }
+    st.order.retain(|id| f.nodes.iter().any(|n| n.id == *id));
+
+    let row_h   = fs + 6.0;
+    let title_h = fs + 8.0;
+    let mut nodes: Vec<NodeGeom> = Vec::with_capacity(f.nodes.len());
+    let mut pins:  Vec<PinGeom>  = Vec::new();
+    for n in &f.nodes {
+        let widest = |ps: &[PinDecl]| ps.iter().map(|p| ui.text_width(&p.label)).fold(0.0, f32::max);
+        let w = (ui.text_width(&n.title) + NODE_PAD * 2.0)
+            .max(widest(&n.inputs) + widest(&n.outputs) + NODE_PAD * 4.0)
+            .max(MIN_NODE_W);
+        let rows = n.inputs.len().max(n.outputs.len()) as f32;
+        let h = title_h + rows * row_h + NODE_PAD * 0.5;
+        let p = st.positions[&n.id];
+        for (i, pin) in n.inputs.iter().enumerate() {
+            let at = p + Vec2::new(0.0, title_h + (i as f32 + 0.5) * row_h);
+            pins.push(PinGeom { pin: NodePin { id: pin.id, node: n.id, kind: pin.kind, output: false }, pos: to_screen(at) });
+        }
+        for (i, pin) in n.outputs.iter().enumerate() {
+            let at = p + Vec2::new(w, title_h + (i as f32 + 0.5) * row_h);
+            pins.push(PinGeom { pin: NodePin { id: pin.id, node: n.id, kind: pin.kind, output: true }, pos: to_screen(at) });
+        }
+        nodes.push(NodeGeom { id: n.id, rect: Rect::new(to_screen(p), to_screen(p + Vec2::new(w, h))), title_h: title_h * z });
+    }
+    let pin_at  = |id: u64| pins.iter().find(|p| p.pin.id == id).copied();
+    let links: Vec<(LinkDecl, PinGeom, PinGeom)> = f.links.iter()
+        .filter_map(|l| Some((*l, pin_at(l.from)?, pin_at(l.to)?)))
+        .collect();
+
+    // ── Hit testing ──────────────────────────────────────────────────────────
+    let minimap = f.flags.contains(NodeEditorFlags::MINIMAP).then(|| minimap_layout(rect, &nodes, to_canvas));
+    let in_minimap = minimap.as_ref().is_some_and(|m| m.rect.contains(input_mouse));
+    let hit_r = PIN_RADIUS * z + HIT_SLOP;
+    let hovered_pin = if hovered && !in_minimap {
+        pins.iter().filter(|p| (p.pos - input_mouse).length() <= hit_r).min_by(|a, b| {
+            (a.pos - input_mouse).length().total_cmp(&(b.pos - input_mouse).length())
+        }).copied()
+    } else {
+        None
+    };
+    let hovered_node = if hovered && !in_minimap && hovered_pin.is_none() {
+        st.order.iter().rev().find(|id| nodes.iter().any(|n| n.id == **id && n.rect.contains(input_mouse))).copied()
+    } else {
+        None
+    };
+    let hovered_link = if hovered && !in_minimap && hovered_pin.is_none() && hovered_node.is_none() {
+        links.iter().rev().find(|(_, a, b)| near_bezier(a.pos, b.pos, z, input_mouse, HIT_SLOP + 1.0)).map(|(l, _, _)| l.id)
+    } else {
+        None
+    };
+    if matches!(st.drag, Drag::None) {
+        resp.hovered_node = hovered_node;
+        resp.hovered_link = hovered_link;
+    }
+
+    // ── Press ────────────────────────────────────────────────────────────────
+    let ctrl    = ui.ctx.input.ctrl();
+    let pressed = hovered && ui.ctx.input.mouse_clicked(MouseButton::Left);
+    if pressed {
+        if in_minimap {
+            st.drag = Drag::Minimap;
+        } else if let Some(p) = hovered_pin {
+            // Grabbing a connected input detaches its link
+            let attached = (!p.pin.output).then(|| links.iter().find(|(l, _, _)| l.to == p.pin.id)).flatten();
+            match attached {
+                Some((l, from, _)) => {
+                    resp.links_deleted.push(l.id);
+                    st.drag = Drag::Link(from.pin.id);
+                }
+                None => st.drag = Drag::Link(p.pin.id),
+            }
+        } else if let Some(n) = hovered_node {
+            if ctrl {
+                toggle(&mut st.selected, n);
+            } else if !st.selected.contains(&n) {
+                st.selected = vec![n];
+            }
+            st.links_sel.clear();
+            st.order.retain(|&o| o != n);
+            st.order.push(n);
+            st.drag = Drag::Nodes;
+        } else if let Some(l) = hovered_link {
+            if ctrl { toggle(&mut st.links_sel, l); } else { st.links_sel = vec![l]; }
+            st.selected.clear();
+        } else {
+            if !ctrl {
+                st.selected.clear();
+                st.links_sel.clear();
+            }
+            if !f.flags.contains(NodeEditorFlags::NO_BOX_SELECT) {
+                st.drag = Drag::Box(to_canvas(input_mouse));
+            }
+        }
+    }
+
+    // ── Drag / release ───────────────────────────────────────────────────────
+    let left_down = ui.ctx.input.mouse_down(MouseButton::Left);
+    let mut preview_link = None;
+    let mut select_box = None;
+    match st.drag.clone() {
+        Drag::Nodes if left_down => {
+            // The press frame's delta is the jump to the press point
+            let d = if pressed { Vec2::ZERO } else { ui.ctx.input.mouse_delta / z };
+            for id in &st.selected {
+                if let Some(p) = st.positions.get_mut(id) { *p += d; }
+            }
+        }
+        Drag::Minimap if left_down => {
+            if let Some(m) = &minimap {
+                let c = m.bounds.min + (input_mouse - m.rect.min) / m.scale;
+                st.pan = rect.size() * 0.5 - c * z;
+            }
+        }
+        Drag::Link(from) => match pin_at(from) {
+            // The pin left the graph mid-drag
+            None    => st.drag = Drag::None,
+            Some(a) => {
+                let target = hovered_pin.filter(|b| b.pin.output != a.pin.output && b.pin.node != a.pin.node);
+                let (out, inp) = match target {
+                    Some(b) if a.pin.output => (a.pin, b.pin),
+                    Some(b)                 => (b.pin, a.pin),
+                    None                    => (a.pin, a.pin),
+                };
+                let exists = links.iter().any(|(l, _, _)| l.from == out.id && l.to == inp.id);
+                let valid  = target.is_some() && !exists && validate(&out, &inp);
+                if left_down {
+                    let end = match target { Some(b) if valid => b.pos, _ => input_mouse };
+                    preview_link = Some((a, end, target.is_some() && !valid));
+                } else {
+                    if valid { resp.link_created = Some((out.id, inp.id)); }
+                    st.drag = Drag::None;
+                }
+            }
+        },
+        Drag::Box(start) => {
+            let r = Rect::new(to_screen(start).min(input_mouse), to_screen(start).max(input_mouse));
+            if left_down {
+                select_box = Some(r);
+            } else {
+                if !ctrl { st.selected.clear(); }
+                for n in nodes.iter().filter(|n| n.rect.overlaps(r)) {
+                    if !st.selected.contains(&n.id) { st.selected.push(n.id); }
+                }
+                st.drag = Drag::None;
+            }
+        }
+        Drag::Pan => {}
+        _ => st.drag = Drag::None,
+    }
+
+    // ── Keyboard ─────────────────────────────────────────────────────────────
+    let focused = ui.ctx.focus_item == Some(f.id);
+    if focused && (ui.ctx.input.key_pressed(Key::Delete) || ui.ctx.input.key_pressed(Key::Backspace)) {
+        resp.links_deleted.extend(st.links_sel.drain(..).filter(|id| links.iter().any(|(l, _, _)| l.id == *id)));
+    }
+    st.selected.retain(|id| nodes.iter().any(|n| n.id == *id));
+    resp.selected_nodes = st.selected.clone();
+
+    // ── Drawing ──────────────────────────────────────────────────────────────
+    let style = &ui.ctx.style;
+    let bg        = style.color(StyleColor::PlotBg);
+    let grid_col  = style.color(StyleColor::PlotGrid);
+    let border    = style.color(StyleColor::Border);
+    let node_bg   = style.color(StyleColor::FrameBg);
+    let title_bg  = style.color(StyleColor::TitleBar);
+    let title_sel = style.color(StyleColor::TitleBarActive);
+    let text_col  = style.color(StyleColor::Text);
+    let sel_col   = style.color(StyleColor::SliderGrabActive);
+    let hov_col   = style.color(StyleColor::HeaderHovered);
+    let box_col   = style.color(StyleColor::PlotSelection);
+    let rounding  = style.frame_rounding;
+
+    let draw = &mut ui.ctx.draw_list;
+    draw.filled_rect(rect, rounding, bg);
+    draw.push_clip_rect(rect);
+    let step = GRID_STEP * z;
+    let mut x = rect.min.x + st.pan.x.rem_euclid(step);
+    while x < rect.max.x {
+        draw.line(Vec2::new(x, rect.min.y), Vec2::new(x, rect.max.y), 1.0, grid_col);
+        x += step;
+    }
+    let mut y = rect.min.y + st.pan.y.rem_euclid(step);
+    while y < rect.max.y {
+        draw.line(Vec2::new(rect.min.x, y), Vec2::new(rect.max.x, y), 1.0, grid_col);
+        y += step;
+    }
+
+    for (l, a, b) in &links {
+        let (col, thick) = if st.links_sel.contains(&l.id) {
+            (sel_col, 3.0)
+        } else if resp.hovered_link == Some(l.id) {
+            (hov_col, 2.5)
+        } else {
+            (pin_color(a.pin.kind), 2.0)
+        };
+        draw_bezier(draw, a.pos, b.pos, z, thick * z.sqrt(), col);
+    }
+
+    let connected = |id: u64| links.iter().any(|(l, _, _)| l.from == id || l.to == id);
+    let pin_r = PIN_RADIUS * z;
+    for id in &st.order {
+        let (Some(g), Some(n)) = (nodes.iter().find(|g| g.id == *id), f.nodes.iter().find(|n| n.id == *id)) else { continue };
+        let selected = st.selected.contains(id);
+        let draw = &mut ui.ctx.draw_list;
+        draw.filled_rect(g.rect, rounding * z, node_bg);
+        let title = Rect::new(g.rect.min, Vec2::new(g.rect.max.x, g.rect.min.y + g.title_h));
+        draw.filled_rect(title, rounding * z, if selected { title_sel } else { title_bg });
+        let outline = if selected { sel_col } else if resp.hovered_node == Some(*id) { hov_col } else { border };
+        draw.rect_outline(g.rect, if selected { 2.0 } else { 1.0 }, outline);
+
+        let pad = NODE_PAD * z;
+        draw_scaled_text(ui, &n.title, Vec2::new(g.rect.min.x + pad, g.rect.min.y + (g.title_h - fs * z) * 0.5), z, text_col);
+        for p in pins.iter().filter(|p| p.pin.node == *id) {
+            let col = pin_color(p.pin.kind);
+            if connected(p.pin.id) {
+                ui.ctx.draw_list.filled_circle(p.pos, pin_r, col, 12);
+            } else {
+                ui.ctx.draw_list.filled_circle(p.pos, pin_r, node_bg, 12);
+                ui.ctx.draw_list.arc(p.pos, pin_r, 0.0, std::f32::consts::TAU, 1.5, col);
+            }
+            if hovered_pin.is_some_and(|h| h.pin.id == p.pin.id) {
+                ui.ctx.draw_list.arc(p.pos, pin_r + 2.0, 0.0, std::f32::consts::TAU, 1.0, hov_col);
+            }
+            let decl = if p.pin.output { &n.outputs } else { &n.inputs };
+            let Some(label) = decl.iter().find(|d| d.id == p.pin.id).map(|d| d.label.as_str()) else { continue };
+            let ty = p.pos.y - fs * z * 0.5;
+            let tx = if p.pin.output { p.pos.x - pad - ui.text_width(label) * z } else { p.pos.x + pad };
+            draw_scaled_text(ui, label, Vec2::new(tx, ty), z, text_col);
+        }
+    }
+
+    let draw = &mut ui.ctx.draw_list;
+    if let Some((a, end, invalid)) = preview_link {
+        let col = if invalid { Color::from_hex(0xe05050) } else { pin_color(a.pin.kind) };
+        let (p0, p3) = if a.pin.output { (a.pos, end) } else { (end, a.pos) };
+        draw_bezier(draw, p0, p3, z, 2.0 * z.sqrt(), col);
+    }
+    if let Some(r) = select_box {
+        draw.filled_rect(r, 0.0, box_col);
+        draw.rect_outline(r, 1.0, box_col.with_alpha(1.0));
+    }
+    if let Some(m) = &minimap {
+        draw.filled_rect(m.rect, 0.0, bg.with_alpha(0.9));
+        draw.rect_outline(m.rect, 1.0, border);
+        let map = |r: Rect| Rect::new(
+            m.rect.min + (to_canvas(r.min) - m.bounds.min) * m.scale,
+            m.rect.min + (to_canvas(r.max) - m.bounds.min) * m.scale,
+        );
+        for g in &nodes {
+            let col = if st.selected.contains(&g.id) { sel_col } else { title_bg };
+            draw.filled_rect(map(g.rect), 0.0, col);
+        }
+        draw.rect_outline(map(rect), 1.0, text_col);
+    }
+    draw.pop_clip_rect();
+    draw.rect_outline(rect, 1.0, border);
+
+    ui.ctx.node_editors.insert(f.id, st);
+    resp
+}
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+fn toggle(list: &mut Vec<u64>, id: u64) {
+    match list.iter().position(|&x| x == id) {
+        Some(i) => { list.remove(i); }
+        None    => list.push(id),
+    }
+}
+
+fn pin_color(kind: u32) -> Color { Color::from_hex(PIN_COLORS[kind as usize % PIN_COLORS.len()]) }
+
+/// Text at `zoom` times the font size.
+fn draw_scaled_text(ui: &mut Ui<'_>, text: &str, pos: Vec2, zoom: f32, col: Color) {
+    let fs = ui.ctx.style.font_size;
+    ui.ctx.style.font_size = fs * zoom;
+    ui.draw_text(text, pos, col);
+    ui.ctx.style.font_size = fs;
+}
+
+/// Horizontal-tangent cubic from output `p0` to input `p3`.
+fn bezier_points(p0: Vec2, p3: Vec2, zoom: f32) -> [Vec2; LINK_SEGMENTS + 1] {
+    let d  = ((p3.x - p0.x).abs() * 0.5).max(40.0 * zoom);
+    let p1 = p0 + Vec2::new(d, 0.0);
+    let p2 = p3 - Vec2::new(d, 0.0);
+    let mut pts = [Vec2::ZERO; LINK_SEGMENTS + 1];
+    for (i, pt) in pts.iter_mut().enumerate() {
+        let t = i as f32 / LINK_SEGMENTS as f32;
+        let u = 1.0 - t;
+        *pt = p0 * (u * u * u) + p1 * (3.0 * u * u * t) + p2 * (3.0 * u * t * t) + p3 * (t * t * t);
+    }
+    pts
+}
+
+fn draw_bezier(draw: &mut crate::draw_list::DrawList, p0: Vec2, p3: Vec2, zoom: f32, thickness: f32, col: Color) {
+    let pts = bezier_points(p0, p3, zoom);
+    for w in pts.windows(2) {
+        draw.line(w[0], w[1], thickness, col);
+    }
+}
+
+fn near_bezier(p0: Vec2, p3: Vec2, zoom: f32, p: Vec2, dist: f32) -> bool {
+    let pts = bezier_points(p0, p3, zoom);
+    pts.windows(2).any(|w| {
+        let (a, b) = (w[0], w[1]);
+        let ab = b - a;
+        let t  = ((p - a).dot(ab) / ab.dot(ab).max(1e-6)).clamp(0.0, 1.0);
+        (a + ab * t - p).length() <= dist
+    })
+}
+
+struct Minimap {
+    rect:   Rect,
+    /// Canvas area shown.
+    bounds: Rect,
+    /// Minimap pixels per canvas unit.
+    scale:  f32,
+}
+
+/// Bottom-right overview covering every node and the current view.
+fn minimap_layout(rect: Rect, nodes: &[NodeGeom], to_canvas: impl Fn(Vec2) -> Vec2) -> Minimap {
+    let size = Vec2::new(rect.width() * MINIMAP_FRAC, rect.height() * MINIMAP_FRAC).max(Vec2::new(80.0, 60.0));
+    let mm   = Rect::from_min_size(rect.max - size - Vec2::splat(NODE_PAD), size);
+    let view = Rect::new(to_canvas(rect.min), to_canvas(rect.max));
+    let bounds = nodes.iter()
+        .map(|n| Rect::new(to_canvas(n.rect.min), to_canvas(n.rect.max)))
+        .fold(view, Rect::union)
+        .expand(GRID_STEP);
+    let scale = (size.x / bounds.width()).min(size.y / bounds.height());
+    Minimap { rect: mm, bounds, scale }
+}
@@ -0,0 +1,197 @@
+//! Simple data plots – `plot_lines` and `plot_histogram`.
+//!
+//! Samples come from a slice (read from `offset`, wrapping, as a ring
+//! buffer) or from a getter closure for data that is not contiguous.
+//! Pass [`AUTO_SCALE`] for either bound to fit it to the data.
+
+use crate::{
+    id::parse_label,
+    style::StyleColor,
+    ui::Ui,
+    Rect, Vec2,
+};
+
+/// Scale bound that is computed from the samples.
+pub const AUTO_SCALE: f32 = f32::MAX;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlotKind { Lines, Histogram }
+
+/// Everything but the data source.
+struct PlotArgs<'a> {
+    label:     &'a str,
+    overlay:   Option<&'a str>,
+    scale_min: f32,
+    scale_max: f32,
+    size:      Vec2,
+}
+
+/// Line graph of `values`, starting at `offset` and wrapping around.
+#[allow(clippy::too_many_arguments)]
+pub fn plot_lines(
+    ui:        &mut Ui<'_>,
+    label:     &str,
+    values:    &[f32],
+    offset:    usize,
+    overlay:   Option<&str>,
+    scale_min: f32,
+    scale_max: f32,
+    size:      Vec2,
+) {
+    let n = values.len();
+    let get = |i: usize| values[(i + offset) % n];
+    plot(ui, PlotKind::Lines, n, &get, PlotArgs { label, overlay, scale_min, scale_max, size });
+}
+
+/// `plot_lines` reading sample `i` of `count` from `getter`.
+#[allow(clippy::too_many_arguments)]
+pub fn plot_lines_fn(
+    ui:        &mut Ui<'_>,
+    label:     &str,
+    count:     usize,
+    getter:    impl Fn(usize) -> f32,
+    overlay:   Option<&str>,
+    scale_min: f32,
+    scale_max: f32,
+    size:      Vec2,
+) {
+    plot(ui, PlotKind::Lines, count, &getter, PlotArgs { label, overlay, scale_min, scale_max, size });
+}
+
+/// Bar chart of `values`, starting at `offset` and wrapping around.
+#[allow(clippy::too_many_arguments)]
+pub fn plot_histogram(
+    ui:        &mut Ui<'_>,
+    label:     &str,
+    values:    &[f32],
+    offset:    usize,
+    overlay:   Option<&str>,
+    scale_min: f32,
+    scale_max: f32,
+    size:      Vec2,
+) {
+    let n = values.len();
+    let get = |i: usize| values[(i + offset) % n];
+    plot(ui, PlotKind::Histogram, n, &get, PlotArgs { label, overlay, scale_min, scale_max, size });
+}
+
+/// `plot_histogram` reading sample `i` of `count` from `getter`.
+#[allow(clippy::too_many_arguments)]
+pub fn plot_histogram_fn(
+    ui:        &mut Ui<'_>,
+    label:     &str,
+    count:     usize,
+    getter:    impl Fn(usize) -> f32,
+    overlay:   Option<&str>,
+    scale_min: f32,
+    scale_max: f32,
+    size:      Vec2,
+) {
+    plot(ui, PlotKind::Histogram, count, &getter, PlotArgs { label, overlay, scale_min, scale_max, size });
+}
+
+fn plot(ui: &mut Ui<'_>, kind: PlotKind, count: usize, get: &dyn Fn(usize) -> f32, args: PlotArgs<'_>) {
+    let (text, id_src) = parse_label(args.label);
+    let id = ui.ctx.make_id(id_src);
+
+    let fs = ui.ctx.style.font_size;
+    let fp = ui.ctx.style.frame_padding;
+    let sp = ui.ctx.style.item_spacing;
+    let tw = ui.text_width(text);
+    let label_w = if text.is_empty() { 0.0 } else { sp.0 + tw };
+    let w = if args.size.x > 0.0 { args.size.x } else { (ui.available_width() - label_w).max(60.0) };
+    let h = if args.size.y > 0.0 { args.size.y } else { fs * 4.0 + fp.1 * 2.0 };
+
+    let pos = match ui.layout_next(Vec2::new(w + label_w, h)) { Some(p) => p, None => return };
+    let frame = Rect::from_min_size(pos, Vec2::new(w, h));
+    let inner = Rect::new(frame.min + Vec2::new(fp.0, fp.1), frame.max - Vec2::new(fp.0, fp.1));
+
+    let (hovered, _, _) = ui.ctx.button_behavior(id, frame);
+
+    let bg       = ui.ctx.style.color(StyleColor::FrameBg);
+    let rounding = ui.ctx.style.frame_rounding;
+    ui.ctx.draw_list.filled_rect(frame, rounding, bg);
+
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    if !text.is_empty() {
+        ui.draw_text(text, Vec2::new(frame.max.x + sp.0, pos.y + fp.1), tc);
+    }
+
+    let min_count = if kind == PlotKind::Lines { 2 } else { 1 };
+    if count >= min_count {
+        // ── Scale ────────────────────────────────────────────────────────────
+        let (mut lo, mut hi) = (args.scale_min, args.scale_max);
+        if lo == AUTO_SCALE || hi == AUTO_SCALE {
+            let (dmin, dmax) = (0..count).map(get).fold((f32::MAX, f32::MIN), |(a, b), v| (a.min(v), b.max(v)));
+            if lo == AUTO_SCALE { lo = dmin; }
+            if hi == AUTO_SCALE { hi = dmax; }
+        }
+        if hi <= lo { hi = lo + 1.0; }
+        let t_of = |v: f32| ((v - lo) / (hi - lo)).clamp(0.0, 1.0);
+        let y_of = |t: f32| inner.max.y - t * inner.height();
+
+        // ── Hover ────────────────────────────────────────────────────────────
+        let mouse = ui.ctx.input.mouse_pos;
+        let slots = if kind == PlotKind::Lines { count - 1 } else { count };
+        let hover_idx = if hovered && inner.contains(mouse) {
+            let t = (mouse.x - inner.min.x) / inner.width();
+            let i = match kind {
+                PlotKind::Lines     => (t * slots as f32).round() as usize,
+                PlotKind::Histogram => (t * slots as f32).floor() as usize,
+            };
+            Some(i.min(count - 1))
+        } else {
+            None
+        };
+
+        // ── Samples ──────────────────────────────────────────────────────────
+        match kind {
+            PlotKind::Lines => {
+                let col = ui.ctx.style.color(StyleColor::PlotLines);
+                // At most one segment per pixel
+                let res = slots.min(inner.width().max(1.0) as usize).max(1);
+                let step = slots as f32 / res as f32;
+                let point = |k: usize| {
+                    let i = ((k as f32 * step).round() as usize).min(count - 1);
+                    Vec2::new(inner.min.x + inner.width() * i as f32 / slots as f32, y_of(t_of(get(i))))
+                };
+                let mut prev = point(0);
+                for k in 1..=res {
+                    let p = point(k);
+                    ui.ctx.draw_list.line(prev, p, 1.0, col);
+                    prev = p;
+                }
+                if let Some(i) = hover_idx {
+                    let hc = ui.ctx.style.color(StyleColor::PlotLinesHovered);
+                    let x  = inner.min.x + inner.width() * i as f32 / slots as f32;
+                    let p  = Vec2::new(x, y_of(t_of(get(i))));
+                    ui.ctx.draw_list.line(Vec2::new(x, inner.min.y), Vec2::new(x, inner.max.y), 1.0, hc.with_alpha(0.35));
+                    ui.ctx.draw_list.filled_circle(p, 3.0, hc, 8);
+                }
+            }
+            PlotKind::Histogram => {
+                let col = ui.ctx.style.color(StyleColor::PlotHistogram);
+                let hc  = ui.ctx.style.color(StyleColor::PlotHistogramHovered);
+                // Bars grow from zero when the range spans it, else from the near edge
+                let zero_t = if lo < 0.0 && hi > 0.0 { t_of(0.0) } else if hi <= 0.0 { 1.0 } else { 0.0 };
+                let bar_w  = inner.width() / count as f32;
+                let gap    = if bar_w > 3.0 { 1.0 } else { 0.0 };
+                for i in 0..count {
+                    let x0 = inner.min.x + i as f32 * bar_w;
+                    let (a, b) = (y_of(zero_t), y_of(t_of(get(i))));
+                    let bar = Rect::new(Vec2::new(x0, a.min(b)), Vec2::new(x0 + bar_w - gap, a.max(b).max(a.min(b) + 1.0)));
+                    ui.ctx.draw_list.filled_rect(bar, 0.0, if hover_idx == Some(i) { hc } else { col });
+                }
+            }
+        }
+
+        if let Some(i) = hover_idx {
+            ui.set_tooltip(&format!("{i}: {:.3}", get(i)));
+        }
+    }
+
+    if let Some(overlay) = args.overlay {
+        let ow = ui.text_width(overlay);
+        ui.draw_text(overlay, Vec2::new(frame.center().x - ow * 0.5, frame.min.y + fp.1), tc);
+    }
+}
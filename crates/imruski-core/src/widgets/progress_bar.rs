@@ -1,4 +1,9 @@
-//! Progress-bar widget.
+//! Progress indicators: bars, rings and spinners.
+//!
+//! Animated variants take their phase from [`Ui::time`], so they run at the
+//! same speed whatever the frame rate.
+
+use std::f32::consts::{FRAC_PI_2, TAU};
 
 use crate::{
     style::StyleColor,
@@ -6,6 +11,11 @@ use crate::{
     Rect, Vec2,
 };
 
+/// Seconds for one sweep of the indeterminate bar.
+const SWEEP_PERIOD: f64 = 1.6;
+/// Spinner revolutions per second.
+const SPIN_SPEED:   f64 = 1.2;
+
 pub fn progress_bar(ui: &mut Ui<'_>, fraction: f32, mut size: Vec2, overlay: Option<&str>) {
     let fs  = ui.ctx.style.font_size;
     let fp  = ui.ctx.style.frame_padding;
@@ -40,3 +50,83 @@ pub fn progress_bar(ui: &mut Ui<'_>, fraction: f32, mut size: Vec2, overlay: Opt
     let tc = ui.ctx.style.color(StyleColor::Text);
     ui.draw_text(&ov_str, tp, tc);
 }
+
+/// Bar for work of unknown length: a block sweeps across it continuously.
+pub fn progress_bar_indeterminate(ui: &mut Ui<'_>, mut size: Vec2, overlay: Option<&str>) {
+    let fs  = ui.ctx.style.font_size;
+    let fp  = ui.ctx.style.frame_padding;
+    let h   = fs + fp.1 * 2.0;
+
+    if size.x <= 0.0 { size.x = ui.available_width(); }
+    if size.y <= 0.0 { size.y = h; }
+
+    let pos = match ui.layout_next(size) { Some(p) => p, None => return };
+    let rect = Rect::from_min_size(pos, size);
+
+    let rounding = ui.ctx.style.frame_rounding;
+    let bg_col   = ui.ctx.style.color(StyleColor::FrameBg);
+    let fill_col = ui.ctx.style.color(StyleColor::ProgressBar);
+    let border   = ui.ctx.style.color(StyleColor::Border);
+
+    // Block is 30% of the bar and enters/leaves fully off either end
+    let phase   = (ui.time() / SWEEP_PERIOD).fract() as f32;
+    let block_w = size.x * 0.3;
+    let x0      = pos.x - block_w + (size.x + block_w) * phase;
+    let block   = Rect::new(Vec2::new(x0.max(pos.x), pos.y), Vec2::new((x0 + block_w).min(rect.max.x), rect.max.y));
+
+    let draw = &mut ui.ctx.draw_list;
+    draw.filled_rect(rect, rounding, bg_col);
+    if block.width() > 0.0 { draw.filled_rect(block, rounding, fill_col); }
+    draw.rect_outline(rect, 1.0, border);
+
+    if let Some(text) = overlay {
+        let ow = ui.text_width(text);
+        let tp = Vec2::new(pos.x + (size.x - ow) * 0.5, pos.y + (size.y - fs) * 0.5);
+        let tc = ui.ctx.style.color(StyleColor::Text);
+        ui.draw_text(text, tp, tc);
+    }
+}
+
+/// Progress ring filling clockwise from 12 o'clock. `radius <= 0` uses the
+/// font size; the overlay (default `"NN%"`) is drawn in the middle.
+pub fn circular_progress(ui: &mut Ui<'_>, fraction: f32, radius: f32, overlay: Option<&str>) {
+    let fs     = ui.ctx.style.font_size;
+    let radius = if radius > 0.0 { radius } else { fs };
+    let thick  = (radius * 0.2).max(2.0);
+
+    let d   = (radius + thick * 0.5) * 2.0;
+    let pos = match ui.layout_next(Vec2::new(d, d)) { Some(p) => p, None => return };
+    let center = pos + Vec2::new(d * 0.5, d * 0.5);
+
+    let bg_col   = ui.ctx.style.color(StyleColor::FrameBg);
+    let fill_col = ui.ctx.style.color(StyleColor::ProgressBar);
+    let start    = -FRAC_PI_2;
+    ui.ctx.draw_list.arc(center, radius, 0.0, TAU, thick, bg_col);
+    ui.ctx.draw_list.arc(center, radius, start, start + TAU * fraction.clamp(0.0, 1.0), thick, fill_col);
+
+    let ov_str = overlay.map(str::to_owned).unwrap_or_else(|| format!("{:.0}%", fraction * 100.0));
+    let ow = ui.text_width(&ov_str);
+    if ow < (radius - thick) * 2.0 {
+        let tc = ui.ctx.style.color(StyleColor::Text);
+        ui.draw_text(&ov_str, center - Vec2::new(ow * 0.5, fs * 0.5), tc);
+    }
+}
+
+/// Rotating loading spinner. `radius <= 0` uses half the font size.
+pub fn spinner(ui: &mut Ui<'_>, radius: f32, thickness: f32) {
+    let fs     = ui.ctx.style.font_size;
+    let radius = if radius > 0.0 { radius } else { fs * 0.5 };
+    let thick  = if thickness > 0.0 { thickness } else { (radius * 0.25).max(1.5) };
+
+    let d   = (radius + thick * 0.5) * 2.0;
+    let pos = match ui.layout_next(Vec2::new(d, d)) { Some(p) => p, None => return };
+    let center = pos + Vec2::new(d * 0.5, d * 0.5);
+
+    // The head spins at a constant rate while the tail breathes, so the
+    // arc grows and shrinks as it turns
+    let t    = ui.time();
+    let head = ((t * SPIN_SPEED).fract() as f32) * TAU;
+    let len  = TAU * (0.45 + 0.3 * (t * 2.5).sin() as f32);
+    let col  = ui.ctx.style.color(StyleColor::ProgressBar);
+    ui.ctx.draw_list.arc(center, radius, head - len, head, thick, col);
+}
@@ -0,0 +1,100 @@
+//! Live scrolling plot over a [`ScrollingBuffer`].
+//!
+//! The view shows the last `history_secs` seconds up to `Ui::time`, so it
+//! slides with `delta_time` even when no samples arrive. Pause freezes
+//! the view; while paused the chart can be zoomed and panned.
+
+use crate::{
+    scrolling::ScrollingBuffer,
+    ui::Ui,
+    widgets::chart::{self, PlotAxis, PlotCond, PlotFlags},
+    Vec2,
+};
+
+const REALTIME_HEIGHT: f32 = 160.0;
+/// Headroom above and below the visible samples.
+const Y_PAD: f64 = 0.1;
+
+/// Overlaid channels of `buffer` over the last `history_secs`, with a
+/// pause button and min / avg / max of each channel's visible samples.
+///
+/// Storage: `active` paused, `int[0..2]` the frozen `Ui::time` as `f64` bits.
+pub fn plot_realtime<T: Copy + Default + Into<f64>>(ui: &mut Ui<'_>, str_id: &str, buffer: &ScrollingBuffer<T>, history_secs: f32) {
+    let id   = ui.ctx.make_id(str_id);
+    let time = ui.ctx.input.time;
+    let st   = ui.ctx.get_storage_mut(id);
+    let paused  = st.active;
+    let now     = if paused { f64::from_bits(st.int[0] as u32 as u64 | (st.int[1] as u32 as u64) << 32) } else { time };
+    let history = history_secs.max(f32::EPSILON) as f64;
+
+    ui.ctx.push_id_str(str_id);
+    let label = if paused { "Resume##pause" } else { "Pause##pause" };
+    if ui.small_button(label) {
+        let st = ui.ctx.get_storage_mut(id);
+        let bits = time.to_bits();
+        st.active = !paused;
+        st.int[0] = bits as u32 as i32;
+        st.int[1] = (bits >> 32) as u32 as i32;
+    }
+
+    // ── Visible samples, relative to `now` ───────────────────────────────────
+    let channels = buffer.channel_count();
+    let mut series: Vec<(Vec<f64>, Vec<f64>)> = vec![(Vec::new(), Vec::new()); channels];
+    let mut range = (f64::INFINITY, f64::NEG_INFINITY);
+    for (c, (xs, ys)) in series.iter_mut().enumerate() {
+        for (t, v) in buffer.iter(c) {
+            if t < now - history || t > now { continue; }
+            let v: f64 = v.into();
+            xs.push(t - now);
+            ys.push(v);
+            if v.is_finite() { range = (range.0.min(v), range.1.max(v)); }
+        }
+    }
+
+    let flags = if paused { PlotFlags::NO_TITLE } else { PlotFlags::NO_TITLE | PlotFlags::NO_INPUTS };
+    let flags = if channels > 1 { flags } else { flags | PlotFlags::NO_LEGEND };
+    if chart::begin_plot_ex(ui, "##plot", Vec2::new(0.0, REALTIME_HEIGHT), flags) {
+        if !paused {
+            chart::plot_setup_axis_limits(ui, PlotAxis::X, -history, 0.0, PlotCond::Always);
+            if range.0 <= range.1 {
+                let pad = ((range.1 - range.0) * Y_PAD).max(1e-6);
+                chart::plot_setup_axis_limits(ui, PlotAxis::Y, range.0 - pad, range.1 + pad, PlotCond::Always);
+            }
+        }
+        for (c, (xs, ys)) in series.iter().enumerate() {
+            chart::plot_line(ui, &channel_label(buffer, c), xs, ys);
+        }
+        chart::end_plot(ui);
+    }
+
+    // ── Readouts ─────────────────────────────────────────────────────────────
+    for (c, (_, ys)) in series.iter().enumerate() {
+        let col  = chart::series_color(c);
+        let name = buffer.channel_name(c);
+        let text = match stats(ys) {
+            Some((min, avg, max)) => format!("{name}  min {min:.3}  avg {avg:.3}  max {max:.3}"),
+            None                  => format!("{name}  no data"),
+        };
+        ui.text_colored(col, text.trim_start());
+    }
+    ui.ctx.pop_id();
+}
+
+fn channel_label<T: Copy + Default>(buffer: &ScrollingBuffer<T>, c: usize) -> String {
+    match buffer.channel_name(c) {
+        ""   => format!("##channel{c}"),
+        name => format!("{name}##channel{c}"),
+    }
+}
+
+/// `(min, avg, max)` of the finite values.
+fn stats(values: &[f64]) -> Option<(f64, f64, f64)> {
+    let (mut min, mut max, mut sum, mut n) = (f64::INFINITY, f64::NEG_INFINITY, 0.0, 0usize);
+    for &v in values.iter().filter(|v| v.is_finite()) {
+        min = min.min(v);
+        max = max.max(v);
+        sum += v;
+        n   += 1;
+    }
+    (n > 0).then(|| (min, sum / n as f64, max))
+}
@@ -0,0 +1,396 @@
+//! Selectable rows, list boxes and the multi-selection scope.
+//!
+//! - `selectable`                  – a full-width row that highlights when selected
+//! - `list_box` / `list_box_multi` – framed, scrolling lists of rows
+//! - `begin_multi_select` / `multi_selectable` / `end_multi_select` – a
+//!   selection scope for lists the app stores itself; it reports
+//!   [`SelectionRequest`]s instead of mutating anything.
+
+use crate::{
+    id::{parse_label, Id},
+    input::{Key, MouseButton},
+    selection::{Selection, SelectionRequest},
+    style::StyleColor,
+    ui::Ui,
+    widgets::table::scrollbar,
+    Rect, Vec2,
+};
+
+/// Rows skipped by PageUp / PageDown inside a multi-select scope.
+const NAV_PAGE: usize = 8;
+
+// ─── Selectable ──────────────────────────────────────────────────────────────
+
+/// Row that spans the available width. Returns `true` when clicked; the
+/// caller owns the `selected` state.
+pub fn selectable(ui: &mut Ui<'_>, label: &str, selected: bool) -> bool {
+    selectable_sized(ui, label, selected, Vec2::ZERO)
+}
+
+/// `selectable` with an explicit size; a zero component means "fit"
+/// (available width, or one text line of height).
+pub fn selectable_sized(ui: &mut Ui<'_>, label: &str, selected: bool, size: Vec2) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+    match selectable_row(ui, id, text, selected, size) {
+        Some((_, _, clicked)) => clicked,
+        None => false,
+    }
+}
+
+/// Lay out, hit-test and draw one row. Returns `(rect, hovered, clicked)`.
+fn selectable_row(ui: &mut Ui<'_>, id: Id, text: &str, selected: bool, size: Vec2) -> Option<(Rect, bool, bool)> {
+    let fs = ui.ctx.style.font_size;
+    let w  = if size.x > 0.0 { size.x } else { ui.available_width().max(ui.text_width(text)) };
+    let h  = if size.y > 0.0 { size.y } else { fs + 2.0 };
+    let pos  = ui.layout_next(Vec2::new(w, h))?;
+    let rect = Rect::from_min_size(pos, Vec2::new(w, h));
+    let (hovered, _, clicked) = ui.ctx.button_behavior(id, rect);
+    draw_row(ui, rect, text, 0.0, selected, hovered);
+    Some((rect, hovered, clicked))
+}
+
+/// Background (`Header*` colors) plus label for a row.
+fn draw_row(ui: &mut Ui<'_>, rect: Rect, text: &str, pad: f32, selected: bool, hovered: bool) {
+    let bg = if hovered && ui.ctx.input.mouse_down(MouseButton::Left) {
+        Some(StyleColor::HeaderActive)
+    } else if hovered {
+        Some(StyleColor::HeaderHovered)
+    } else if selected {
+        Some(StyleColor::Header)
+    } else {
+        None
+    };
+    if let Some(bg) = bg {
+        let c = ui.ctx.style.color(bg);
+        ui.ctx.draw_list.filled_rect(rect, 0.0, c);
+    }
+    let fs = ui.ctx.style.font_size;
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    ui.draw_text(text, Vec2::new(rect.min.x + pad, rect.min.y + (rect.height() - fs) * 0.5), tc);
+}
+
+// ─── Multi-selection scope ───────────────────────────────────────────────────
+
+/// State of one `begin_multi_select` … `end_multi_select` scope.
+///
+/// Click selects, Ctrl+click toggles, Shift+click selects the range from the
+/// anchor; Ctrl+A selects all, Escape clears, arrows / Home / End / Page keys
+/// move the selection (with Shift to extend, with Ctrl to move only the
+/// cursor) and Space toggles the cursor item.
+#[derive(Debug)]
+pub struct MultiSelect {
+    id:            Id,
+    anchor:        Option<usize>,
+    cursor:        Option<usize>,
+    focused:       bool,
+    toggle_cursor: bool,
+    moved:         bool,
+    requests:      Vec<SelectionRequest>,
+}
+
+impl MultiSelect {
+    /// Item with keyboard focus, if any.
+    pub fn cursor(&self) -> Option<usize> { self.cursor }
+
+    /// Requests gathered so far this frame.
+    pub fn requests(&self) -> &[SelectionRequest] { &self.requests }
+
+    fn select_range(&mut self, a: usize, b: usize, clear: bool) {
+        if clear { self.requests.push(SelectionRequest::SetAll(false)); }
+        self.requests.push(SelectionRequest::SetRange { first: a.min(b), last: a.max(b), selected: true });
+    }
+}
+
+/// Open a selection scope over `count` items. Keyboard requests are
+/// gathered here; submit the rows with `multi_selectable`.
+///
+/// Storage: `int[0]` anchor, `int[1]` cursor (`-1` = none).
+pub fn begin_multi_select(ui: &mut Ui<'_>, str_id: &str, count: usize) -> MultiSelect {
+    let id = ui.ctx.make_id(str_id);
+    let idx = |v: i32| if v >= 0 && (v as usize) < count { Some(v as usize) } else { None };
+    let (anchor, cursor) = match ui.ctx.get_storage(id) {
+        Some(s) => (idx(s.int[0]), idx(s.int[1])),
+        None    => (None, None),
+    };
+    let mut ms = MultiSelect {
+        id, anchor, cursor,
+        focused:       ui.ctx.focus_item == Some(id),
+        toggle_cursor: false,
+        moved:         false,
+        requests:      Vec::new(),
+    };
+    if !ms.focused || count == 0 { return ms; }
+
+    let input = &ui.ctx.input;
+    let last  = count - 1;
+    if input.ctrl() && input.key_pressed(Key::A) {
+        ms.requests.push(SelectionRequest::SetAll(true));
+    } else if input.key_pressed(Key::Escape) {
+        ms.requests.push(SelectionRequest::SetAll(false));
+    }
+    let target = if input.key_pressed(Key::Down) {
+        Some(cursor.map_or(0, |c| (c + 1).min(last)))
+    } else if input.key_pressed(Key::Up) {
+        Some(cursor.map_or(0, |c| c.saturating_sub(1)))
+    } else if input.key_pressed(Key::PageDown) {
+        Some(cursor.map_or(0, |c| (c + NAV_PAGE).min(last)))
+    } else if input.key_pressed(Key::PageUp) {
+        Some(cursor.map_or(0, |c| c.saturating_sub(NAV_PAGE)))
+    } else if input.key_pressed(Key::Home) {
+        Some(0)
+    } else if input.key_pressed(Key::End) {
+        Some(last)
+    } else {
+        None
+    };
+    let (shift, ctrl) = (input.shift(), input.ctrl());
+    ms.toggle_cursor = input.key_pressed(Key::Space);
+
+    if let Some(t) = target {
+        if shift {
+            let a = anchor.or(cursor).unwrap_or(t);
+            ms.select_range(a, t, !ctrl);
+            ms.anchor = Some(a);
+        } else if !ctrl {
+            ms.select_range(t, t, true);
+            ms.anchor = Some(t);
+        }
+        ms.cursor = Some(t);
+        ms.moved  = true;
+    }
+    ms
+}
+
+/// One row of a multi-select scope; `selected` is the app's current state
+/// for `index`. Returns `true` when the row was clicked.
+pub fn multi_selectable(ui: &mut Ui<'_>, ms: &mut MultiSelect, index: usize, label: &str, selected: bool) -> bool {
+    let (text, _) = parse_label(label);
+    let row_id = ms.id.combine(Id::from_hash(&index));
+    let Some((rect, _, clicked)) = selectable_row(ui, row_id, text, selected, Vec2::ZERO) else { return false };
+    multi_row_behavior(ui, ms, row_id, rect, index, selected, clicked);
+    clicked
+}
+
+/// Shared click / focus handling for rows inside a scope.
+fn multi_row_behavior(ui: &mut Ui<'_>, ms: &mut MultiSelect, row_id: Id, rect: Rect, index: usize, selected: bool, clicked: bool) {
+    // Rows hand keyboard focus to the scope
+    if ui.ctx.focus_item == Some(row_id) {
+        ui.ctx.focus_item = Some(ms.id);
+        ms.focused = true;
+    }
+    if ms.toggle_cursor && ms.cursor == Some(index) {
+        ms.requests.push(SelectionRequest::SetRange { first: index, last: index, selected: !selected });
+    }
+    if ms.focused && ms.cursor == Some(index) {
+        let c = ui.ctx.style.color(StyleColor::SliderGrab);
+        ui.ctx.draw_list.rect_outline(rect, 1.0, c);
+    }
+    if !clicked { return; }
+
+    let (shift, ctrl) = (ui.ctx.input.shift(), ui.ctx.input.ctrl());
+    if shift {
+        let a = ms.anchor.unwrap_or(index);
+        ms.select_range(a, index, !ctrl);
+        ms.anchor = Some(a);
+    } else if ctrl {
+        ms.requests.push(SelectionRequest::SetRange { first: index, last: index, selected: !selected });
+        ms.anchor = Some(index);
+    } else {
+        ms.select_range(index, index, true);
+        ms.anchor = Some(index);
+    }
+    ms.cursor = Some(index);
+}
+
+/// Close the scope and return the requests to apply.
+pub fn end_multi_select(ui: &mut Ui<'_>, ms: MultiSelect) -> Vec<SelectionRequest> {
+    let s = ui.ctx.get_storage_mut(ms.id);
+    s.int[0] = ms.anchor.map_or(-1, |a| a as i32);
+    s.int[1] = ms.cursor.map_or(-1, |c| c as i32);
+    ms.requests
+}
+
+// ─── List boxes ──────────────────────────────────────────────────────────────
+
+/// Geometry of a list box for the current frame.
+struct ListFrame {
+    id:      Id,
+    inner:   Rect,
+    row_h:   f32,
+    first:   usize,
+    visible: usize,
+    count:   usize,
+    rows:    usize,
+}
+
+/// Lay out the framed list, apply wheel scrolling and keep `follow` in view.
+/// Pushes a clip rect that `end_list_frame` pops.
+///
+/// Storage: `int[2]` first visible row.
+fn begin_list_frame(
+    ui:     &mut Ui<'_>,
+    id:     Id,
+    text:   &str,
+    count:  usize,
+    rows:   usize,
+    follow: Option<usize>,
+) -> Option<ListFrame> {
+    let fs    = ui.ctx.style.font_size;
+    let fp    = ui.ctx.style.frame_padding;
+    let sp    = ui.ctx.style.item_spacing;
+    let row_h = fs + sp.1;
+    let rows  = rows.max(1);
+    let tw    = ui.text_width(text);
+    let box_w = if text.is_empty() { ui.available_width() } else { (ui.available_width() - tw - sp.0).max(60.0) };
+    let box_h = rows as f32 * row_h + fp.1 * 2.0;
+    let total = Vec2::new(if text.is_empty() { box_w } else { box_w + sp.0 + tw }, box_h);
+
+    let pos   = ui.layout_next(total)?;
+    let frame = Rect::from_min_size(pos, Vec2::new(box_w, box_h));
+
+    let max_first = count.saturating_sub(rows);
+    let mut first = ui.ctx.get_storage(id).map_or(0, |s| s.int[2].max(0) as usize);
+    if frame.contains(ui.ctx.input.mouse_pos) && ui.ctx.input.mouse_wheel != 0.0 {
+        let step = -ui.ctx.input.mouse_wheel.signum() as isize;
+        first = (first as isize + step).max(0) as usize;
+    }
+    if let Some(f) = follow {
+        if f < first { first = f; }
+        if f >= first + rows { first = f + 1 - rows; }
+    }
+    first = first.min(max_first);
+    ui.ctx.get_storage_mut(id).int[2] = first as i32;
+
+    let rounding = ui.ctx.style.frame_rounding;
+    let bg       = ui.ctx.style.color(StyleColor::FrameBg);
+    let border   = ui.ctx.style.color(StyleColor::Border);
+    ui.ctx.draw_list.filled_rect(frame, rounding, bg);
+    ui.ctx.draw_list.rect_outline(frame, 1.0, border);
+    if !text.is_empty() {
+        let tc = ui.ctx.style.color(StyleColor::Text);
+        ui.draw_text(text, Vec2::new(frame.max.x + sp.0, pos.y + fp.1 + (row_h - fs) * 0.5), tc);
+    }
+
+    let sb_w  = if max_first > 0 { ui.ctx.style.scrollbar_size } else { 0.0 };
+    let inner = Rect::new(
+        Vec2::new(frame.min.x + 1.0, frame.min.y + fp.1),
+        Vec2::new(frame.max.x - 1.0 - sb_w, frame.max.y - fp.1),
+    );
+    ui.ctx.draw_list.push_clip_rect(inner);
+    Some(ListFrame { id, inner, row_h, first, visible: rows.min(count - first.min(count)), count, rows })
+}
+
+impl ListFrame {
+    fn row_rect(&self, row: usize) -> Rect {
+        Rect::from_min_size(
+            Vec2::new(self.inner.min.x, self.inner.min.y + row as f32 * self.row_h),
+            Vec2::new(self.inner.width(), self.row_h),
+        )
+    }
+}
+
+/// Pop the clip rect and draw the scrollbar if the list overflows.
+fn end_list_frame(ui: &mut Ui<'_>, lf: &ListFrame) {
+    ui.ctx.draw_list.pop_clip_rect();
+    let max_first = lf.count.saturating_sub(lf.rows);
+    if max_first == 0 { return; }
+    let sb_w   = ui.ctx.style.scrollbar_size;
+    let track  = Rect::new(
+        Vec2::new(lf.inner.max.x, lf.inner.min.y),
+        Vec2::new(lf.inner.max.x + sb_w, lf.inner.max.y),
+    );
+    let bar_id = lf.id.combine(Id::from_str("##scroll"));
+    let scroll = scrollbar(ui, bar_id, track, true, lf.first as f32, lf.rows as f32, lf.count as f32);
+    // Dragging the bar keeps keyboard navigation on the list
+    if ui.ctx.focus_item == Some(bar_id) { ui.ctx.focus_item = Some(lf.id); }
+    ui.ctx.get_storage_mut(lf.id).int[2] = (scroll.round().max(0.0) as usize).min(max_first) as i32;
+}
+
+/// Framed single-selection list showing `height_in_items` rows. Arrow,
+/// Page, Home and End keys move the selection while the list has focus.
+/// Returns `true` if `current` changed.
+pub fn list_box(
+    ui:              &mut Ui<'_>,
+    label:           &str,
+    current:         &mut usize,
+    items:           &[&str],
+    height_in_items: usize,
+) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id    = ui.ctx.make_id(id_src);
+    let count = items.len();
+    let rows  = height_in_items.max(1);
+
+    let mut changed = false;
+    let mut follow  = None;
+    if ui.ctx.focus_item == Some(id) && count > 0 {
+        let input = &ui.ctx.input;
+        let last  = count - 1;
+        let old   = (*current).min(last);
+        let mut cur = old;
+        if input.key_pressed(Key::Down)     { cur = (cur + 1).min(last); }
+        if input.key_pressed(Key::Up)       { cur = cur.saturating_sub(1); }
+        if input.key_pressed(Key::PageDown) { cur = (cur + rows).min(last); }
+        if input.key_pressed(Key::PageUp)   { cur = cur.saturating_sub(rows); }
+        if input.key_pressed(Key::Home)     { cur = 0; }
+        if input.key_pressed(Key::End)      { cur = last; }
+        if cur != old || *current > last {
+            *current = cur;
+            changed  = true;
+            follow   = Some(cur);
+        }
+    }
+
+    let Some(lf) = begin_list_frame(ui, id, text, count, rows, follow) else { return changed };
+    let pad = ui.ctx.style.frame_padding.0;
+    for row in 0..lf.visible {
+        let i        = lf.first + row;
+        let rect     = lf.row_rect(row);
+        let row_id   = id.combine(Id::from_hash(&i));
+        let (hovered, _, clicked) = ui.ctx.button_behavior(row_id, rect);
+        if ui.ctx.focus_item == Some(row_id) { ui.ctx.focus_item = Some(id); }
+        draw_row(ui, rect, items[i], pad, i == *current, hovered);
+        if clicked && *current != i {
+            *current = i;
+            changed  = true;
+        }
+    }
+    end_list_frame(ui, &lf);
+    changed
+}
+
+/// Framed list with multi-selection stored in `selection`. See
+/// [`MultiSelect`] for the mouse and keyboard bindings. Returns `true` if the
+/// selection changed.
+pub fn list_box_multi(
+    ui:              &mut Ui<'_>,
+    label:           &str,
+    selection:       &mut Selection,
+    items:           &[&str],
+    height_in_items: usize,
+) -> bool {
+    let (text, id_src) = parse_label(label);
+    let count  = items.len();
+    let mut ms = begin_multi_select(ui, id_src, count);
+    let follow = if ms.moved { ms.cursor } else { None };
+
+    if let Some(lf) = begin_list_frame(ui, ms.id, text, count, height_in_items, follow) {
+        let pad = ui.ctx.style.frame_padding.0;
+        for row in 0..lf.visible {
+            let i        = lf.first + row;
+            let rect     = lf.row_rect(row);
+            let row_id   = ms.id.combine(Id::from_hash(&i));
+            let selected = selection.contains(i);
+            let (hovered, _, clicked) = ui.ctx.button_behavior(row_id, rect);
+            draw_row(ui, rect, items[i], pad, selected, hovered);
+            multi_row_behavior(ui, &mut ms, row_id, rect, i, selected, clicked);
+        }
+        end_list_frame(ui, &lf);
+    }
+
+    let requests = end_multi_select(ui, ms);
+    let before   = selection.clone();
+    selection.apply_requests(&requests, count);
+    *selection != before
+}
@@ -1,122 +1,730 @@
 //! Slider and drag widgets.
+//!
+//! Sliders are generic over [`Numeric`] (`i8`‥`u64`, `f32`, `f64`); integer
+//! values never pass through a float, so wide `i64` / `u64` ranges stay
+//! exact. Value text uses a printf-style format such as `"%.2f ms"`,
+//! `"%d"` or `"%08X"`. Ctrl+click or double-click a slider to type a value.
 
 use crate::{
-    id::parse_label,
+    id::{parse_label, Id},
+    input::{Key, MouseButton},
     style::StyleColor,
     ui::Ui,
-    Rect, Vec2,
+    Color, Rect, Vec2,
 };
 
+bitflags::bitflags! {
+    /// Options for `slider_scalar` and `v_slider`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct SliderFlags: u32 {
+        /// Clamp typed-in values to the range too.
+        const ALWAYS_CLAMP       = 1 << 0;
+        /// Logarithmic mapping – more resolution near zero.
+        const LOGARITHMIC        = 1 << 1;
+        /// Keep full float precision instead of rounding to the format.
+        const NO_ROUND_TO_FORMAT = 1 << 2;
+        /// Disable Ctrl+click / double-click text entry.
+        const NO_INPUT           = 1 << 3;
+    }
+}
+
+// ─── Numeric ─────────────────────────────────────────────────────────────────
+
+/// Scalar types a slider can edit.
+pub trait Numeric: Copy + PartialOrd + std::fmt::Debug + 'static {
+    const IS_FLOAT: bool;
+    /// Format used by the non-`_scalar` helpers.
+    const DEFAULT_FORMAT: &'static str;
+    /// Width `%x` / `%X` wrap negative values to.
+    const BITS: u32;
+    fn to_f64(self) -> f64;
+    /// Rounds and saturates for integers.
+    fn from_f64(v: f64) -> Self;
+    /// Rounds floats.
+    fn to_i128(self) -> i128;
+    /// Saturates for integers.
+    fn from_i128(v: i128) -> Self;
+    fn parse(s: &str) -> Option<Self>;
+}
+
+macro_rules! impl_numeric_int {
+    ($($t:ty),*) => {$(
+        impl Numeric for $t {
+            const IS_FLOAT: bool = false;
+            const DEFAULT_FORMAT: &'static str = "%d";
+            const BITS: u32 = <$t>::BITS;
+            fn to_f64(self) -> f64 { self as f64 }
+            fn from_f64(v: f64) -> Self { v.round() as $t }
+            fn to_i128(self) -> i128 { self as i128 }
+            fn from_i128(v: i128) -> Self { v.clamp(<$t>::MIN as i128, <$t>::MAX as i128) as $t }
+            fn parse(s: &str) -> Option<Self> {
+                s.parse::<i128>().ok().map(Self::from_i128)
+                    .or_else(|| s.parse::<f64>().ok().filter(|v| v.is_finite()).map(Self::from_f64))
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_numeric_float {
+    ($($t:ty),*) => {$(
+        impl Numeric for $t {
+            const IS_FLOAT: bool = true;
+            const DEFAULT_FORMAT: &'static str = "%.3f";
+            const BITS: u32 = 64;
+            fn to_f64(self) -> f64 { self as f64 }
+            fn from_f64(v: f64) -> Self { v as $t }
+            fn to_i128(self) -> i128 { self.round() as i128 }
+            fn from_i128(v: i128) -> Self { v as $t }
+            fn parse(s: &str) -> Option<Self> { s.parse::<f64>().ok().filter(|v| v.is_finite()).map(|v| v as $t) }
+        }
+    )*};
+}
+
+impl_numeric_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+impl_numeric_float!(f32, f64);
+
+/// `min + t * (max - min)`; integers stay exact at both ends.
+fn lerp<T: Numeric>(min: T, max: T, t: f64) -> T {
+    if T::IS_FLOAT {
+        T::from_f64(min.to_f64() + t * (max.to_f64() - min.to_f64()))
+    } else {
+        let (a, b) = (min.to_i128(), max.to_i128());
+        let step = (t * (b - a) as f64).round() as i128;
+        T::from_i128(a + step.clamp((b - a).min(0), (b - a).max(0)))
+    }
+}
+
+/// Position of `v` in `min..=max`, `0..=1`.
+fn unlerp<T: Numeric>(min: T, max: T, v: T) -> f64 {
+    let t = if T::IS_FLOAT {
+        (v.to_f64() - min.to_f64()) / (max.to_f64() - min.to_f64())
+    } else {
+        let (a, b) = (min.to_i128(), max.to_i128());
+        if a == b { 0.0 } else { (v.to_i128() - a) as f64 / (b - a) as f64 }
+    };
+    if t.is_finite() { t.clamp(0.0, 1.0) } else { 0.0 }
+}
+
+// ─── Logarithmic mapping ─────────────────────────────────────────────────────
+//
+// Values within `eps` of zero are treated as zero so ranges that touch or
+// span zero still work; a range spanning zero gets one log ramp per side.
+
+fn log_ramp(v: f64, lo: f64, hi: f64) -> f64 { (v.max(lo) / lo).ln() / (hi / lo).ln() }
+
+fn log_unlerp(v: f64, min: f64, max: f64, eps: f64) -> f64 {
+    if min > max { return 1.0 - log_unlerp(v, max, min, eps); }
+    let t = if min >= 0.0 {
+        let lo = min.max(eps);
+        if max <= lo { 0.0 } else { log_ramp(v, lo, max) }
+    } else if max <= 0.0 {
+        let lo = (-max).max(eps);
+        if -min <= lo { 0.0 } else { 1.0 - log_ramp(-v, lo, -min) }
+    } else {
+        let (l, r) = ((-min / eps).ln().max(0.0), (max / eps).ln().max(0.0));
+        let zero = l / (l + r).max(f64::EPSILON);
+        if v < 0.0 {
+            zero * (1.0 - (-v / eps).max(1.0).ln() / l.max(f64::EPSILON))
+        } else {
+            zero + (1.0 - zero) * (v / eps).max(1.0).ln() / r.max(f64::EPSILON)
+        }
+    };
+    t.clamp(0.0, 1.0)
+}
+
+fn log_lerp(t: f64, min: f64, max: f64, eps: f64) -> f64 {
+    if min > max { return log_lerp(1.0 - t, max, min, eps); }
+    if t <= 0.0 { return min; }
+    if t >= 1.0 { return max; }
+    if min >= 0.0 {
+        let lo = min.max(eps);
+        lo * (max / lo).powf(t)
+    } else if max <= 0.0 {
+        let lo = (-max).max(eps);
+        -(lo * (-min / lo).powf(1.0 - t))
+    } else {
+        let (l, r) = ((-min / eps).ln().max(0.0), (max / eps).ln().max(0.0));
+        let zero = l / (l + r).max(f64::EPSILON);
+        if t < zero {
+            -(eps * (l * (1.0 - t / zero)).exp())
+        } else if t > zero {
+            eps * (r * (t - zero) / (1.0 - zero)).exp()
+        } else {
+            0.0
+        }
+    }
+}
+
+// ─── Formatting ──────────────────────────────────────────────────────────────
+
+/// One `%` conversion with the literal text around it.
+struct FormatSpec<'a> {
+    prefix:    &'a str,
+    suffix:    &'a str,
+    left:      bool,
+    plus:      bool,
+    zero:      bool,
+    width:     usize,
+    precision: Option<usize>,
+    kind:      char,
+}
+
+fn parse_format(fmt: &str) -> Option<FormatSpec<'_>> {
+    let bytes = fmt.as_bytes();
+    let mut i = 0;
+    let start = loop {
+        let at = i + fmt[i..].find('%')?;
+        if bytes.get(at + 1) == Some(&b'%') { i = at + 2; continue; }
+        break at;
+    };
+    let mut spec = FormatSpec { prefix: &fmt[..start], suffix: "", left: false, plus: false, zero: false, width: 0, precision: None, kind: 'f' };
+    let mut j = start + 1;
+    while let Some(&c) = bytes.get(j) {
+        match c {
+            b'-' => spec.left = true,
+            b'+' => spec.plus = true,
+            b'0' => spec.zero = true,
+            b' ' | b'#' | b'\'' => {}
+            _ => break,
+        }
+        j += 1;
+    }
+    while let Some(d) = bytes.get(j).filter(|c| c.is_ascii_digit()) {
+        spec.width = spec.width * 10 + (d - b'0') as usize;
+        j += 1;
+    }
+    if bytes.get(j) == Some(&b'.') {
+        j += 1;
+        let mut p = 0;
+        while let Some(d) = bytes.get(j).filter(|c| c.is_ascii_digit()) {
+            p = p * 10 + (d - b'0') as usize;
+            j += 1;
+        }
+        spec.precision = Some(p);
+    }
+    // Length modifiers (`%lld`, `%lf`) are accepted and ignored
+    while matches!(bytes.get(j), Some(b'l' | b'h' | b'L' | b'z' | b'j' | b't')) { j += 1; }
+    spec.kind   = *bytes.get(j)? as char;
+    spec.suffix = &fmt[j + 1..];
+    Some(spec)
+}
+
+/// Format `v` with a printf-style `fmt` (`%d %i %u %x %X %f %e %g`).
+/// A format without a conversion is shown as-is.
+pub fn format_numeric<T: Numeric>(fmt: &str, v: T) -> String {
+    let Some(spec) = parse_format(fmt) else { return fmt.replace("%%", "%") };
+    let body = format_body(&spec, v);
+    format!("{}{}{}", spec.prefix.replace("%%", "%"), body, spec.suffix.replace("%%", "%"))
+}
+
+/// The converted number alone, padded to the spec's width.
+fn format_body<T: Numeric>(spec: &FormatSpec<'_>, v: T) -> String {
+    let f = v.to_f64();
+    // Two's complement at the type's own width, not i128's
+    let hex = v.to_i128() as u128 & (u128::MAX >> (128 - T::BITS));
+    let mut num = match spec.kind {
+        'd' | 'i' | 'u' => v.to_i128().to_string(),
+        'x'             => format!("{hex:x}"),
+        'X'             => format!("{hex:X}"),
+        'e' | 'E'       => {
+            let s = format!("{:.*e}", spec.precision.unwrap_or(6), f);
+            if spec.kind == 'E' { s.to_uppercase() } else { s }
+        }
+        'g' | 'G'       => format!("{f}"),
+        _               => format!("{:.*}", spec.precision.unwrap_or(6), f),
+    };
+    if spec.plus && !num.starts_with('-') { num.insert(0, '+'); }
+    if num.len() < spec.width {
+        let pad = spec.width - num.len();
+        if spec.left {
+            num.push_str(&" ".repeat(pad));
+        } else if spec.zero {
+            let sign = usize::from(num.starts_with(['-', '+']));
+            num.insert_str(sign, &"0".repeat(pad));
+        } else {
+            num.insert_str(0, &" ".repeat(pad));
+        }
+    }
+    num
+}
+
+/// Round a float to the precision its `%f` / `%e` format shows.
+fn round_to_format<T: Numeric>(fmt: &str, v: T) -> T {
+    if !T::IS_FLOAT { return v; }
+    let Some(spec) = parse_format(fmt) else { return v };
+    if !matches!(spec.kind, 'f' | 'F' | 'e' | 'E') { return v; }
+    T::parse(format_body(&FormatSpec { width: 0, plus: false, ..spec }, v).trim()).unwrap_or(v)
+}
+
+/// Smallest step the format shows – the near-zero cutoff for log sliders.
+fn format_epsilon(fmt: &str) -> f64 {
+    match parse_format(fmt) {
+        Some(FormatSpec { kind: 'f' | 'F', precision, .. }) => 10f64.powi(-(precision.unwrap_or(6) as i32)),
+        _ => 1e-3,
+    }
+}
+
+// ─── Shared pieces ───────────────────────────────────────────────────────────
+
+/// Tag colours for the X / Y / Z / W components of multi-value widgets.
+const COMPONENT_TAGS: [(&str, Color); 4] = [
+    ("X", Color { r: 0.80, g: 0.28, b: 0.28, a: 1.0 }),
+    ("Y", Color { r: 0.32, g: 0.68, b: 0.32, a: 1.0 }),
+    ("Z", Color { r: 0.30, g: 0.46, b: 0.86, a: 1.0 }),
+    ("W", Color { r: 0.60, g: 0.60, b: 0.60, a: 1.0 }),
+];
+
+/// Lay out a frame-height row split evenly into `n` boxes followed by
+/// `label`. Returns the boxes and where the label goes.
+pub(crate) fn component_row(ui: &mut Ui<'_>, label: &str, n: usize) -> Option<(Vec<Rect>, Vec2)> {
+    let fs = ui.ctx.style.font_size;
+    let fp = ui.ctx.style.frame_padding;
+    let sp = ui.ctx.style.item_spacing;
+    let h  = fs + fp.1 * 2.0;
+    let tw = ui.text_width(label);
+    let inner = sp.0 * 0.5;
+
+    let row_w  = (ui.available_width() - tw - sp.0).max(50.0 * n as f32);
+    let comp_w = ((row_w - inner * n.saturating_sub(1) as f32) / n.max(1) as f32).max(1.0);
+    let pos = ui.layout_next(Vec2::new(row_w + sp.0 + tw, h))?;
+    let rects = (0..n)
+        .map(|i| Rect::from_min_size(Vec2::new(pos.x + i as f32 * (comp_w + inner), pos.y), Vec2::new(comp_w, h)))
+        .collect();
+    Some((rects, Vec2::new(pos.x + row_w + sp.0, pos.y + (h - fs) * 0.5)))
+}
+
+/// Draw the X/Y/Z/W tag at the left of a component box; returns its width.
+fn draw_component_tag(ui: &mut Ui<'_>, rect: Rect, component: Option<usize>) -> f32 {
+    let Some(&(tag, col)) = component.and_then(|c| COMPONENT_TAGS.get(c)) else { return 0.0 };
+    let fs = ui.ctx.style.font_size;
+    let w  = ui.text_width(tag) + 6.0;
+    let rounding = ui.ctx.style.frame_rounding;
+    ui.ctx.draw_list.filled_rect(Rect::from_min_size(rect.min, Vec2::new(w, rect.height())), rounding, col);
+    ui.draw_text(tag, Vec2::new(rect.min.x + 3.0, rect.min.y + (rect.height() - fs) * 0.5), Color::WHITE);
+    w
+}
+
+/// Result of one frame of in-place text entry.
+pub(crate) enum TextEntry {
+    /// Not editing.
+    Idle,
+    /// Still typing; the entry box has been drawn.
+    Editing,
+    /// Enter pressed or focus lost – the text to apply.
+    Commit(String),
+    /// Escape pressed.
+    Cancel,
+}
+
+/// Start editing `text` in place of widget `id`, all of it selected.
+pub(crate) fn begin_text_entry(ui: &mut Ui<'_>, id: Id, text: String) {
+    ui.ctx.focus_item = Some(id);
+    let st = ui.ctx.get_storage_mut(id);
+    st.open   = true;
+    st.active = true;
+    st.string = text;
+}
+
+/// Typing, committing and drawing for in-place text entry over `rect`.
+///
+/// Storage: `open` while typing, `string` the text being typed, `active`
+/// while that text is still selected in full.
+pub(crate) fn text_entry(ui: &mut Ui<'_>, id: Id, rect: Rect) -> TextEntry {
+    if !ui.ctx.get_storage(id).is_some_and(|s| s.open) { return TextEntry::Idle; }
+    let focused = ui.ctx.focus_item == Some(id);
+    let typed   = ui.ctx.input.text_input.clone();
+    let (enter, escape, back) = (
+        ui.ctx.input.key_pressed(Key::Enter),
+        ui.ctx.input.key_pressed(Key::Escape),
+        ui.ctx.input.key_pressed(Key::Backspace),
+    );
+    let st = ui.ctx.get_storage_mut(id);
+    if focused && !escape && (back || !typed.is_empty()) {
+        // The initial text is selected: the first edit replaces it
+        if std::mem::take(&mut st.active) { st.string.clear(); }
+        st.string.push_str(&typed);
+        if back { st.string.pop(); }
+    }
+    if !(escape || enter || !focused) {
+        let (buf, selected) = (st.string.clone(), st.active);
+        draw_text_entry(ui, rect, &buf, selected);
+        return TextEntry::Editing;
+    }
+
+    st.open = false;
+    let text = std::mem::take(&mut st.string);
+    if escape || enter { ui.ctx.focus_item = None; }
+    if escape { TextEntry::Cancel } else { TextEntry::Commit(text) }
+}
+
+/// Start typing over a numeric widget, pre-filled with the bare number.
+fn begin_value_entry<T: Numeric>(ui: &mut Ui<'_>, id: Id, v: T, format: &str) {
+    let body = parse_format(format)
+        .map_or_else(|| format_numeric(T::DEFAULT_FORMAT, v), |s| format_body(&FormatSpec { width: 0, ..s }, v));
+    begin_text_entry(ui, id, body.trim().to_owned());
+}
+
+/// [`text_entry`] parsing into `v`. Returns `(changed, editing)`.
+fn value_entry<T: Numeric>(ui: &mut Ui<'_>, id: Id, rect: Rect, v: &mut T, clamp: Option<(T, T)>) -> (bool, bool) {
+    match text_entry(ui, id, rect) {
+        TextEntry::Editing => (false, true),
+        TextEntry::Commit(text) => {
+            let Some(mut nv) = T::parse(text.trim()) else { return (false, false) };
+            if let Some((lo, hi)) = clamp { nv = clamp_to(nv, lo, hi); }
+            let changed = nv != *v;
+            *v = nv;
+            (changed, false)
+        }
+        TextEntry::Idle | TextEntry::Cancel => (false, false),
+    }
+}
+
+/// Clamp to the range between `a` and `b`, in either order.
+fn clamp_to<T: Numeric>(v: T, a: T, b: T) -> T {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    if v < lo { lo } else if v > hi { hi } else { v }
+}
+
+// ─── Sliders ─────────────────────────────────────────────────────────────────
+
+/// Range, format and flags shared by the slider variants.
+struct SliderArgs<'a, T> {
+    min:       T,
+    max:       T,
+    format:    &'a str,
+    flags:     SliderFlags,
+    component: Option<usize>,
+}
+
 pub fn slider_float(ui: &mut Ui<'_>, label: &str, v: &mut f32, min: f32, max: f32) -> bool {
+    slider_scalar(ui, label, v, min, max, f32::DEFAULT_FORMAT, SliderFlags::empty())
+}
+
+/// Horizontal slider over `min..=max` showing `format`.
+pub fn slider_scalar<T: Numeric>(
+    ui:     &mut Ui<'_>,
+    label:  &str,
+    v:      &mut T,
+    min:    T,
+    max:    T,
+    format: &str,
+    flags:  SliderFlags,
+) -> bool {
     let (text, id_src) = parse_label(label);
     let id = ui.ctx.make_id(id_src);
+    let Some((rects, lp)) = component_row(ui, text, 1) else { return false };
+    let changed = slider_behavior(ui, id, rects[0], false, v, SliderArgs { min, max, format, flags, component: None });
 
-    let fs  = ui.ctx.style.font_size;
-    let fp  = ui.ctx.style.frame_padding;
-    let h   = fs + fp.1 * 2.0;
-    let tw  = ui.text_width(text);
-    let sp  = ui.ctx.style.item_spacing;
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    ui.draw_text(text, lp, tc);
+    changed
+}
 
-    // Track width = available – label – spacing
-    let track_w = (ui.available_width() - tw - sp.0).max(50.0);
-    let total   = Vec2::new(track_w + sp.0 + tw, h);
+/// One slider per element of `v`, side by side on a single row.
+pub fn slider_scalar_n<T: Numeric>(
+    ui:     &mut Ui<'_>,
+    label:  &str,
+    v:      &mut [T],
+    min:    T,
+    max:    T,
+    format: &str,
+    flags:  SliderFlags,
+) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+    let Some((rects, lp)) = component_row(ui, text, v.len()) else { return false };
+    let mut changed = false;
+    for (i, (x, rect)) in v.iter_mut().zip(rects).enumerate() {
+        let args = SliderArgs { min, max, format, flags, component: Some(i) };
+        changed |= slider_behavior(ui, id.combine(Id::from_hash(&i)), rect, false, x, args);
+    }
 
-    let pos = match ui.layout_next(total) { Some(p) => p, None => return false };
-    let track_rect = Rect::from_min_size(pos, Vec2::new(track_w, h));
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    ui.draw_text(text, lp, tc);
+    changed
+}
 
-    // Interaction
-    let (hovered, held, _) = ui.ctx.button_behavior(id, track_rect);
-    let mut changed = false;
-    if held {
-        let t = ((ui.ctx.input.mouse_pos.x - track_rect.min.x) / track_w).clamp(0.0, 1.0);
-        let new_v = min + t * (max - min);
-        if (*v - new_v).abs() > f32::EPSILON {
-            *v = new_v;
+/// Vertical slider of `size`, minimum at the bottom.
+#[allow(clippy::too_many_arguments)]
+pub fn v_slider<T: Numeric>(
+    ui:     &mut Ui<'_>,
+    label:  &str,
+    size:   Vec2,
+    v:      &mut T,
+    min:    T,
+    max:    T,
+    format: &str,
+    flags:  SliderFlags,
+) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+
+    let fs = ui.ctx.style.font_size;
+    let sp = ui.ctx.style.item_spacing;
+    let tw = ui.text_width(text);
+    let label_w = if text.is_empty() { 0.0 } else { sp.0 + tw };
+
+    let pos = match ui.layout_next(Vec2::new(size.x + label_w, size.y)) { Some(p) => p, None => return false };
+    let track_rect = Rect::from_min_size(pos, size);
+    let changed = slider_behavior(ui, id, track_rect, true, v, SliderArgs { min, max, format, flags, component: None });
+
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    ui.draw_text(text, Vec2::new(track_rect.max.x + sp.0, pos.y + (size.y - fs) * 0.5), tc);
+    changed
+}
+
+/// Slider editing an angle in radians, shown in degrees.
+pub fn slider_angle(ui: &mut Ui<'_>, label: &str, v_rad: &mut f32, min_deg: f32, max_deg: f32) -> bool {
+    let mut deg = v_rad.to_degrees();
+    let changed = slider_scalar(ui, label, &mut deg, min_deg, max_deg, "%.0f deg", SliderFlags::empty());
+    if changed { *v_rad = deg.to_radians(); }
+    changed
+}
+
+/// Drag, text entry and drawing for a slider track.
+fn slider_behavior<T: Numeric>(ui: &mut Ui<'_>, id: Id, rect: Rect, vertical: bool, v: &mut T, args: SliderArgs<'_, T>) -> bool {
+    let SliderArgs { min, max, format, flags, component } = args;
+    let (hovered, held, _) = ui.ctx.button_behavior(id, rect);
+    let pressed = hovered && ui.ctx.input.mouse_clicked(MouseButton::Left);
+
+    let wants_input = pressed && (ui.ctx.input.ctrl() || ui.ctx.input.mouse_double_clicked(MouseButton::Left));
+    if wants_input && !flags.contains(SliderFlags::NO_INPUT) { begin_value_entry(ui, id, *v, format); }
+    let clamp = flags.contains(SliderFlags::ALWAYS_CLAMP).then_some((min, max));
+    let (mut changed, editing) = value_entry(ui, id, rect, v, clamp);
+    if editing { return changed; }
+
+    // ── Drag ─────────────────────────────────────────────────────────────────
+    let log = flags.contains(SliderFlags::LOGARITHMIC);
+    let eps = format_epsilon(format);
+    let fraction = |v: T| if log { log_unlerp(v.to_f64(), min.to_f64(), max.to_f64(), eps) } else { unlerp(min, max, v) };
+    let dragging = (held || pressed) && ui.ctx.input.mouse_down(MouseButton::Left) && !wants_input;
+    if dragging {
+        let m = ui.ctx.input.mouse_pos;
+        let t = if vertical {
+            1.0 - ((m.y - rect.min.y) / rect.height()).clamp(0.0, 1.0)
+        } else {
+            ((m.x - rect.min.x) / rect.width()).clamp(0.0, 1.0)
+        } as f64;
+        let mut nv = if log { T::from_f64(log_lerp(t, min.to_f64(), max.to_f64(), eps)) } else { lerp(min, max, t) };
+        if !flags.contains(SliderFlags::NO_ROUND_TO_FORMAT) { nv = round_to_format(format, nv); }
+        if nv != *v {
+            *v = nv;
             changed = true;
         }
     }
 
-    // Pre-compute text metrics before mutably borrowing draw_list
-    let val_str  = format!("{:.3}", v);
+    // ── Drawing ──────────────────────────────────────────────────────────────
+    let fs       = ui.ctx.style.font_size;
+    let val_str  = format_numeric(format, *v);
     let vw       = ui.text_width(&val_str);
     let tc       = ui.ctx.style.color(StyleColor::Text);
-    let vp       = Vec2::new(pos.x + (track_w - vw) * 0.5, pos.y + fp.1);
     let bg       = ui.ctx.style.color(StyleColor::FrameBg);
     let rounding = ui.ctx.style.frame_rounding;
-    let t        = if max != min { (*v - min) / (max - min) } else { 0.0 };
-    let filled_w = (track_w * t).max(0.0);
+    let t        = fraction(*v) as f32;
     let grab_sz  = ui.ctx.style.grab_min_size.max(6.0);
-    let grab_x   = (pos.x + filled_w - grab_sz * 0.5).clamp(pos.x, pos.x + track_w - grab_sz);
-    let grab_rect = Rect::from_min_size(Vec2::new(grab_x, pos.y + 1.0), Vec2::new(grab_sz, h - 2.0));
-    let fill_col  = if held { ui.ctx.style.color(StyleColor::SliderGrabActive) }
-                    else    { ui.ctx.style.color(StyleColor::SliderGrab).with_alpha(0.5) };
-    let grab_col  = if held { ui.ctx.style.color(StyleColor::SliderGrabActive) }
-                    else    { ui.ctx.style.color(StyleColor::SliderGrab) };
-
-    // Draw track
-    {
-        let draw = &mut ui.ctx.draw_list;
-        draw.filled_rect(track_rect, rounding, bg);
-        if filled_w > 0.0 {
-            let filled_rect = Rect::from_min_size(pos, Vec2::new(filled_w, h));
-            draw.filled_rect(filled_rect, rounding, fill_col);
-        }
-        draw.filled_rect(grab_rect, rounding, grab_col);
-    } // draw borrow ends
+    let active   = dragging;
+    let fill_col = if active { ui.ctx.style.color(StyleColor::SliderGrabActive) }
+                   else      { ui.ctx.style.color(StyleColor::SliderGrab).with_alpha(0.5) };
+    let grab_col = if active { ui.ctx.style.color(StyleColor::SliderGrabActive) }
+                   else      { ui.ctx.style.color(StyleColor::SliderGrab) };
 
+    ui.ctx.draw_list.filled_rect(rect, rounding, bg);
+    let tag_w = draw_component_tag(ui, rect, component);
+    let track = Rect::new(Vec2::new(rect.min.x + tag_w, rect.min.y), rect.max);
+    let (filled_rect, grab_rect) = if vertical {
+        let filled_h = track.height() * t;
+        let grab_y   = (track.max.y - filled_h - grab_sz * 0.5).clamp(track.min.y, track.max.y - grab_sz);
+        (
+            Rect::new(Vec2::new(track.min.x, track.max.y - filled_h), track.max),
+            Rect::from_min_size(Vec2::new(track.min.x + 1.0, grab_y), Vec2::new(track.width() - 2.0, grab_sz)),
+        )
+    } else {
+        let filled_w = track.width() * t;
+        let grab_x   = (track.min.x + filled_w - grab_sz * 0.5).clamp(track.min.x, track.max.x - grab_sz);
+        (
+            Rect::from_min_size(track.min, Vec2::new(filled_w, track.height())),
+            Rect::from_min_size(Vec2::new(grab_x, track.min.y + 1.0), Vec2::new(grab_sz, track.height() - 2.0)),
+        )
+    };
+
+    ui.ctx.draw_list.filled_rect(filled_rect, rounding, fill_col);
+    ui.ctx.draw_list.filled_rect(grab_rect, rounding, grab_col);
+    let vp = Vec2::new(track.min.x + (track.width() - vw) * 0.5, track.min.y + (track.height() - fs) * 0.5);
     ui.draw_text(&val_str, vp, tc);
 
-    // Label
-    let lp = Vec2::new(pos.x + track_w + sp.0, pos.y + (h - fs) * 0.5);
+    changed
+}
+
+/// The widget frame while a value is being typed.
+fn draw_text_entry(ui: &mut Ui<'_>, rect: Rect, buf: &str, selected: bool) {
+    let fs = ui.ctx.style.font_size;
+    let fp = ui.ctx.style.frame_padding;
+    let bg       = ui.ctx.style.color(StyleColor::FrameBgActive);
+    let border   = ui.ctx.style.color(StyleColor::SliderGrab);
+    let tc       = ui.ctx.style.color(StyleColor::Text);
+    let rounding = ui.ctx.style.frame_rounding;
+    ui.ctx.draw_list.filled_rect(rect, rounding, bg);
+    ui.ctx.draw_list.rect_outline(rect, 1.0, border);
+    let tp = Vec2::new(rect.min.x + fp.0, rect.min.y + (rect.height() - fs) * 0.5);
+    if selected {
+        let sel = ui.ctx.style.color(StyleColor::Header).with_alpha(0.45);
+        ui.ctx.draw_list.filled_rect(Rect::from_min_size(tp, Vec2::new(ui.text_width(buf), fs)), 0.0, sel);
+    }
+    ui.draw_text(buf, tp, tc);
+    if (ui.ctx.input.frame_count / 30) & 1 == 0 {
+        let cx = tp.x + ui.text_width(buf);
+        ui.ctx.draw_list.line(Vec2::new(cx, tp.y), Vec2::new(cx, tp.y + fs), 1.0, tc);
+    }
+}
+
+// ─── Drags ───────────────────────────────────────────────────────────────────
+
+/// Drag speed multipliers while Shift / Alt are held.
+const DRAG_FAST: f64 = 10.0;
+const DRAG_SLOW: f64 = 0.1;
+
+/// Speed, bounds and format shared by the drag variants.
+pub(crate) struct DragArgs<'a, T> {
+    pub(crate) speed:     f32,
+    pub(crate) clamp:     Option<(T, T)>,
+    pub(crate) format:    &'a str,
+    pub(crate) component: Option<usize>,
+}
+
+/// `min == max` means unbounded.
+fn drag_bounds<T: Numeric>(min: T, max: T) -> Option<(T, T)> {
+    (min != max).then_some((min, max))
+}
+
+pub fn drag_float(ui: &mut Ui<'_>, label: &str, v: &mut f32, speed: f32, min: f32, max: f32) -> bool {
+    drag_scalar(ui, label, v, speed, min, max, f32::DEFAULT_FORMAT)
+}
+
+/// Drag horizontally to change `v` by `speed` per pixel (Shift ×10,
+/// Alt ×0.1). `min == max` leaves it unbounded.
+pub fn drag_scalar<T: Numeric>(
+    ui:     &mut Ui<'_>,
+    label:  &str,
+    v:      &mut T,
+    speed:  f32,
+    min:    T,
+    max:    T,
+    format: &str,
+) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+    let Some((rects, lp)) = component_row(ui, text, 1) else { return false };
+    let args = DragArgs { speed, clamp: drag_bounds(min, max), format, component: None };
+    let changed = drag_behavior(ui, id, rects[0], v, args);
+
+    let tc = ui.ctx.style.color(StyleColor::Text);
     ui.draw_text(text, lp, tc);
+    changed
+}
 
+/// One drag box per element of `v`, side by side on a single row.
+pub fn drag_scalar_n<T: Numeric>(
+    ui:     &mut Ui<'_>,
+    label:  &str,
+    v:      &mut [T],
+    speed:  f32,
+    min:    T,
+    max:    T,
+    format: &str,
+) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+    let Some((rects, lp)) = component_row(ui, text, v.len()) else { return false };
+    let mut changed = false;
+    for (i, (x, rect)) in v.iter_mut().zip(rects).enumerate() {
+        let args = DragArgs { speed, clamp: drag_bounds(min, max), format, component: Some(i) };
+        changed |= drag_behavior(ui, id.combine(Id::from_hash(&i)), rect, x, args);
+    }
+
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    ui.draw_text(text, lp, tc);
     changed
 }
 
-pub fn drag_float(ui: &mut Ui<'_>, label: &str, v: &mut f32, speed: f32, min: f32, max: f32) -> bool {
+/// Two drag boxes editing a `v_min..=v_max` range; neither end can cross
+/// the other. `min == max` leaves the outer bounds open.
+pub fn drag_float_range2(
+    ui:    &mut Ui<'_>,
+    label: &str,
+    v_min: &mut f32,
+    v_max: &mut f32,
+    speed: f32,
+    min:   f32,
+    max:   f32,
+) -> bool {
     let (text, id_src) = parse_label(label);
     let id = ui.ctx.make_id(id_src);
+    let Some((rects, lp)) = component_row(ui, text, 2) else { return false };
+    let (lo, hi) = drag_bounds(min, max).unwrap_or((f32::MIN, f32::MAX));
+
+    let args = DragArgs { speed, clamp: Some((lo.min(*v_max), *v_max)), format: "Min: %.3f", component: None };
+    let mut changed = drag_behavior(ui, id.combine(Id::from_hash(&0usize)), rects[0], v_min, args);
+    let args = DragArgs { speed, clamp: Some((*v_min, hi.max(*v_min))), format: "Max: %.3f", component: None };
+    changed |= drag_behavior(ui, id.combine(Id::from_hash(&1usize)), rects[1], v_max, args);
+
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    ui.draw_text(text, lp, tc);
+    changed
+}
 
-    let fs  = ui.ctx.style.font_size;
-    let fp  = ui.ctx.style.frame_padding;
-    let h   = fs + fp.1 * 2.0;
-    let tw  = ui.text_width(text);
-    let sp  = ui.ctx.style.item_spacing;
-    let box_w = (ui.available_width() - tw - sp.0).max(50.0);
-    let total = Vec2::new(box_w + sp.0 + tw, h);
+/// Drag, text entry and drawing for one drag box.
+///
+/// Storage: `float[0]` is the drag distance not yet applied – integers and
+/// rounded floats only move once it adds up to a whole step.
+pub(crate) fn drag_behavior<T: Numeric>(ui: &mut Ui<'_>, id: Id, rect: Rect, v: &mut T, args: DragArgs<'_, T>) -> bool {
+    let DragArgs { speed, clamp, format, component } = args;
+    let (hovered, held, _) = ui.ctx.button_behavior(id, rect);
+    let pressed = hovered && ui.ctx.input.mouse_clicked(MouseButton::Left);
 
-    let pos = match ui.layout_next(total) { Some(p) => p, None => return false };
-    let box_rect = Rect::from_min_size(pos, Vec2::new(box_w, h));
+    let wants_input = pressed && (ui.ctx.input.ctrl() || ui.ctx.input.mouse_double_clicked(MouseButton::Left));
+    if wants_input { begin_value_entry(ui, id, *v, format); }
+    let (mut changed, editing) = value_entry(ui, id, rect, v, clamp);
+    if editing { return changed; }
 
-    let (hovered, held, _) = ui.ctx.button_behavior(id, box_rect);
-    let mut changed = false;
-    if held {
-        let delta = ui.ctx.input.mouse_delta.x * speed;
-        if delta.abs() > f32::EPSILON {
-            *v = (*v + delta).clamp(if min == max { f32::NEG_INFINITY } else { min },
-                                    if min == max { f32::INFINITY    } else { max });
+    let dragging = (held || pressed) && ui.ctx.input.mouse_down(MouseButton::Left) && !wants_input;
+    if pressed { ui.ctx.get_storage_mut(id).float[0] = 0.0; }
+    if dragging {
+        let input = &ui.ctx.input;
+        let mult  = if input.shift() { DRAG_FAST } else if input.alt() { DRAG_SLOW } else { 1.0 };
+        let pending = ui.ctx.get_storage(id).map_or(0.0, |s| s.float[0]) as f64
+            + input.mouse_delta.x as f64 * speed as f64 * mult;
+
+        let mut nv = if T::IS_FLOAT {
+            round_to_format(format, T::from_f64(v.to_f64() + pending))
+        } else {
+            T::from_i128(v.to_i128() + pending.trunc() as i128)
+        };
+        if let Some((lo, hi)) = clamp { nv = clamp_to(nv, lo, hi); }
+        let applied = if T::IS_FLOAT { nv.to_f64() - v.to_f64() } else { (nv.to_i128() - v.to_i128()) as f64 };
+        // Drop what a bound swallowed so reversing responds immediately
+        let rest = if nv == *v && clamp.is_some_and(|(lo, hi)| nv == lo || nv == hi) { 0.0 } else { pending - applied };
+        ui.ctx.get_storage_mut(id).float[0] = rest as f32;
+        if nv != *v {
+            *v = nv;
             changed = true;
         }
     }
 
-    let bg       = if hovered { ui.ctx.style.color(StyleColor::FrameBgHovered) }
-                   else       { ui.ctx.style.color(StyleColor::FrameBg) };
+    // ── Drawing ──────────────────────────────────────────────────────────────
+    let fs       = ui.ctx.style.font_size;
+    let fp       = ui.ctx.style.frame_padding;
+    let bg       = if dragging     { ui.ctx.style.color(StyleColor::FrameBgActive) }
+                   else if hovered { ui.ctx.style.color(StyleColor::FrameBgHovered) }
+                   else            { ui.ctx.style.color(StyleColor::FrameBg) };
     let rounding = ui.ctx.style.frame_rounding;
     let border   = ui.ctx.style.color(StyleColor::Border);
-    let val_str  = format!("{:.3}", v);
     let tc       = ui.ctx.style.color(StyleColor::Text);
-    let vp       = Vec2::new(pos.x + fp.0, pos.y + (h - fs) * 0.5);
+    let val_str  = format_numeric(format, *v);
 
-    {
-        let draw = &mut ui.ctx.draw_list;
-        draw.filled_rect(box_rect, rounding, bg);
-        draw.rect_outline(box_rect, 1.0, border);
-    } // draw borrow ends
-
-    ui.draw_text(&val_str, vp, tc);
-
-    let lp = Vec2::new(pos.x + box_w + sp.0, pos.y + (h - fs) * 0.5);
-    ui.draw_text(text, lp, tc);
+    ui.ctx.draw_list.filled_rect(rect, rounding, bg);
+    ui.ctx.draw_list.rect_outline(rect, 1.0, border);
+    let tag_w = draw_component_tag(ui, rect, component);
+    ui.draw_text(&val_str, Vec2::new(rect.min.x + tag_w + fp.0, rect.min.y + (rect.height() - fs) * 0.5), tc);
 
     changed
 }
@@ -0,0 +1,929 @@
+//! Tables – `begin_table` … `end_table` with resizable, reorderable,
+//! hideable and sortable columns.
+//!
+//! ```ignore
+//! if ui.begin_table("assets", 3, TableFlags::RESIZABLE | TableFlags::SORTABLE, Vec2::ZERO) {
+//!     ui.table_setup_column("Name", TableColumnFlags::DEFAULT_SORT, 0.0);
+//!     ui.table_setup_column("Size", TableColumnFlags::WIDTH_FIXED, 80.0);
+//!     ui.table_setup_column("Kind", TableColumnFlags::empty(), 0.0);
+//!     if let Some(specs) = ui.table_sort_specs() {
+//!         if specs.dirty { sort_assets(&mut assets, &specs); }
+//!     }
+//!     ui.table_headers_row();
+//!     for a in &assets {
+//!         ui.table_next_row();
+//!         ui.table_next_column(); ui.text(&a.name);
+//!         ui.table_next_column(); ui.text(&a.size);
+//!         ui.table_next_column(); ui.text(&a.kind);
+//!     }
+//!     ui.end_table();
+//! }
+//! ```
+//!
+//! Cells are submitted in column-index order; reordering only changes where
+//! a column is drawn. Column widths, order, visibility and sort order are
+//! kept on the [`Context`](crate::Context) and written by `save_settings`.
+
+use crate::{
+    id::{parse_label, Id},
+    input::MouseButton,
+    layout::Layout,
+    style::StyleColor,
+    ui::Ui,
+    Rect, Vec2,
+};
+
+bitflags::bitflags! {
+    /// Table-wide behaviour and decoration.
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct TableFlags: u32 {
+        /// Drag column borders to resize.
+        const RESIZABLE         = 1 << 0;
+        /// Drag headers to reorder columns.
+        const REORDERABLE       = 1 << 1;
+        /// Right-click a header for a menu that shows / hides columns.
+        const HIDEABLE          = 1 << 2;
+        /// Click headers to sort; read the result with `table_sort_specs`.
+        const SORTABLE          = 1 << 3;
+        /// Shift+click adds secondary sort columns.
+        const SORT_MULTI        = 1 << 4;
+        /// Alternate `TableRowBg` / `TableRowBgAlt` behind body rows.
+        const ROW_BG            = 1 << 5;
+        const BORDERS_INNER_H   = 1 << 6;
+        const BORDERS_OUTER_H   = 1 << 7;
+        const BORDERS_INNER_V   = 1 << 8;
+        const BORDERS_OUTER_V   = 1 << 9;
+        const BORDERS_H         = Self::BORDERS_INNER_H.bits() | Self::BORDERS_OUTER_H.bits();
+        const BORDERS_V         = Self::BORDERS_INNER_V.bits() | Self::BORDERS_OUTER_V.bits();
+        const BORDERS_INNER     = Self::BORDERS_INNER_H.bits() | Self::BORDERS_INNER_V.bits();
+        const BORDERS_OUTER     = Self::BORDERS_OUTER_H.bits() | Self::BORDERS_OUTER_V.bits();
+        const BORDERS           = Self::BORDERS_INNER.bits() | Self::BORDERS_OUTER.bits();
+        /// Keep this table out of `Context::save_settings`.
+        const NO_SAVED_SETTINGS = 1 << 10;
+        /// Horizontal scrolling (Shift+wheel); columns default to fixed width.
+        const SCROLL_X          = 1 << 11;
+        /// Vertical scrolling inside `outer_size.y`.
+        const SCROLL_Y          = 1 << 12;
+    }
+}
+
+bitflags::bitflags! {
+    /// Per-column options for `table_setup_column`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct TableColumnFlags: u32 {
+        /// Hidden until enabled from the header context menu.
+        const DEFAULT_HIDE           = 1 << 0;
+        /// Initial sort column.
+        const DEFAULT_SORT           = 1 << 1;
+        /// Width is `init_width_or_weight` (or fits the header).
+        const WIDTH_FIXED            = 1 << 2;
+        /// Shares leftover width, weighted by `init_width_or_weight`.
+        const WIDTH_STRETCH          = 1 << 3;
+        const NO_RESIZE              = 1 << 4;
+        const NO_REORDER             = 1 << 5;
+        const NO_HIDE                = 1 << 6;
+        const NO_SORT                = 1 << 7;
+        /// First click sorts descending.
+        const PREFER_SORT_DESCENDING = 1 << 8;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortDirection { Ascending, Descending }
+
+impl SortDirection {
+    fn flipped(self) -> Self {
+        match self { Self::Ascending => Self::Descending, Self::Descending => Self::Ascending }
+    }
+}
+
+/// One entry of the sort order, most significant first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableColumnSortSpec {
+    pub column_index: usize,
+    pub direction:    SortDirection,
+}
+
+/// Sort order returned by `table_sort_specs`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableSortSpecs {
+    pub specs: Vec<TableColumnSortSpec>,
+    /// `true` when the order changed since the last call – re-sort then.
+    pub dirty: bool,
+}
+
+const MIN_COLUMN_WIDTH: f32 = 24.0;
+const CELL_PADDING:     Vec2 = Vec2 { x: 4.0, y: 2.0 };
+/// Half-width of the grab zone on a resizable column border.
+const RESIZE_GRAB:      f32 = 4.0;
+/// Where hidden columns lay out their (clipped) content.
+const HIDDEN_X:         f32 = -100_000.0;
+
+// ─── Persistent state ────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ColumnState {
+    /// User-set width; `0` means automatic.
+    pub width:   f32,
+    pub visible: bool,
+}
+
+/// Per-table state kept across frames (and in settings).
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TableState {
+    pub columns: Vec<ColumnState>,
+    /// Display position → column index.
+    pub order:   Vec<usize>,
+    pub sort:    Vec<(usize, SortDirection)>,
+    /// Columns, order and sort came from `load_settings` and are not yet
+    /// reconciled with `table_setup_column`.
+    pub loaded:  bool,
+    pub save:    bool,
+    sort_dirty:  bool,
+    ready:       bool,
+    scroll:      Vec2,
+    /// Content size last frame, for scroll clamping.
+    content:     Vec2,
+    /// Table height last frame, for the resize grab zones.
+    height:      f32,
+    /// Header context menu position while open.
+    menu:        Option<Vec2>,
+    /// Column being dragged by its header, and whether it moved.
+    drag:        Option<(usize, bool)>,
+    /// Column whose right border is being dragged.
+    resizing:    Option<usize>,
+}
+
+// ─── Per-frame state ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone)]
+struct ColumnSetup {
+    label:  String,
+    flags:  TableColumnFlags,
+    init:   f32,
+}
+
+/// The table being submitted; lives on `Context::table_stack`.
+#[derive(Debug)]
+pub(crate) struct TableFrame {
+    id:        Id,
+    flags:     TableFlags,
+    count:     usize,
+    outer:     Rect,
+    fixed_h:   bool,
+    setup:     Vec<ColumnSetup>,
+    freeze:    (usize, usize),
+    laid_out:  bool,
+    /// Per column index: unscrolled x and width.
+    col_x:     Vec<f32>,
+    col_w:     Vec<f32>,
+    /// Per column index: display position.
+    col_pos:   Vec<usize>,
+    /// Right edge of the frozen columns.
+    frozen_x:  f32,
+    scroll:    Vec2,
+    inner:     Rect,
+    row:       Option<usize>,
+    /// A row has been started and not yet closed.
+    row_open:  bool,
+    header:    bool,
+    body_rows: usize,
+    row_top:   f32,
+    row_h:     f32,
+    row_bg:    Option<usize>,
+    /// Top of the first scrolling row.
+    body_top:  f32,
+    cell:      Option<usize>,
+    saved:     Layout,
+}
+
+impl TableFrame {
+    fn row_frozen(&self) -> bool { self.row.is_some_and(|r| r < self.freeze.1) }
+    fn col_frozen(&self, c: usize) -> bool { self.col_pos[c] < self.freeze.0 }
+
+    /// Row top on screen.
+    fn row_y(&self) -> f32 {
+        if self.row_frozen() { self.row_top } else { self.row_top - self.scroll.y }
+    }
+
+    /// Column left edge on screen.
+    fn col_screen_x(&self, c: usize) -> f32 {
+        if self.col_frozen(c) { self.col_x[c] } else { self.col_x[c] - self.scroll.x }
+    }
+
+    /// Visible y range for the current row.
+    fn clip_y(&self) -> (f32, f32) {
+        let top = if self.row_frozen() { self.inner.min.y } else { self.body_top.max(self.inner.min.y) };
+        let bot = if self.fixed_h { self.inner.max.y } else { f32::MAX };
+        (top, bot)
+    }
+
+    /// Visible x range for column `c`.
+    fn clip_x(&self, c: usize) -> (f32, f32) {
+        let left = if self.col_frozen(c) { self.inner.min.x } else { self.frozen_x };
+        let x    = self.col_screen_x(c);
+        (x.max(left), (x + self.col_w[c]).min(self.inner.max.x))
+    }
+}
+
+fn current<'a>(ui: &'a mut Ui<'_>) -> Option<&'a mut TableFrame> { ui.ctx.table_stack.last_mut() }
+
+fn state<'a>(ui: &'a mut Ui<'_>, id: Id) -> &'a mut TableState {
+    ui.ctx.tables.entry(id).or_default()
+}
+
+// ─── Begin / setup ───────────────────────────────────────────────────────────
+
+/// Start a table with `columns` columns. `outer_size` components `<= 0`
+/// mean "available width" and "fit the rows". Call `end_table` only if
+/// this returns `true`.
+pub fn begin_table(ui: &mut Ui<'_>, str_id: &str, columns: usize, flags: TableFlags, outer_size: Vec2) -> bool {
+    if columns == 0 { return false; }
+    let id = ui.ctx.make_id(str_id);
+    let Some(saved) = ui.ctx.current_layout_mut().map(|l| l.clone()) else { return false };
+
+    let w       = if outer_size.x > 0.0 { outer_size.x } else { saved.available_width() };
+    let fixed_h = outer_size.y > 0.0;
+    let outer   = Rect::from_min_size(saved.cursor, Vec2::new(w, if fixed_h { outer_size.y } else { 0.0 }));
+
+    let st = state(ui, id);
+    st.save = !flags.contains(TableFlags::NO_SAVED_SETTINGS);
+
+    ui.ctx.table_stack.push(TableFrame {
+        id, flags, count: columns, outer, fixed_h,
+        setup:     Vec::with_capacity(columns),
+        freeze:    (0, 0),
+        laid_out:  false,
+        col_x:     vec![0.0; columns],
+        col_w:     vec![0.0; columns],
+        col_pos:   (0..columns).collect(),
+        frozen_x:  outer.min.x,
+        scroll:    Vec2::ZERO,
+        inner:     outer,
+        row:       None,
+        row_open:  false,
+        header:    false,
+        body_rows: 0,
+        row_top:   outer.min.y,
+        row_h:     0.0,
+        row_bg:    None,
+        body_top:  outer.min.y,
+        cell:      None,
+        saved,
+    });
+    true
+}
+
+/// Describe the next column. `init_width_or_weight` is a width for fixed
+/// columns and a share of the leftover space for stretch columns (`0` =
+/// default).
+pub fn table_setup_column(ui: &mut Ui<'_>, label: &str, flags: TableColumnFlags, init_width_or_weight: f32) {
+    let Some(t) = current(ui) else { return };
+    if t.setup.len() < t.count {
+        t.setup.push(ColumnSetup { label: label.to_owned(), flags, init: init_width_or_weight });
+    }
+}
+
+/// Keep the first `cols` columns and `rows` rows in place while the rest
+/// scroll (needs `SCROLL_X` / `SCROLL_Y`).
+pub fn table_setup_scroll_freeze(ui: &mut Ui<'_>, cols: usize, rows: usize) {
+    if let Some(t) = current(ui) { t.freeze = (cols, rows); }
+}
+
+/// Current sort order, or `None` for tables without `SORTABLE`.
+/// `dirty` is reported once per change.
+pub fn table_sort_specs(ui: &mut Ui<'_>) -> Option<TableSortSpecs> {
+    let t = current(ui)?;
+    if !t.flags.contains(TableFlags::SORTABLE) { return None; }
+    let id = t.id;
+    layout_columns(ui);
+    let st = state(ui, id);
+    let dirty = std::mem::take(&mut st.sort_dirty);
+    Some(TableSortSpecs {
+        specs: st.sort.iter().map(|&(column_index, direction)| TableColumnSortSpec { column_index, direction }).collect(),
+        dirty,
+    })
+}
+
+/// Reconcile persistent state with the column setup and compute this
+/// frame's column geometry. Runs once, before the first row.
+fn layout_columns(ui: &mut Ui<'_>) {
+    let Some(t) = ui.ctx.table_stack.last() else { return };
+    if t.laid_out { return; }
+    let (id, count, flags, outer) = (t.id, t.count, t.flags, t.outer);
+
+    // Columns that were never set up get defaults
+    let mut setup = t.setup.clone();
+    while setup.len() < count {
+        setup.push(ColumnSetup { label: String::new(), flags: TableColumnFlags::empty(), init: 0.0 });
+    }
+
+    let fs      = ui.ctx.style.font_size;
+    let sb      = ui.ctx.style.scrollbar_size;
+    let headers: Vec<f32> = setup.iter().map(|s| ui.text_width(parse_label(&s.label).0)).collect();
+
+    let st = state(ui, id);
+    let fresh = !st.ready || st.columns.len() != count || st.order.len() != count;
+    if fresh {
+        let keep = st.loaded && st.columns.len() == count && st.order.len() == count;
+        if !keep {
+            st.columns = setup.iter()
+                .map(|s| ColumnState { width: 0.0, visible: !s.flags.contains(TableColumnFlags::DEFAULT_HIDE) })
+                .collect();
+            st.order = (0..count).collect();
+            st.sort  = setup.iter().enumerate()
+                .filter(|(_, s)| s.flags.contains(TableColumnFlags::DEFAULT_SORT))
+                .map(|(i, s)| (i, default_direction(s.flags)))
+                .collect();
+        }
+        st.sort.retain(|&(c, _)| c < count);
+        if !flags.contains(TableFlags::SORT_MULTI) { st.sort.truncate(1); }
+        if !st.columns.iter().any(|c| c.visible) { st.columns[0].visible = true; }
+        st.loaded     = false;
+        st.ready      = true;
+        st.sort_dirty = true;
+    }
+    if flags.contains(TableFlags::SORTABLE) && st.sort.is_empty() {
+        // A sortable table always has an order; default to the first sortable column
+        if let Some(c) = setup.iter().position(|s| !s.flags.contains(TableColumnFlags::NO_SORT)) {
+            st.sort.push((c, default_direction(setup[c].flags)));
+            st.sort_dirty = true;
+        }
+    }
+
+    // ── Scroll region ────────────────────────────────────────────────────────
+    let scroll_x = flags.contains(TableFlags::SCROLL_X);
+    let scroll_y = flags.contains(TableFlags::SCROLL_Y) && outer.height() > 0.0;
+    let bar_x    = scroll_x && st.content.x > outer.width() - if scroll_y { sb } else { 0.0 };
+    let bar_y    = scroll_y && st.content.y > outer.height() - if bar_x { sb } else { 0.0 };
+    let inner    = Rect::new(
+        outer.min,
+        Vec2::new(outer.max.x - if bar_y { sb } else { 0.0 }, if outer.height() > 0.0 { outer.max.y - if bar_x { sb } else { 0.0 } } else { outer.max.y }),
+    );
+
+    // ── Widths ───────────────────────────────────────────────────────────────
+    let order: Vec<usize> = st.order.clone();
+    let visible: Vec<usize> = order.iter().copied().filter(|&c| st.columns[c].visible).collect();
+    let mut col_w = vec![0.0; count];
+    let mut fixed_total = 0.0;
+    let mut weights     = 0.0;
+    for &c in &visible {
+        let s = &setup[c];
+        let user = st.columns[c].width;
+        let is_fixed = s.flags.contains(TableColumnFlags::WIDTH_FIXED)
+            || (scroll_x && !s.flags.contains(TableColumnFlags::WIDTH_STRETCH));
+        if user > 0.0 {
+            col_w[c] = user;
+            fixed_total += user;
+        } else if is_fixed {
+            let w = if s.init > 0.0 { s.init } else { headers[c] + CELL_PADDING.x * 2.0 + fs };
+            col_w[c] = w.max(MIN_COLUMN_WIDTH);
+            fixed_total += col_w[c];
+        } else {
+            col_w[c] = -(if s.init > 0.0 { s.init } else { 1.0 });
+            weights += -col_w[c];
+        }
+    }
+    let leftover = (inner.width() - fixed_total).max(0.0);
+    for &c in &visible {
+        if col_w[c] < 0.0 {
+            col_w[c] = (leftover * -col_w[c] / weights).floor().max(MIN_COLUMN_WIDTH);
+        }
+    }
+
+    let mut col_x   = vec![HIDDEN_X; count];
+    let mut col_pos = vec![usize::MAX; count];
+    let mut x = inner.min.x;
+    for (pos, &c) in order.iter().enumerate() {
+        col_pos[c] = pos;
+        if st.columns[c].visible {
+            col_x[c] = x;
+            x += col_w[c];
+        }
+    }
+    let content_w = x - inner.min.x;
+    let scroll = if scroll_x || scroll_y {
+        let max = Vec2::new(
+            if scroll_x { (content_w - inner.width()).max(0.0) } else { 0.0 },
+            if scroll_y { (st.content.y - inner.height()).max(0.0) } else { 0.0 },
+        );
+        st.scroll = st.scroll.clamp(Vec2::ZERO, max);
+        st.scroll
+    } else {
+        Vec2::ZERO
+    };
+    st.content.x = content_w;
+    let height = st.height;
+
+    let t = ui.ctx.table_stack.last_mut().unwrap();
+    let freeze_cols = t.freeze.0.min(visible.len());
+    let frozen_x = visible.iter().take(freeze_cols).fold(inner.min.x, |acc, &c| acc + col_w[c]);
+    // `freeze.0` counts visible columns; store it as a display position
+    t.freeze.0 = visible.get(freeze_cols).map_or(count, |&c| col_pos[c]);
+    t.setup    = setup;
+    t.col_w    = col_w;
+    t.col_x    = col_x;
+    t.col_pos  = col_pos;
+    t.frozen_x = if freeze_cols > 0 { frozen_x } else { inner.min.x };
+    t.scroll   = scroll;
+    t.inner    = inner;
+    t.laid_out = true;
+
+    if flags.contains(TableFlags::RESIZABLE) {
+        resize_columns(ui, &visible, height);
+    }
+}
+
+fn default_direction(flags: TableColumnFlags) -> SortDirection {
+    if flags.contains(TableColumnFlags::PREFER_SORT_DESCENDING) { SortDirection::Descending } else { SortDirection::Ascending }
+}
+
+/// Drag handles on the right border of each visible, resizable column.
+fn resize_columns(ui: &mut Ui<'_>, visible: &[usize], height: f32) {
+    let t = ui.ctx.table_stack.last().unwrap();
+    let id = t.id;
+    let mut edges = Vec::new();
+    for (i, &c) in visible.iter().enumerate() {
+        let no_resize = t.setup[c].flags.contains(TableColumnFlags::NO_RESIZE);
+        // The last column of a non-scrolling table has no right neighbour to give way
+        let last = i + 1 == visible.len() && !t.flags.contains(TableFlags::SCROLL_X);
+        if no_resize || last { continue; }
+        let x = t.col_screen_x(c) + t.col_w[c];
+        if x < t.inner.min.x || x > t.inner.max.x { continue; }
+        edges.push((c, x, t.col_x[c]));
+    }
+    let top = t.outer.min.y;
+    let h   = height.max(ui.ctx.style.font_size);
+
+    let down = ui.ctx.input.mouse_down(MouseButton::Left);
+    if !down { state(ui, id).resizing = None; }
+
+    let mut resized = None;
+    for (c, x, left) in edges {
+        let grab    = Rect::new(Vec2::new(x - RESIZE_GRAB, top), Vec2::new(x + RESIZE_GRAB, top + h));
+        let edge_id = id.combine(Id::from_str("##resize")).combine(Id::from_hash(&c));
+        let (hovered, _, _) = ui.ctx.button_behavior(edge_id, grab);
+        if hovered && ui.ctx.input.mouse_clicked(MouseButton::Left) {
+            state(ui, id).resizing = Some(c);
+        }
+        let dragging = down && state(ui, id).resizing == Some(c);
+        if dragging {
+            let t = ui.ctx.table_stack.last().unwrap();
+            let scroll = if t.col_frozen(c) { 0.0 } else { t.scroll.x };
+            resized = Some((c, (ui.ctx.input.mouse_pos.x + scroll - left).max(MIN_COLUMN_WIDTH)));
+        }
+        if hovered || dragging {
+            let col = ui.ctx.style.color(StyleColor::SliderGrab);
+            ui.ctx.draw_list.line(Vec2::new(x, top), Vec2::new(x, top + h), 2.0, col);
+        }
+    }
+    if let Some((c, w)) = resized {
+        state(ui, id).columns[c].width = w;
+        let t = ui.ctx.table_stack.last_mut().unwrap();
+        let delta = w - t.col_w[c];
+        t.col_w[c] = w;
+        let pos = t.col_pos[c];
+        for o in 0..t.count {
+            if t.col_pos[o] > pos && t.col_x[o] != HIDDEN_X { t.col_x[o] += delta; }
+        }
+    }
+}
+
+// ─── Rows and cells ──────────────────────────────────────────────────────────
+
+/// Start a new row. Cells are entered with `table_next_column`.
+pub fn table_next_row(ui: &mut Ui<'_>) {
+    next_row(ui, false);
+}
+
+fn next_row(ui: &mut Ui<'_>, header: bool) {
+    layout_columns(ui);
+    end_row(ui);
+    let fs = ui.ctx.style.font_size;
+    let Some(t) = current(ui) else { return };
+    t.row      = Some(t.row.map_or(0, |r| r + 1));
+    t.row_open = true;
+    t.header   = header;
+    t.row_h  = fs + CELL_PADDING.y * 2.0;
+    if t.row == Some(t.freeze.1) { t.body_top = t.row_top; }
+
+    // Reserve the row background under the cells
+    let needs_bg = header || t.flags.contains(TableFlags::ROW_BG);
+    let (top, bot) = t.clip_y();
+    let clip = Rect::new(Vec2::new(t.inner.min.x, top), Vec2::new(t.inner.max.x, bot));
+    if needs_bg {
+        ui.ctx.draw_list.push_clip_rect(clip);
+        let at = ui.ctx.draw_list.reserve_rect();
+        ui.ctx.draw_list.pop_clip_rect();
+        if let Some(t) = current(ui) { t.row_bg = Some(at); }
+    }
+}
+
+/// Close the current cell (if any) and fold its height into the row.
+fn end_cell(ui: &mut Ui<'_>) {
+    let Some(t) = ui.ctx.table_stack.last() else { return };
+    if t.cell.is_none() { return; }
+    let top = t.row_y();
+    let used = ui.ctx.current_layout_mut().map_or(0.0, |l| l.content_max.y - top);
+    ui.ctx.draw_list.pop_clip_rect();
+    let t = current(ui).unwrap();
+    t.row_h = t.row_h.max(used + CELL_PADDING.y);
+    t.cell  = None;
+}
+
+/// Close the current row: paint its background and horizontal border.
+fn end_row(ui: &mut Ui<'_>) {
+    end_cell(ui);
+    let Some(t) = ui.ctx.table_stack.last() else { return };
+    let Some(row) = t.row.filter(|_| t.row_open) else { return };
+    let rect = Rect::from_min_size(Vec2::new(t.inner.min.x, t.row_y()), Vec2::new(t.inner.width(), t.row_h));
+    let (header, flags, body_rows, row_bg) = (t.header, t.flags, t.body_rows, t.row_bg);
+    let (top, bot) = t.clip_y();
+    let last_frozen = row + 1 == t.freeze.1;
+
+    if let Some(at) = row_bg {
+        let col = if header {
+            StyleColor::TableHeaderBg
+        } else if body_rows & 1 == 1 {
+            StyleColor::TableRowBgAlt
+        } else {
+            StyleColor::TableRowBg
+        };
+        let col = ui.ctx.style.color(col);
+        ui.ctx.draw_list.patch_rect(at, rect, col);
+    }
+    let border = if header || last_frozen { StyleColor::TableBorderStrong } else { StyleColor::TableBorderLight };
+    if flags.contains(TableFlags::BORDERS_INNER_H) || header || last_frozen {
+        let col = ui.ctx.style.color(border);
+        let y   = rect.max.y;
+        if y >= top && y <= bot {
+            ui.ctx.draw_list.line(Vec2::new(rect.min.x, y), Vec2::new(rect.max.x, y), 1.0, col);
+        }
+    }
+
+    let t = current(ui).unwrap();
+    t.row_top += t.row_h;
+    t.row_bg   = None;
+    t.row_open = false;
+    if !header { t.body_rows += 1; }
+}
+
+/// For `ListClipper`: the unscrolled top of the next row and the visible y
+/// range in the same coordinates. Closes the current row.
+pub(crate) fn clipper_frame(ui: &mut Ui<'_>) -> Option<(f32, f32, f32)> {
+    layout_columns(ui);
+    end_row(ui);
+    let vis = ui.ctx.visible_rect();
+    let t = ui.ctx.table_stack.last()?;
+    let (top, bot) = if t.fixed_h {
+        (t.body_top.max(t.inner.min.y).max(vis.min.y), t.inner.max.y.min(vis.max.y))
+    } else {
+        (vis.min.y, vis.max.y)
+    };
+    Some((t.row_top, top + t.scroll.y, bot + t.scroll.y))
+}
+
+/// For `ListClipper`: account for `rows` body rows of height `pitch` that
+/// were not submitted.
+pub(crate) fn clipper_skip(ui: &mut Ui<'_>, rows: usize, pitch: f32) {
+    end_row(ui);
+    let Some(t) = current(ui) else { return };
+    if rows == 0 { return; }
+    let first = t.row.map_or(0, |r| r + 1);
+    if first <= t.freeze.1 && t.freeze.1 < first + rows {
+        t.body_top = t.row_top + (t.freeze.1 - first) as f32 * pitch;
+    }
+    t.row       = Some(first + rows - 1);
+    t.row_top  += rows as f32 * pitch;
+    t.body_rows += rows;
+}
+
+/// Move to the next cell, starting a new row after the last column.
+/// Returns `false` if the column is hidden (its content is clipped away).
+pub fn table_next_column(ui: &mut Ui<'_>) -> bool {
+    let Some(t) = ui.ctx.table_stack.last() else { return false };
+    let next = match (t.row, t.cell) {
+        (None, _)                          => None,
+        (Some(_), None)                    => Some(0),
+        (Some(_), Some(c)) if c + 1 < t.count => Some(c + 1),
+        _                                  => None,
+    };
+    match next {
+        Some(c) => enter_cell(ui, c),
+        None    => { next_row(ui, false); enter_cell(ui, 0) }
+    }
+}
+
+/// Jump to `column` in the current row. Returns `false` if it is hidden.
+pub fn table_set_column_index(ui: &mut Ui<'_>, column: usize) -> bool {
+    let Some(t) = ui.ctx.table_stack.last() else { return false };
+    if column >= t.count { return false; }
+    if t.row.is_none() { next_row(ui, false); }
+    enter_cell(ui, column)
+}
+
+fn enter_cell(ui: &mut Ui<'_>, c: usize) -> bool {
+    end_cell(ui);
+    let Some(t) = current(ui) else { return false };
+    t.cell = Some(c);
+    let visible = t.col_x[c] != HIDDEN_X;
+    let x   = if visible { t.col_screen_x(c) } else { HIDDEN_X };
+    let y   = t.row_y();
+    let w   = t.col_w[c];
+    let (cx0, cx1) = if visible { t.clip_x(c) } else { (0.0, 0.0) };
+    let (cy0, cy1) = t.clip_y();
+    let clip = Rect::new(Vec2::new(cx0, cy0), Vec2::new(cx1.max(cx0), cy1));
+
+    ui.ctx.draw_list.push_clip_rect(clip);
+    if let Some(l) = ui.ctx.current_layout_mut() {
+        let start = Vec2::new(x + CELL_PADDING.x, y + CELL_PADDING.y);
+        l.start_x       = start.x;
+        l.indent        = 0.0;
+        l.cursor        = start;
+        l.last_item_max = start;
+        l.content_max   = start;
+        l.content_size  = Vec2::new((w - CELL_PADDING.x * 2.0).max(1.0), f32::MAX);
+        l.reset_line();
+    }
+    visible
+}
+
+// ─── Headers ─────────────────────────────────────────────────────────────────
+
+/// Submit a header row from the `table_setup_column` labels.
+pub fn table_headers_row(ui: &mut Ui<'_>) {
+    next_row(ui, true);
+    let Some(t) = ui.ctx.table_stack.last() else { return };
+    let labels: Vec<String> = t.setup.iter().map(|s| s.label.clone()).collect();
+    for (c, label) in labels.iter().enumerate() {
+        if !table_set_column_index(ui, c) { continue; }
+        table_header(ui, label);
+    }
+}
+
+/// Header cell for the current column: click to sort, drag to reorder,
+/// right-click for the column menu.
+pub fn table_header(ui: &mut Ui<'_>, label: &str) {
+    let Some(t) = ui.ctx.table_stack.last() else { return };
+    let Some(c) = t.cell else { return };
+    let (id, flags) = (t.id, t.flags);
+    let col_flags = t.setup.get(c).map(|s| s.flags).unwrap_or_default();
+    let cell_x = t.col_screen_x(c);
+    let cell_w = t.col_w[c];
+    let row_y  = t.row_y();
+
+    let (text, _) = parse_label(label);
+    let fs   = ui.ctx.style.font_size;
+    let h    = fs + CELL_PADDING.y * 2.0;
+    let Some(_) = ui.layout_next(Vec2::new((cell_w - CELL_PADDING.x * 2.0).max(1.0), fs)) else { return };
+    let rect = Rect::from_min_size(Vec2::new(cell_x, row_y), Vec2::new(cell_w, h));
+
+    // Leave the column borders to the resize handles
+    let hit = if flags.contains(TableFlags::RESIZABLE) {
+        Rect::new(Vec2::new(rect.min.x + RESIZE_GRAB, rect.min.y), Vec2::new(rect.max.x - RESIZE_GRAB, rect.max.y))
+    } else {
+        rect
+    };
+    let hid = id.combine(Id::from_str("##header")).combine(Id::from_hash(&c));
+    let (hovered, held, clicked) = ui.ctx.button_behavior(hid, hit);
+    let pressed = hovered && ui.ctx.input.mouse_clicked(MouseButton::Left);
+    let down    = ui.ctx.input.mouse_down(MouseButton::Left);
+    let mouse   = ui.ctx.input.mouse_pos;
+
+    if pressed { state(ui, id).drag = Some((c, false)); }
+
+    // ── Reorder ──────────────────────────────────────────────────────────────
+    let dragging = held && down && state(ui, id).drag.is_some_and(|(d, _)| d == c);
+    if dragging && flags.contains(TableFlags::REORDERABLE) && !col_flags.contains(TableColumnFlags::NO_REORDER) {
+        let dir: isize = if mouse.x < rect.min.x { -1 } else if mouse.x > rect.max.x { 1 } else { 0 };
+        if dir != 0 { reorder(ui, c, dir); }
+    }
+
+    // ── Sort ─────────────────────────────────────────────────────────────────
+    let moved = state(ui, id).drag.is_some_and(|(d, m)| d == c && m);
+    let sortable = flags.contains(TableFlags::SORTABLE) && !col_flags.contains(TableColumnFlags::NO_SORT);
+    if clicked && !moved && sortable {
+        let multi = flags.contains(TableFlags::SORT_MULTI) && ui.ctx.input.shift();
+        let st = state(ui, id);
+        match st.sort.iter().position(|&(s, _)| s == c) {
+            Some(i) if multi || st.sort.len() == 1 => st.sort[i].1 = st.sort[i].1.flipped(),
+            None if multi => st.sort.push((c, default_direction(col_flags))),
+            _ => st.sort = vec![(c, default_direction(col_flags))],
+        }
+        st.sort_dirty = true;
+    }
+
+    // ── Context menu ─────────────────────────────────────────────────────────
+    if hovered && flags.contains(TableFlags::HIDEABLE) && ui.ctx.input.mouse_clicked(MouseButton::Right) {
+        state(ui, id).menu = Some(mouse);
+    }
+
+    // ── Drawing ──────────────────────────────────────────────────────────────
+    if hovered {
+        let bg = if held && down { StyleColor::HeaderActive } else { StyleColor::HeaderHovered };
+        let bg = ui.ctx.style.color(bg);
+        ui.ctx.draw_list.filled_rect(rect, 0.0, bg);
+    }
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    let ty = row_y + (h - fs) * 0.5;
+    ui.draw_text(text, Vec2::new(cell_x + CELL_PADDING.x, ty), tc);
+
+    let sort = state(ui, id).sort.clone();
+    if let Some(rank) = sort.iter().position(|&(s, _)| s == c) {
+        let dir = sort[rank].1;
+        let ax  = rect.max.x - CELL_PADDING.x - fs * 0.5;
+        let ay  = row_y + h * 0.5;
+        let s   = fs * 0.25;
+        let (a, b, p) = match dir {
+            SortDirection::Ascending  => (Vec2::new(ax - s, ay + s * 0.6), Vec2::new(ax + s, ay + s * 0.6), Vec2::new(ax, ay - s * 0.8)),
+            SortDirection::Descending => (Vec2::new(ax - s, ay - s * 0.6), Vec2::new(ax + s, ay - s * 0.6), Vec2::new(ax, ay + s * 0.8)),
+        };
+        let col = if rank == 0 { tc } else { ui.ctx.style.color(StyleColor::TextDisabled) };
+        ui.ctx.draw_list.triangle_filled(a, b, p, col);
+        if sort.len() > 1 {
+            let n = (rank + 1).to_string();
+            let nw = ui.text_width(&n);
+            ui.draw_text(&n, Vec2::new(ax - s - 2.0 - nw, ty), col);
+        }
+    }
+}
+
+/// Swap column `c` with its visible neighbour in direction `dir`.
+fn reorder(ui: &mut Ui<'_>, c: usize, dir: isize) {
+    let t  = ui.ctx.table_stack.last().unwrap();
+    let id = t.id;
+    let reorderable = |o: usize| !t.setup[o].flags.contains(TableColumnFlags::NO_REORDER);
+    let setup_ok: Vec<bool> = (0..t.count).map(reorderable).collect();
+    let st  = state(ui, id);
+    let Some(pos) = st.order.iter().position(|&o| o == c) else { return };
+    let mut n = pos as isize + dir;
+    while n >= 0 && (n as usize) < st.order.len() && !st.columns[st.order[n as usize]].visible {
+        n += dir;
+    }
+    if n < 0 || n as usize >= st.order.len() { return; }
+    let n = n as usize;
+    if !setup_ok[st.order[n]] { return; }
+    st.order.swap(pos, n);
+    st.drag = Some((c, true));
+}
+
+// ─── End ─────────────────────────────────────────────────────────────────────
+
+/// Finish the table, draw borders, scrollbars and the column menu, and
+/// advance the parent layout past it.
+pub fn end_table(ui: &mut Ui<'_>) {
+    layout_columns(ui);
+    end_row(ui);
+    let Some(t) = ui.ctx.table_stack.pop() else { return };
+
+    let content_h = t.row_top - t.outer.min.y;
+    let outer = if t.fixed_h { t.outer } else { Rect::from_min_size(t.outer.min, Vec2::new(t.outer.width(), content_h)) };
+    let inner = if t.fixed_h { t.inner } else { Rect::new(t.inner.min, Vec2::new(t.inner.max.x, outer.max.y)) };
+
+    // ── Borders ──────────────────────────────────────────────────────────────
+    let strong = ui.ctx.style.color(StyleColor::TableBorderStrong);
+    let light  = ui.ctx.style.color(StyleColor::TableBorderLight);
+    if t.flags.contains(TableFlags::BORDERS_INNER_V) {
+        ui.ctx.draw_list.push_clip_rect(inner);
+        for c in 0..t.count {
+            if t.col_x[c] == HIDDEN_X { continue; }
+            let x = t.col_screen_x(c) + t.col_w[c];
+            if x >= inner.max.x - 1.0 { continue; }
+            ui.ctx.draw_list.line(Vec2::new(x, inner.min.y), Vec2::new(x, inner.max.y), 1.0, light);
+        }
+        ui.ctx.draw_list.pop_clip_rect();
+    }
+    if t.flags.contains(TableFlags::BORDERS_OUTER_H) {
+        ui.ctx.draw_list.line(outer.min, Vec2::new(outer.max.x, outer.min.y), 1.0, strong);
+        ui.ctx.draw_list.line(Vec2::new(outer.min.x, outer.max.y), outer.max, 1.0, strong);
+    }
+    if t.flags.contains(TableFlags::BORDERS_OUTER_V) {
+        ui.ctx.draw_list.line(outer.min, Vec2::new(outer.min.x, outer.max.y), 1.0, strong);
+        ui.ctx.draw_list.line(Vec2::new(outer.max.x, outer.min.y), outer.max, 1.0, strong);
+    }
+
+    // ── Scrolling ────────────────────────────────────────────────────────────
+    let mut scroll = t.scroll;
+    let content = Vec2::new(t.col_x.iter().zip(&t.col_w).filter(|(x, _)| **x != HIDDEN_X).map(|(_, w)| *w).sum(), content_h);
+    let max = Vec2::new((content.x - inner.width()).max(0.0), (content.y - inner.height()).max(0.0));
+    let wheel = ui.ctx.input.mouse_wheel;
+    if wheel != 0.0 && outer.contains(ui.ctx.input.mouse_pos) {
+        let step = (ui.ctx.style.font_size + CELL_PADDING.y * 2.0) * 3.0 * wheel;
+        if ui.ctx.input.shift() && t.flags.contains(TableFlags::SCROLL_X) {
+            scroll.x -= step;
+        } else if t.flags.contains(TableFlags::SCROLL_Y) && t.fixed_h {
+            scroll.y -= step;
+        }
+    }
+    if max.y > 0.0 && t.fixed_h {
+        let track = Rect::new(Vec2::new(inner.max.x, outer.min.y), Vec2::new(outer.max.x, inner.max.y));
+        scroll.y = scrollbar(ui, t.id.combine(Id::from_str("##vscroll")), track, true, scroll.y, inner.height(), content.y);
+    }
+    if max.x > 0.0 {
+        let track = Rect::new(Vec2::new(outer.min.x, inner.max.y), Vec2::new(inner.max.x, outer.max.y));
+        scroll.x = scrollbar(ui, t.id.combine(Id::from_str("##hscroll")), track, false, scroll.x, inner.width(), content.x);
+    }
+
+    let released = !ui.ctx.input.mouse_down(MouseButton::Left);
+    let st = state(ui, t.id);
+    if released { st.drag = None; }
+    st.scroll  = scroll.clamp(Vec2::ZERO, max);
+    st.content = content;
+    st.height  = outer.height();
+
+    // ── Column menu ──────────────────────────────────────────────────────────
+    if let Some(at) = st.menu {
+        column_menu(ui, &t, at);
+    }
+
+    // ── Restore parent layout ────────────────────────────────────────────────
+    let sp = ui.ctx.style.item_spacing;
+    if let Some(l) = ui.ctx.current_layout_mut() {
+        *l = t.saved;
+        l.place(outer.size(), sp);
+    }
+}
+
+/// Scrollbar along `track`; returns the new scroll offset.
+pub(crate) fn scrollbar(ui: &mut Ui<'_>, id: Id, track: Rect, vertical: bool, scroll: f32, visible: f32, content: f32) -> f32 {
+    let len   = if vertical { track.height() } else { track.width() };
+    let max   = (content - visible).max(1.0);
+    let thumb = (len * visible / content).clamp(ui.ctx.style.grab_min_size, len);
+    let (hovered, held, _) = ui.ctx.button_behavior(id, track);
+    let mut scroll = scroll;
+    if held && ui.ctx.input.mouse_down(MouseButton::Left) {
+        let m = if vertical { ui.ctx.input.mouse_pos.y - track.min.y } else { ui.ctx.input.mouse_pos.x - track.min.x };
+        scroll = ((m - thumb * 0.5) / (len - thumb).max(1.0) * max).clamp(0.0, max);
+    }
+    let off = (len - thumb) * (scroll / max).clamp(0.0, 1.0);
+    let thumb_rect = if vertical {
+        Rect::from_min_size(Vec2::new(track.min.x, track.min.y + off), Vec2::new(track.width(), thumb))
+    } else {
+        Rect::from_min_size(Vec2::new(track.min.x + off, track.min.y), Vec2::new(thumb, track.height()))
+    };
+    let grab = if held { StyleColor::ScrollbarGrabActive } else if hovered { StyleColor::ScrollbarGrabHovered } else { StyleColor::ScrollbarGrab };
+    let bg   = ui.ctx.style.color(StyleColor::ScrollbarBg);
+    let grab = ui.ctx.style.color(grab);
+    let rounding = ui.ctx.style.frame_rounding;
+    ui.ctx.draw_list.filled_rect(track, 0.0, bg);
+    ui.ctx.draw_list.filled_rect(thumb_rect.expand(-2.0), rounding, grab);
+    scroll
+}
+
+/// Popup listing the columns with a check mark for each visible one.
+fn column_menu(ui: &mut Ui<'_>, t: &TableFrame, at: Vec2) {
+    let fs = ui.ctx.style.font_size;
+    let fp = ui.ctx.style.frame_padding;
+    let row_h = fs + fp.1 * 2.0;
+    let check_w = fs + fp.0;
+    let labels: Vec<String> = t.setup.iter().enumerate()
+        .map(|(i, s)| if s.label.is_empty() { format!("Column {i}") } else { parse_label(&s.label).0.to_owned() })
+        .collect();
+    let w = labels.iter().map(|l| ui.text_width(l)).fold(0.0, f32::max) + check_w + fp.0 * 2.0;
+    let popup = Rect::from_min_size(at, Vec2::new(w, row_h * t.count as f32 + fp.1 * 2.0));
+
+    let bg     = ui.ctx.style.color(StyleColor::PopupBg);
+    let border = ui.ctx.style.color(StyleColor::Border);
+    let rounding = ui.ctx.style.frame_rounding;
+    ui.ctx.draw_list.filled_rect(popup, rounding, bg);
+    ui.ctx.draw_list.rect_outline(popup, 1.0, border);
+
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    let dc = ui.ctx.style.color(StyleColor::TextDisabled);
+    let ck = ui.ctx.style.color(StyleColor::CheckMark);
+    let mut toggled = None;
+    for (c, label) in labels.iter().enumerate() {
+        let row = Rect::from_min_size(Vec2::new(popup.min.x, popup.min.y + fp.1 + c as f32 * row_h), Vec2::new(w, row_h));
+        let visible = ui.ctx.tables.get(&t.id).is_some_and(|s| s.columns.get(c).is_some_and(|cs| cs.visible));
+        let locked  = t.setup[c].flags.contains(TableColumnFlags::NO_HIDE);
+        let rid = t.id.combine(Id::from_str("##menu")).combine(Id::from_hash(&c));
+        let (hovered, _, clicked) = ui.ctx.button_behavior(rid, row);
+        if hovered && !locked {
+            let hc = ui.ctx.style.color(StyleColor::HeaderHovered);
+            ui.ctx.draw_list.filled_rect(row, 0.0, hc);
+        }
+        if clicked && !locked { toggled = Some(c); }
+        if visible {
+            let cx = row.min.x + fp.0 + fs * 0.5;
+            let cy = row.min.y + row_h * 0.5;
+            let s  = fs * 0.5;
+            let mark = if locked { dc } else { ck };
+            ui.ctx.draw_list.line(Vec2::new(cx - s * 0.6, cy), Vec2::new(cx - s * 0.1, cy + s * 0.5), 2.0, mark);
+            ui.ctx.draw_list.line(Vec2::new(cx - s * 0.1, cy + s * 0.5), Vec2::new(cx + s * 0.7, cy - s * 0.5), 2.0, mark);
+        }
+        ui.draw_text(label, Vec2::new(row.min.x + fp.0 + check_w, row.min.y + fp.1), if locked { dc } else { tc });
+    }
+
+    let st = state(ui, t.id);
+    if let Some(c) = toggled {
+        let shown = st.columns.iter().filter(|cs| cs.visible).count();
+        // Never hide the last visible column
+        if !st.columns[c].visible || shown > 1 {
+            st.columns[c].visible = !st.columns[c].visible;
+        }
+    }
+    let (clicked_outside, mouse) = (ui.ctx.input.mouse_clicked(MouseButton::Left), ui.ctx.input.mouse_pos);
+    if (clicked_outside && !popup.contains(mouse)) || ui.ctx.input.key_pressed(crate::input::Key::Escape) {
+        state(ui, t.id).menu = None;
+    }
+}
@@ -1,98 +1,503 @@
 //! Tab-bar / tab-item widgets.
+//!
+//! ```ignore
+//! if ui.begin_tab_bar_ex("docs", TabBarFlags::REORDERABLE | TabBarFlags::AUTO_SELECT_NEW_TABS) {
+//!     for doc in &mut docs {
+//!         if ui.tab_item(&doc.title, Some(&mut doc.open), TabItemFlags::empty()) {
+//!             ui.text(&doc.body);
+//!             ui.end_tab_item();
+//!         }
+//!     }
+//!     ui.end_tab_bar();
+//! }
+//! docs.retain(|d| d.open);
+//! ```
+//!
+//! Tabs are hit-tested against last frame's layout and drawn by
+//! `end_tab_bar`, once every tab of the frame is known. Tab order and the
+//! selected tab are kept on the [`Context`](crate::Context) per bar.
 
 use crate::{
-    id::parse_label,
+    id::{parse_label, Id},
+    input::MouseButton,
     style::StyleColor,
     ui::Ui,
     Rect, Vec2,
 };
 
-const TAB_H: f32 = 22.0;
+bitflags::bitflags! {
+    /// Tab-bar behaviour.
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct TabBarFlags: u32 {
+        /// Drag tabs to change their order.
+        const REORDERABLE           = 1 << 0;
+        /// Select a tab on the first frame it is submitted.
+        const AUTO_SELECT_NEW_TABS  = 1 << 1;
+        /// Show the tab-list dropdown even when every tab fits.
+        const TAB_LIST_POPUP_BUTTON = 1 << 2;
+        /// Shrink tabs to fit the bar, scrolling once they reach their
+        /// minimum width. The default.
+        const FITTING_POLICY_RESIZE = 1 << 3;
+        /// Keep tabs at full width and scroll them with the arrow
+        /// buttons or the mouse wheel.
+        const FITTING_POLICY_SCROLL = 1 << 4;
+    }
+}
+
+bitflags::bitflags! {
+    /// Per-tab options for `tab_item`.
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct TabItemFlags: u32 {
+        /// Make this the selected tab.
+        const SET_SELECTED     = 1 << 0;
+        /// Mark the tab with a dot where the close button would be.
+        const UNSAVED_DOCUMENT = 1 << 1;
+        /// Other tabs can't be dragged past this one, nor it past them.
+        const NO_REORDER       = 1 << 2;
+    }
+}
+
+const TAB_H:     f32 = 22.0;
+/// Gap between neighbouring tabs.
+const TAB_GAP:   f32 = 2.0;
+/// Narrowest a tab shrinks to under `FITTING_POLICY_RESIZE`.
+const MIN_TAB_W: f32 = 40.0;
+/// Scroll-arrow and tab-list button widths.
+const ARROW_W:   f32 = 14.0;
+const LIST_W:    f32 = 16.0;
+/// Pixels scrolled per mouse-wheel notch.
+const WHEEL_STEP: f32 = 30.0;
+
+// ─── Persistent state ────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone)]
+struct TabState {
+    id:       Id,
+    label:    String,
+    flags:    TabItemFlags,
+    closable: bool,
+    /// Full (unshrunk) width.
+    width:    f32,
+    /// Submitted this frame.
+    seen:     bool,
+}
+
+/// Per-bar state kept across frames.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TabBarState {
+    /// Display order.
+    tabs:          Vec<TabState>,
+    selected:      Option<Id>,
+    /// Selection made this frame; takes effect in `end_tab_bar` so only
+    /// one tab shows its content per frame.
+    next_selected: Option<Id>,
+    scroll:        f32,
+    list_open:     bool,
+    /// At least one frame has been submitted.
+    initialized:   bool,
+}
+
+// ─── Per-frame state ─────────────────────────────────────────────────────────
+
+/// The bar being submitted; lives on `Context::tab_bar_stack`.
+#[derive(Debug)]
+pub(crate) struct TabBarFrame {
+    id:           Id,
+    flags:        TabBarFlags,
+    rect:         Rect,
+    /// Last frame's layout: visible tab area and one rect per tab, in the
+    /// display order of `begin_tab_bar`.
+    tabs_rect:    Rect,
+    laid:         Vec<Rect>,
+    was_selected: Option<Id>,
+}
+
+/// Where the tabs and bar buttons go.
+struct Arrangement {
+    tabs_rect: Rect,
+    rects:     Vec<Rect>,
+    content_w: f32,
+    overflow:  bool,
+}
+
+/// Lay the tabs of `state` out in `rect`, clamping `state.scroll`.
+fn arrange(state: &mut TabBarState, flags: TabBarFlags, rect: Rect) -> Arrangement {
+    let full: Vec<f32> = state.tabs.iter().map(|t| t.width).collect();
+    let fit = |avail: f32| {
+        let gaps = TAB_GAP * full.len().saturating_sub(1) as f32;
+        let w = if flags.contains(TabBarFlags::FITTING_POLICY_SCROLL) { full.clone() } else { fit_widths(&full, avail - gaps) };
+        let content = w.iter().sum::<f32>() + gaps;
+        (w, content)
+    };
+
+    let (mut widths, mut content_w) = fit(rect.width());
+    let overflow = content_w > rect.width() + 0.5;
+    let mut tabs_rect = rect;
+    let buttons = if overflow { ARROW_W * 2.0 } else { 0.0 }
+        + if overflow || flags.contains(TabBarFlags::TAB_LIST_POPUP_BUTTON) { LIST_W } else { 0.0 };
+    if buttons > 0.0 {
+        tabs_rect.max.x = (rect.max.x - buttons).max(rect.min.x);
+        (widths, content_w) = fit(tabs_rect.width());
+    }
+
+    state.scroll = if overflow { state.scroll.clamp(0.0, (content_w - tabs_rect.width()).max(0.0)) } else { 0.0 };
+    let mut x = tabs_rect.min.x - state.scroll;
+    let rects = widths.iter()
+        .map(|&w| {
+            let r = Rect::from_min_size(Vec2::new(x, rect.min.y), Vec2::new(w, rect.height()));
+            x += w + TAB_GAP;
+            r
+        })
+        .collect();
+    Arrangement { tabs_rect, rects, content_w, overflow }
+}
+
+/// Shrink the widest widths until they sum to `avail`, never below
+/// `MIN_TAB_W`.
+fn fit_widths(full: &[f32], avail: f32) -> Vec<f32> {
+    if full.iter().sum::<f32>() <= avail { return full.to_vec(); }
+    let (mut lo, mut hi) = (MIN_TAB_W, full.iter().copied().fold(MIN_TAB_W, f32::max));
+    for _ in 0..24 {
+        let cap = (lo + hi) * 0.5;
+        if full.iter().map(|w| w.min(cap)).sum::<f32>() > avail { hi = cap; } else { lo = cap; }
+    }
+    full.iter().map(|w| w.min(lo)).collect()
+}
+
+/// Scroll so the tab at `index` is fully inside the visible area.
+fn scroll_into_view(state: &mut TabBarState, a: &Arrangement, index: usize) {
+    let Some(r) = a.rects.get(index) else { return };
+    let (left, right) = (r.min.x + state.scroll - a.tabs_rect.min.x, r.max.x + state.scroll - a.tabs_rect.min.x);
+    if left < state.scroll {
+        state.scroll = left;
+    } else if right > state.scroll + a.tabs_rect.width() {
+        state.scroll = right - a.tabs_rect.width();
+    }
+}
+
+/// Close-button square inside a tab rect.
+fn close_rect(tab: Rect, fs: f32) -> Rect {
+    let s = fs * 0.8;
+    Rect::from_min_size(Vec2::new(tab.max.x - s - 4.0, tab.min.y + (tab.height() - s) * 0.5), Vec2::splat(s))
+}
+
+// ─── Begin / end ─────────────────────────────────────────────────────────────
 
 /// Begin a tab bar. Returns `true` if at least one tab is rendered.
 pub fn begin_tab_bar(ui: &mut Ui<'_>, id_str: &str) -> bool {
-    let id  = ui.ctx.make_id(id_str);
-    let bar_id = id.combine(crate::id::Id::from_str("__tabbar"));
-    // push the tab bar ID so tab_item can read it
-    ui.ctx.id_stack.push(bar_id);
+    begin_tab_bar_ex(ui, id_str, TabBarFlags::empty())
+}
+
+/// `begin_tab_bar` with options. Call `end_tab_bar` only if this returns
+/// `true`.
+pub fn begin_tab_bar_ex(ui: &mut Ui<'_>, id_str: &str, flags: TabBarFlags) -> bool {
+    let id     = ui.ctx.make_id(id_str);
+    let bar_id = id.combine(Id::from_str("__tabbar"));
 
     let avail_w = ui.available_width();
-    let sp = ui.ctx.style.item_spacing;
     let pos = match ui.layout_next(Vec2::new(avail_w, TAB_H)) {
         Some(p) => p,
         None => return false,
     };
+    let rect = Rect::from_min_size(pos, Vec2::new(avail_w, TAB_H));
+
     // draw background
     let bg = ui.ctx.style.color(StyleColor::Tab);
-    ui.ctx.draw_list.filled_rect(
-        Rect::from_min_size(pos, Vec2::new(avail_w, TAB_H)),
-        0.0,
-        bg,
-    );
-    // Store bar position for tab items
-    let s = ui.ctx.get_storage_mut(bar_id);
-    s.float[0] = pos.x; // cursor x for next tab
-    s.float[1] = pos.y;
+    ui.ctx.draw_list.filled_rect(rect, 0.0, bg);
+
+    let state = ui.ctx.tab_bars.entry(bar_id).or_default();
+    for t in &mut state.tabs { t.seen = false; }
+    let a = arrange(state, flags, rect);
+    let frame = TabBarFrame {
+        id: bar_id,
+        flags,
+        rect,
+        tabs_rect:    a.tabs_rect,
+        laid:         a.rects,
+        was_selected: state.selected,
+    };
+    ui.ctx.tab_bar_stack.push(frame);
+    // push the tab bar ID so tab ids are scoped to the bar
+    ui.ctx.id_stack.push(bar_id);
     true
 }
 
 pub fn end_tab_bar(ui: &mut Ui<'_>) {
     ui.ctx.id_stack.pop(); // pop the tab bar ID
-}
-
-/// Render a single tab. Returns `true` if this tab is selected.
-pub fn tab_item(ui: &mut Ui<'_>, label: &str) -> bool {
-    let (text, id_src) = parse_label(label);
-    let item_id = ui.ctx.make_id(id_src);
-
-    // Retrieve bar ID (last pushed) and its stored position
-    let bar_id = match ui.ctx.id_stack.last().copied() {
-        Some(id) => id,
-        None    => return false,
-    };
+    let Some(frame) = ui.ctx.tab_bar_stack.pop() else { return };
+    let fs = ui.ctx.style.font_size;
+    let fp = ui.ctx.style.frame_padding;
 
-    let bar_x  = ui.ctx.get_storage(bar_id).map_or(0.0, |s| s.float[0]);
-    let bar_y  = ui.ctx.get_storage(bar_id).map_or(0.0, |s| s.float[1]);
-    let active = ui.ctx.get_storage(bar_id).map_or(false, |s| s.int[0] as u64 == item_id.0);
-
-    let fs    = ui.ctx.style.font_size;
-    let fp    = ui.ctx.style.frame_padding;
-    let tw    = ui.text_width(text);
-    let tab_w = tw + fp.0 * 2.0 + 4.0;
+    // Drop tabs that weren't submitted; a vanished selection passes to
+    // its right-hand neighbour (or the new last tab)
+    let state = ui.ctx.tab_bars.entry(frame.id).or_default();
+    if let Some(id) = state.next_selected.take() { state.selected = Some(id); }
+    let sel_pos = state.selected.and_then(|s| state.tabs.iter().position(|t| t.id == s));
+    if !sel_pos.is_some_and(|i| state.tabs[i].seen) {
+        let after  = sel_pos.unwrap_or(0);
+        let before = state.tabs[..after.min(state.tabs.len())].iter().filter(|t| t.seen).count();
+        state.tabs.retain(|t| t.seen);
+        state.selected = state.tabs.get(before.min(state.tabs.len().saturating_sub(1))).map(|t| t.id);
+    } else {
+        state.tabs.retain(|t| t.seen);
+    }
+    state.initialized = true;
 
-    let tab_rect = Rect::from_min_size(Vec2::new(bar_x, bar_y), Vec2::new(tab_w, TAB_H));
+    let mut a = arrange(state, frame.flags, frame.rect);
+    if state.selected != frame.was_selected {
+        if let Some(i) = state.selected.and_then(|s| state.tabs.iter().position(|t| t.id == s)) {
+            scroll_into_view(state, &a, i);
+            a = arrange(state, frame.flags, frame.rect);
+        }
+    }
 
-    let (_h, _, clicked) = ui.ctx.button_behavior(item_id, tab_rect);
-    if clicked || active {
-        if clicked {
-            // Record selection using low 64 bits of item_id
-            ui.ctx.get_storage_mut(bar_id).int[0] = item_id.0 as i32;
+    // ── Scroll arrows / wheel ────────────────────────────────────────────────
+    let m = ui.ctx.input.mouse_pos;
+    let list_rect = Rect::new(Vec2::new(frame.rect.max.x - LIST_W, frame.rect.min.y), frame.rect.max);
+    let show_list = a.overflow || frame.flags.contains(TabBarFlags::TAB_LIST_POPUP_BUTTON);
+    let arrows = a.overflow.then(|| {
+        let left = Rect::from_min_size(Vec2::new(a.tabs_rect.max.x, frame.rect.min.y), Vec2::new(ARROW_W, TAB_H));
+        (left, Rect::from_min_size(Vec2::new(left.max.x, frame.rect.min.y), Vec2::new(ARROW_W, TAB_H)))
+    });
+    if let Some((left, right)) = arrows {
+        let (hl, _, _) = ui.ctx.button_behavior(frame.id.combine(Id::from_str("__left")), left);
+        let (hr, _, _) = ui.ctx.button_behavior(frame.id.combine(Id::from_str("__right")), right);
+        let press = ui.ctx.input.mouse_clicked(MouseButton::Left);
+        let state = ui.ctx.tab_bars.entry(frame.id).or_default();
+        let offsets: Vec<(f32, f32)> = a.rects.iter()
+            .map(|r| (r.min.x + state.scroll - a.tabs_rect.min.x, r.max.x + state.scroll - a.tabs_rect.min.x))
+            .collect();
+        if hl && press {
+            // Previous tab's start
+            if let Some(&(x, _)) = offsets.iter().rev().find(|(x, _)| *x < state.scroll - 0.5) { state.scroll = x; }
         }
-        let tab_col = ui.ctx.style.color(StyleColor::TabActive);
-        ui.ctx.draw_list.filled_rect(tab_rect, ui.ctx.style.frame_rounding, tab_col);
-    } else {
-        let tab_col = if ui.ctx.is_hot(item_id) {
+        if hr && press {
+            let view = a.tabs_rect.width();
+            if let Some(&(_, x)) = offsets.iter().find(|(_, x)| *x > state.scroll + view + 0.5) { state.scroll = x - view; }
+        }
+        let wheel = ui.ctx.input.mouse_wheel;
+        if wheel != 0.0 && frame.rect.contains(m) {
+            state.scroll -= wheel * WHEEL_STEP;
+        }
+        a = arrange(state, frame.flags, frame.rect);
+    }
+
+    // ── Tabs ─────────────────────────────────────────────────────────────────
+    let rounding = ui.ctx.style.frame_rounding;
+    let state = ui.ctx.tab_bars.entry(frame.id).or_default();
+    let tabs  = state.tabs.clone();
+    let selected = state.selected;
+    ui.ctx.draw_list.push_clip_rect(a.tabs_rect);
+    for (t, &r) in tabs.iter().zip(&a.rects) {
+        if r.max.x < a.tabs_rect.min.x || r.min.x > a.tabs_rect.max.x { continue; }
+        let is_sel   = selected == Some(t.id);
+        let hot      = ui.ctx.is_hot(t.id) || ui.ctx.is_hot(t.id.combine(Id::from_str("__close")));
+        let col = if is_sel {
+            ui.ctx.style.color(StyleColor::TabActive)
+        } else if hot {
             ui.ctx.style.color(StyleColor::TabHovered)
         } else {
             ui.ctx.style.color(StyleColor::Tab)
         };
-        ui.ctx.draw_list.filled_rect(tab_rect, ui.ctx.style.frame_rounding, tab_col);
+        ui.ctx.draw_list.filled_rect(r, rounding, col);
+
+        // Close button, or the unsaved dot while it's hidden
+        let tc = ui.ctx.style.color(StyleColor::Text);
+        let cr = close_rect(r, fs);
+        let mut text_max = r.max.x - fp.0;
+        if t.closable && (is_sel || hot) {
+            let close_id = t.id.combine(Id::from_str("__close"));
+            if ui.ctx.is_hot(close_id) {
+                let hc = ui.ctx.style.color(StyleColor::ButtonHovered);
+                ui.ctx.draw_list.filled_rect(cr, 2.0, hc);
+            }
+            let (c, k) = (cr.center(), cr.width() * 0.3);
+            ui.ctx.draw_list.line(c - Vec2::splat(k), c + Vec2::splat(k), 1.0, tc);
+            ui.ctx.draw_list.line(Vec2::new(c.x - k, c.y + k), Vec2::new(c.x + k, c.y - k), 1.0, tc);
+            text_max = cr.min.x - 2.0;
+        } else if t.flags.contains(TabItemFlags::UNSAVED_DOCUMENT) {
+            ui.ctx.draw_list.filled_circle(cr.center(), fs * 0.2, tc, 8);
+            text_max = cr.min.x - 2.0;
+        } else if t.closable {
+            text_max = cr.min.x - 2.0;
+        }
+
+        // Tab label
+        let tp = Vec2::new(r.min.x + fp.0, r.min.y + (TAB_H - fs) * 0.5);
+        ui.ctx.draw_list.push_clip_rect(Rect::new(r.min, Vec2::new(text_max, r.max.y)));
+        ui.draw_text(&t.label, tp, tc);
+        ui.ctx.draw_list.pop_clip_rect();
     }
+    ui.ctx.draw_list.pop_clip_rect();
 
-    // Advance bar cursor
-    ui.ctx.get_storage_mut(bar_id).float[0] = bar_x + tab_w + 2.0;
+    // ── Bar buttons ──────────────────────────────────────────────────────────
+    let arrow_col  = ui.ctx.style.color(StyleColor::Text);
+    let arrow_dim  = ui.ctx.style.color(StyleColor::TextDisabled);
+    if let Some((left, right)) = arrows {
+        let max_scroll = (a.content_w - a.tabs_rect.width()).max(0.0);
+        let scroll = ui.ctx.tab_bars.get(&frame.id).map_or(0.0, |s| s.scroll);
+        let (cl, cr) = (left.center(), right.center());
+        let lc = if scroll > 0.0 { arrow_col } else { arrow_dim };
+        let rc = if scroll < max_scroll { arrow_col } else { arrow_dim };
+        ui.ctx.draw_list.triangle_filled(Vec2::new(cl.x - 3.0, cl.y), Vec2::new(cl.x + 3.0, cl.y - 5.0), Vec2::new(cl.x + 3.0, cl.y + 5.0), lc);
+        ui.ctx.draw_list.triangle_filled(Vec2::new(cr.x + 3.0, cr.y), Vec2::new(cr.x - 3.0, cr.y + 5.0), Vec2::new(cr.x - 3.0, cr.y - 5.0), rc);
+    }
+    if show_list {
+        tab_list(ui, &frame, list_rect);
+    }
+}
 
-    // Tab label
+/// Dropdown button listing every tab; picking one selects it.
+fn tab_list(ui: &mut Ui<'_>, frame: &TabBarFrame, button: Rect) {
+    let list_id = frame.id.combine(Id::from_str("__list"));
+    let (hovered, _, _) = ui.ctx.button_behavior(list_id, button);
+    let press = ui.ctx.input.mouse_clicked(MouseButton::Left);
+    let m = ui.ctx.input.mouse_pos;
+
+    let state = ui.ctx.tab_bars.entry(frame.id).or_default();
+    if hovered && press { state.list_open = !state.list_open; }
+    let open  = state.list_open;
+    let items: Vec<(Id, String)> = state.tabs.iter().map(|t| (t.id, t.label.clone())).collect();
+    let selected = state.selected;
+
+    let c  = button.center();
     let tc = ui.ctx.style.color(StyleColor::Text);
-    let tp = Vec2::new(tab_rect.min.x + fp.0, tab_rect.min.y + (TAB_H - fs) * 0.5);
-    ui.draw_text(text, tp, tc);
+    ui.ctx.draw_list.triangle_filled(Vec2::new(c.x - 4.0, c.y - 2.0), Vec2::new(c.x + 4.0, c.y - 2.0), Vec2::new(c.x, c.y + 3.0), tc);
+    if !open || items.is_empty() { return; }
+
+    let fs = ui.ctx.style.font_size;
+    let fp = ui.ctx.style.frame_padding;
+    let sp = ui.ctx.style.item_spacing;
+    let item_h  = fs + sp.1;
+    let popup_w = items.iter().map(|(_, l)| ui.text_width(l)).fold(0.0, f32::max) + fp.0 * 2.0;
+    let popup   = Rect::from_min_size(
+        Vec2::new((button.max.x - popup_w).max(frame.rect.min.x), button.max.y + 2.0),
+        Vec2::new(popup_w, items.len() as f32 * item_h + fp.1 * 2.0),
+    );
+    let rounding = ui.ctx.style.frame_rounding;
+    let popup_bg = ui.ctx.style.color(StyleColor::PopupBg);
+    let border   = ui.ctx.style.color(StyleColor::Border);
+    ui.ctx.draw_list.filled_rect(popup, rounding, popup_bg);
+    ui.ctx.draw_list.rect_outline(popup, 1.0, border);
+
+    let mut picked = None;
+    for (i, (id, label)) in items.iter().enumerate() {
+        let item_rect = Rect::from_min_size(Vec2::new(popup.min.x, popup.min.y + fp.1 + i as f32 * item_h), Vec2::new(popup_w, item_h));
+        let (hov, _, clicked) = ui.ctx.button_behavior(list_id.combine(*id), item_rect);
+        if hov || selected == Some(*id) {
+            let hc = if selected == Some(*id) { StyleColor::HeaderActive } else { StyleColor::HeaderHovered };
+            let hc = ui.ctx.style.color(hc);
+            ui.ctx.draw_list.filled_rect(item_rect, 0.0, hc);
+        }
+        if clicked { picked = Some(*id); }
+        ui.draw_text(label, Vec2::new(item_rect.min.x + fp.0, item_rect.min.y + (item_h - fs) * 0.5), tc);
+    }
+
+    let state = ui.ctx.tab_bars.entry(frame.id).or_default();
+    if let Some(id) = picked {
+        state.selected  = Some(id);
+        state.list_open = false;
+        if let Some(i) = state.tabs.iter().position(|t| t.id == id) {
+            let a = arrange(state, frame.flags, frame.rect);
+            scroll_into_view(state, &a, i);
+        }
+    } else if press && !popup.contains(m) && !button.contains(m) {
+        state.list_open = false;
+    }
+}
+
+// ─── Tabs ────────────────────────────────────────────────────────────────────
+
+/// Submit a tab. Returns `true` if this tab is selected; draw its content
+/// then call `end_tab_item`. With `open`, the tab gets a close button
+/// (middle-click closes too) that sets `*open = false`; closed tabs are
+/// skipped and return `false`.
+pub fn tab_item(ui: &mut Ui<'_>, label: &str, open: Option<&mut bool>, flags: TabItemFlags) -> bool {
+    if open.as_deref() == Some(&false) { return false; }
+    let Some(bar) = ui.ctx.tab_bar_stack.last() else { return false };
+    let (bar_id, bar_flags, tabs_rect) = (bar.id, bar.flags, bar.tabs_rect);
+
+    let (text, id_src) = parse_label(label);
+    let item_id = ui.ctx.make_id(id_src);
+
+    let fs    = ui.ctx.style.font_size;
+    let fp    = ui.ctx.style.frame_padding;
+    let closable = open.is_some();
+    let marker   = closable || flags.contains(TabItemFlags::UNSAVED_DOCUMENT);
+    let tab_w = ui.text_width(text) + fp.0 * 2.0 + 4.0 + if marker { fs * 0.8 + 2.0 } else { 0.0 };
+
+    // Register / refresh the persistent entry
+    let state = ui.ctx.tab_bars.entry(bar_id).or_default();
+    let index = match state.tabs.iter().position(|t| t.id == item_id) {
+        Some(i) => i,
+        None => {
+            if bar_flags.contains(TabBarFlags::AUTO_SELECT_NEW_TABS) && state.initialized {
+                state.next_selected = Some(item_id);
+            }
+            state.tabs.push(TabState { id: item_id, label: String::new(), flags, closable, width: 0.0, seen: false });
+            state.tabs.len() - 1
+        }
+    };
+    let t = &mut state.tabs[index];
+    if t.label != text { t.label = text.to_owned(); }
+    t.flags    = flags;
+    t.closable = closable;
+    t.width    = tab_w;
+    t.seen     = true;
+    if state.selected.is_none() {
+        state.selected = Some(item_id);
+    } else if flags.contains(TabItemFlags::SET_SELECTED) {
+        state.next_selected = Some(item_id);
+    }
+
+    // ── Interaction (against last frame's layout) ────────────────────────────
+    let laid = ui.ctx.tab_bar_stack.last().and_then(|b| b.laid.get(index).copied());
+    let mut closed = false;
+    if let Some(r) = laid {
+        ui.ctx.draw_list.push_clip_rect(tabs_rect);
+        let (mut close_hov, mut close_clicked) = (false, false);
+        if closable {
+            (close_hov, _, close_clicked) = ui.ctx.button_behavior(item_id.combine(Id::from_str("__close")), close_rect(r, fs));
+        }
+        let (hovered, _, _) = if close_hov { (false, false, false) } else { ui.ctx.button_behavior(item_id, r) };
+        ui.ctx.draw_list.pop_clip_rect();
+
+        let input = &ui.ctx.input;
+        if hovered && input.mouse_clicked(MouseButton::Left) {
+            ui.ctx.tab_bars.entry(bar_id).or_default().next_selected = Some(item_id);
+        }
+        closed = closable && (close_clicked || (hovered && input.mouse_clicked(MouseButton::Middle)));
+
+        // Drag past a neighbour to swap with it
+        let dragging = ui.ctx.is_active(item_id) && input.mouse_down(MouseButton::Left);
+        if bar_flags.contains(TabBarFlags::REORDERABLE) && dragging && !flags.contains(TabItemFlags::NO_REORDER) {
+            let (mx, dx) = (input.mouse_pos.x, input.mouse_delta.x);
+            let target = if dx < 0.0 && mx < r.min.x && index > 0 {
+                Some(index - 1)
+            } else if dx > 0.0 && mx > r.max.x {
+                Some(index + 1)
+            } else {
+                None
+            };
+            let bar = ui.ctx.tab_bar_stack.last();
+            let target = target.filter(|&j| bar.and_then(|b| b.laid.get(j)).is_some_and(|o| mx >= o.min.x && mx <= o.max.x));
+            let state = ui.ctx.tab_bars.entry(bar_id).or_default();
+            if let Some(j) = target.filter(|&j| state.tabs.get(j).is_some_and(|o| !o.flags.contains(TabItemFlags::NO_REORDER))) {
+                state.tabs.swap(index, j);
+            }
+        }
+    }
 
-    // Return whether this tab's content should be shown
-    // First call with no selection → default to first tab
-    let stored = ui.ctx.get_storage(bar_id).map(|s| s.int[0] as u64).unwrap_or(0);
-    stored == item_id.0 || stored == 0
+    let state = ui.ctx.tab_bars.entry(bar_id).or_default();
+    if closed {
+        if let Some(open) = open { *open = false; }
+        if let Some(t) = state.tabs.iter_mut().find(|t| t.id == item_id) { t.seen = false; }
+        return false;
+    }
+    state.selected == Some(item_id)
 }
 
 pub fn end_tab_item(_ui: &mut Ui<'_>) {
     // No state needed
 }
+
@@ -0,0 +1,581 @@
+//! Timeline / sequencer – tracks of clips and keyframes over a time ruler
+//! with a scrubbing playhead.
+//!
+//! ```ignore
+//! let mut tl = TimelineState::new(10.0, 30.0)
+//!     .with_track(TimelineTrack::new(1, "Camera")
+//!         .with_clip(TimelineClip::new(1, 0.0, 4.0).with_label("Dolly"))
+//!         .with_key(1, 0.5)
+//!         .with_key(2, 2.0));
+//! // each frame:
+//! for ev in ui.timeline("seq", &mut tl) {
+//!     if let TimelineEvent::PlayheadMoved(t) = ev { player.seek(t); }
+//! }
+//! ```
+//!
+//! The app owns the [`TimelineState`]; the widget edits it in place and
+//! returns what changed as [`TimelineEvent`]s. Times are in seconds and
+//! snap to whole frames of `fps` while `snap` is on (hold Alt to drag
+//! freely).
+//!
+//! Drag on the ruler to scrub, drag clip bodies to move them and their
+//! edges to resize. Click keyframes to select (Ctrl toggles, Shift adds)
+//! and drag them together; drag on empty track space to box-select and
+//! double-click it to add a key. Delete removes the selected keys and clip.
+//! The wheel zooms around the mouse, Shift+wheel or middle-drag scrolls.
+
+use crate::{
+    id::parse_label,
+    input::{Key, MouseButton},
+    style::StyleColor,
+    ui::Ui,
+    widgets::table::scrollbar,
+    Color, Rect, Vec2,
+};
+
+const HEADER_W:    f32 = 120.0;
+/// Grab width of a clip edge, in pixels.
+const EDGE_GRAB:   f32 = 5.0;
+/// Closest major ruler ticks may get, in pixels.
+const MIN_TICK_PX: f32 = 70.0;
+const MAX_PX_PER_FRAME: f32 = 48.0;
+const PLAYHEAD_COLOR:   u32 = 0xe05050;
+const CLIP_COLORS: [u32; 6] = [0x3d6e9e, 0x5e8f3e, 0x9e6a3d, 0x7a4f9e, 0x3d8f86, 0x9e3d5a];
+
+/// A block of time on a track.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineClip {
+    /// Unique within the timeline.
+    pub id:     u64,
+    pub start:  f32,
+    pub length: f32,
+    pub label:  String,
+    /// `None` picks a colour from the track's position.
+    pub color:  Option<Color>,
+}
+
+impl TimelineClip {
+    pub fn new(id: u64, start: f32, length: f32) -> Self {
+        Self { id, start, length, label: String::new(), color: None }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self { self.label = label.into(); self }
+    pub fn with_color(mut self, color: Color) -> Self { self.color = Some(color); self }
+
+    pub fn end(&self) -> f32 { self.start + self.length }
+}
+
+/// A keyframe, drawn as a diamond.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimelineKey {
+    /// Unique within the timeline.
+    pub id:   u64,
+    pub time: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineTrack {
+    pub id:    u64,
+    pub name:  String,
+    pub clips: Vec<TimelineClip>,
+    pub keys:  Vec<TimelineKey>,
+}
+
+impl TimelineTrack {
+    pub fn new(id: u64, name: impl Into<String>) -> Self {
+        Self { id, name: name.into(), clips: Vec::new(), keys: Vec::new() }
+    }
+
+    pub fn with_clip(mut self, clip: TimelineClip) -> Self { self.clips.push(clip); self }
+    pub fn with_key(mut self, id: u64, time: f32) -> Self { self.keys.push(TimelineKey { id, time }); self }
+}
+
+/// An edit made in the timeline this frame. The state already reflects it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimelineEvent {
+    PlayheadMoved(f32),
+    ClipMoved   { track: u64, clip: u64, start: f32 },
+    ClipResized { track: u64, clip: u64, start: f32, length: f32 },
+    ClipDeleted { track: u64, clip: u64 },
+    KeyAdded    { track: u64, key: u64, time: f32 },
+    KeyMoved    { track: u64, key: u64, time: f32 },
+    KeyDeleted  { track: u64, key: u64 },
+    /// `selected_keys` or `selected_clip` changed.
+    SelectionChanged,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ClipGrab { Body, Start, End }
+
+#[derive(Debug, Clone, Default, PartialEq)]
+enum Drag {
+    #[default]
+    None,
+    Playhead,
+    /// `grab` is the time under the mouse at the press.
+    Clip { clip: u64, grab: f32, part: ClipGrab, start: f32, end: f32 },
+    /// Selected keys with their times at the press.
+    Keys { grab: f32, from: Vec<(u64, f32)> },
+    /// Box select from `(time, y)`.
+    Box(f32, f32),
+    Pan,
+}
+
+/// Tracks plus the view, playhead and selection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineState {
+    pub tracks:            Vec<TimelineTrack>,
+    pub playhead:          f32,
+    /// End of the timeline; clips, keys and the playhead stay in `0..=duration`.
+    pub duration:          f32,
+    /// Frames per second for snapping and ruler labels.
+    pub fps:               f32,
+    pub snap:              bool,
+    pub selected_keys:     Vec<u64>,
+    pub selected_clip:     Option<u64>,
+    /// Time at the left edge of the view.
+    pub view_start:        f32,
+    /// Horizontal zoom; `0` fits the whole duration on the next frame.
+    pub pixels_per_second: f32,
+    drag:                  Drag,
+}
+
+impl TimelineState {
+    pub fn new(duration: f32, fps: f32) -> Self {
+        Self {
+            tracks:            Vec::new(),
+            playhead:          0.0,
+            duration:          duration.max(0.0),
+            fps:               fps.max(0.0),
+            snap:              true,
+            selected_keys:     Vec::new(),
+            selected_clip:     None,
+            view_start:        0.0,
+            pixels_per_second: 0.0,
+            drag:              Drag::None,
+        }
+    }
+
+    pub fn with_track(mut self, track: TimelineTrack) -> Self { self.tracks.push(track); self }
+
+    /// An id no key uses yet.
+    pub fn next_key_id(&self) -> u64 {
+        self.tracks.iter().flat_map(|t| &t.keys).map(|k| k.id + 1).max().unwrap_or(1)
+    }
+
+    /// `t` on the frame grid when snapping.
+    pub fn snap_time(&self, t: f32) -> f32 {
+        if self.snap && self.fps > 0.0 { (t * self.fps).round() / self.fps } else { t }
+    }
+
+    fn frame_len(&self) -> f32 { if self.fps > 0.0 { 1.0 / self.fps } else { 0.01 } }
+}
+
+/// What the mouse is over inside the track area.
+enum Hit {
+    Key(u64),
+    Clip { track: usize, clip: u64, part: ClipGrab },
+    Track(usize),
+}
+
+/// Track names, ruler and tracks, filling the available width; returns
+/// this frame's edits.
+pub fn timeline(ui: &mut Ui<'_>, str_id: &str, state: &mut TimelineState) -> Vec<TimelineEvent> {
+    let mut events = Vec::new();
+    let (_, id_src) = parse_label(str_id);
+    let id = ui.ctx.make_id(id_src);
+
+    let fs      = ui.ctx.style.font_size;
+    let sb      = ui.ctx.style.scrollbar_size;
+    let ruler_h = fs + 10.0;
+    let row_h   = fs + 14.0;
+    let width   = ui.available_width().max(HEADER_W * 2.0);
+    let height  = ruler_h + state.tracks.len() as f32 * row_h + sb;
+    let Some(pos) = ui.layout_next(Vec2::new(width, height)) else { return events };
+
+    let frame = Rect::from_min_size(pos, Vec2::new(width, height));
+    let ruler = Rect::new(Vec2::new(pos.x + HEADER_W, pos.y), Vec2::new(frame.max.x, pos.y + ruler_h));
+    let area  = Rect::new(Vec2::new(ruler.min.x, ruler.max.y), Vec2::new(frame.max.x, frame.max.y - sb));
+    let track_rect = |i: usize| Rect::new(
+        Vec2::new(area.min.x, area.min.y + i as f32 * row_h),
+        Vec2::new(area.max.x, area.min.y + (i + 1) as f32 * row_h),
+    );
+
+    // ── View ─────────────────────────────────────────────────────────────────
+    // `duration` is a public field; a negative one must not invert the clamps
+    let duration = state.duration.max(0.0);
+    let fit     = area.width() / duration.max(state.frame_len());
+    let max_pps = if state.fps > 0.0 { state.fps * MAX_PX_PER_FRAME } else { 4000.0 };
+    if state.pixels_per_second <= 0.0 { state.pixels_per_second = fit; }
+
+    let (hovered, _, _) = ui.ctx.button_behavior(id, Rect::new(frame.min, area.max));
+    let mouse = ui.ctx.input.mouse_pos;
+    let wheel = ui.ctx.input.mouse_wheel;
+    if hovered && wheel != 0.0 && mouse.x >= area.min.x {
+        if ui.ctx.input.shift() {
+            state.view_start -= wheel * 60.0 / state.pixels_per_second;
+        } else {
+            let at  = state.view_start + (mouse.x - area.min.x) / state.pixels_per_second;
+            let pps = (state.pixels_per_second * 1.2f32.powf(wheel)).clamp(fit.min(max_pps), max_pps);
+            state.view_start = at - (mouse.x - area.min.x) / pps;
+            state.pixels_per_second = pps;
+        }
+    }
+    let pan_pressed = hovered && ui.ctx.input.mouse_clicked(MouseButton::Middle);
+    if pan_pressed { state.drag = Drag::Pan; }
+    if state.drag == Drag::Pan {
+        if !ui.ctx.input.mouse_down(MouseButton::Middle) {
+            state.drag = Drag::None;
+        } else if !pan_pressed {
+            state.view_start -= ui.ctx.input.mouse_delta.x / state.pixels_per_second;
+        }
+    }
+    let pps     = state.pixels_per_second;
+    let visible = area.width() / pps;
+    state.view_start = state.view_start.clamp(0.0, (duration - visible).max(0.0));
+
+    let view_start = state.view_start;
+    let to_x = |t: f32| area.min.x + (t - view_start) * pps;
+    let to_t = |x: f32| view_start + (x - area.min.x) / pps;
+
+    // ── Hit testing ──────────────────────────────────────────────────────────
+    let key_r = (row_h * 0.3).max(4.0);
+    let hit = (hovered && area.contains(mouse)).then(|| {
+        let ti = (((mouse.y - area.min.y) / row_h) as usize).min(state.tracks.len().saturating_sub(1));
+        let track = &state.tracks[ti];
+        let mid   = track_rect(ti).center().y;
+        let key = track.keys.iter().rev().find(|k| {
+            (mouse.x - to_x(k.time)).abs() + (mouse.y - mid).abs() <= key_r + 2.0
+        });
+        if let Some(k) = key { return Hit::Key(k.id); }
+        let clip = track.clips.iter().rev().find(|c| mouse.x >= to_x(c.start) - 1.0 && mouse.x <= to_x(c.end()) + 1.0);
+        match clip {
+            Some(c) => {
+                let (x0, x1) = (to_x(c.start), to_x(c.end()));
+                let edge = EDGE_GRAB.min((x1 - x0) / 3.0);
+                let part = if mouse.x - x0 <= edge { ClipGrab::Start } else if x1 - mouse.x <= edge { ClipGrab::End } else { ClipGrab::Body };
+                Hit::Clip { track: ti, clip: c.id, part }
+            }
+            None => Hit::Track(ti),
+        }
+    }).filter(|_| !state.tracks.is_empty());
+
+    // ── Press ────────────────────────────────────────────────────────────────
+    let input   = &ui.ctx.input;
+    let (ctrl, shift) = (input.ctrl(), input.shift());
+    let free    = input.alt();
+    let pressed = hovered && input.mouse_clicked(MouseButton::Left);
+    let double  = hovered && input.mouse_double_clicked(MouseButton::Left);
+    let t_mouse = to_t(mouse.x);
+    let before  = (state.selected_keys.clone(), state.selected_clip);
+    if pressed && ruler.contains(mouse) {
+        state.drag = Drag::Playhead;
+    } else if pressed {
+        match hit {
+            Some(Hit::Key(key)) => {
+                if ctrl {
+                    match state.selected_keys.iter().position(|&k| k == key) {
+                        Some(i) => { state.selected_keys.remove(i); }
+                        None    => state.selected_keys.push(key),
+                    }
+                } else if !state.selected_keys.contains(&key) {
+                    if !shift { state.selected_keys.clear(); }
+                    state.selected_keys.push(key);
+                }
+                if !ctrl && !shift { state.selected_clip = None; }
+                let from = state.tracks.iter().flat_map(|t| &t.keys)
+                    .filter(|k| state.selected_keys.contains(&k.id))
+                    .map(|k| (k.id, k.time))
+                    .collect();
+                state.drag = Drag::Keys { grab: t_mouse, from };
+            }
+            Some(Hit::Clip { track, clip, part }) => {
+                if !ctrl && !shift { state.selected_keys.clear(); }
+                state.selected_clip = Some(clip);
+                if let Some(c) = state.tracks[track].clips.iter().find(|c| c.id == clip) {
+                    state.drag = Drag::Clip { clip, grab: t_mouse, part, start: c.start, end: c.end() };
+                }
+            }
+            Some(Hit::Track(track)) if double => {
+                let key  = state.next_key_id();
+                let time = snap(state, t_mouse, free).clamp(0.0, duration);
+                state.tracks[track].keys.push(TimelineKey { id: key, time });
+                state.selected_keys = vec![key];
+                state.selected_clip = None;
+                events.push(TimelineEvent::KeyAdded { track: state.tracks[track].id, key, time });
+            }
+            Some(Hit::Track(_)) => {
+                if !ctrl && !shift {
+                    state.selected_keys.clear();
+                    state.selected_clip = None;
+                }
+                state.drag = Drag::Box(t_mouse, mouse.y);
+            }
+            None => {}
+        }
+    }
+
+    // ── Drag ─────────────────────────────────────────────────────────────────
+    let left_down = ui.ctx.input.mouse_down(MouseButton::Left);
+    let mut select_box = None;
+    match state.drag.clone() {
+        Drag::Playhead if left_down => {
+            let t = snap(state, t_mouse, free).clamp(0.0, duration);
+            if t != state.playhead {
+                state.playhead = t;
+                events.push(TimelineEvent::PlayheadMoved(t));
+            }
+        }
+        Drag::Keys { grab, from } if left_down => {
+            let d = t_mouse - grab;
+            for (key, t0) in from {
+                let t = snap(state, t0 + d, free).clamp(0.0, duration);
+                for track in &mut state.tracks {
+                    let Some(k) = track.keys.iter_mut().find(|k| k.id == key) else { continue };
+                    if k.time != t {
+                        k.time = t;
+                        events.push(TimelineEvent::KeyMoved { track: track.id, key, time: t });
+                    }
+                }
+            }
+        }
+        Drag::Clip { clip, grab, part, start, end } if left_down => {
+            let d   = t_mouse - grab;
+            let min = state.frame_len();
+            let (s, e) = match part {
+                ClipGrab::Body => {
+                    let s = snap(state, start + d, free).clamp(0.0, (duration - (end - start)).max(0.0));
+                    (s, s + (end - start))
+                }
+                ClipGrab::Start => (snap(state, start + d, free).clamp(0.0, (end - min).max(0.0)), end),
+                ClipGrab::End   => (start, snap(state, end + d, free).clamp(start + min, duration.max(start + min))),
+            };
+            for track in &mut state.tracks {
+                let Some(c) = track.clips.iter_mut().find(|c| c.id == clip) else { continue };
+                if c.start == s && c.end() == e { continue; }
+                c.start  = s;
+                c.length = e - s;
+                events.push(match part {
+                    ClipGrab::Body => TimelineEvent::ClipMoved { track: track.id, clip, start: s },
+                    _              => TimelineEvent::ClipResized { track: track.id, clip, start: s, length: e - s },
+                });
+            }
+        }
+        Drag::Box(t0, y0) => {
+            let (x0, x1) = (to_x(t0).min(mouse.x), to_x(t0).max(mouse.x));
+            let r = Rect::new(Vec2::new(x0, y0.min(mouse.y)), Vec2::new(x1, y0.max(mouse.y)));
+            if left_down {
+                select_box = Some(r);
+            } else {
+                for (i, track) in state.tracks.iter().enumerate() {
+                    let mid = track_rect(i).center().y;
+                    for k in &track.keys {
+                        if r.contains(Vec2::new(to_x(k.time), mid)) && !state.selected_keys.contains(&k.id) {
+                            state.selected_keys.push(k.id);
+                        }
+                    }
+                }
+                state.drag = Drag::None;
+            }
+        }
+        Drag::Pan | Drag::None => {}
+        _ => state.drag = Drag::None,
+    }
+
+    // ── Keyboard ─────────────────────────────────────────────────────────────
+    let focused = ui.ctx.focus_item == Some(id);
+    if focused && (ui.ctx.input.key_pressed(Key::Delete) || ui.ctx.input.key_pressed(Key::Backspace)) {
+        for track in &mut state.tracks {
+            let tid = track.id;
+            track.keys.retain(|k| {
+                let keep = !state.selected_keys.contains(&k.id);
+                if !keep { events.push(TimelineEvent::KeyDeleted { track: tid, key: k.id }); }
+                keep
+            });
+            track.clips.retain(|c| {
+                let keep = state.selected_clip != Some(c.id);
+                if !keep { events.push(TimelineEvent::ClipDeleted { track: tid, clip: c.id }); }
+                keep
+            });
+        }
+        state.selected_keys.clear();
+        state.selected_clip = None;
+    }
+    if (state.selected_keys.clone(), state.selected_clip) != before {
+        events.push(TimelineEvent::SelectionChanged);
+    }
+
+    // ── Scrollbar ────────────────────────────────────────────────────────────
+    if duration > visible {
+        let sid    = ui.ctx.make_id("##timeline_scroll");
+        let track  = Rect::new(Vec2::new(area.min.x, area.max.y), frame.max);
+        let scroll = scrollbar(ui, sid, track, false, view_start * pps, area.width(), duration * pps);
+        state.view_start = scroll / pps;
+    }
+
+    // ── Drawing ──────────────────────────────────────────────────────────────
+    let style      = &ui.ctx.style;
+    let header_bg  = style.color(StyleColor::TableHeaderBg);
+    let row_bg     = style.color(StyleColor::TableRowBg);
+    let row_alt    = style.color(StyleColor::TableRowBgAlt);
+    let border     = style.color(StyleColor::Border);
+    let grid       = style.color(StyleColor::PlotGrid);
+    let text_col   = style.color(StyleColor::Text);
+    let text_dim   = style.color(StyleColor::TextDisabled);
+    let sel_col    = style.color(StyleColor::SliderGrabActive);
+    let key_col    = style.color(StyleColor::SliderGrab);
+    let box_col    = style.color(StyleColor::PlotSelection);
+    let rounding   = style.frame_rounding;
+    let playhead_c = Color::from_hex(PLAYHEAD_COLOR);
+
+    let draw = &mut ui.ctx.draw_list;
+    draw.filled_rect(Rect::new(frame.min, Vec2::new(frame.max.x, ruler.max.y)), 0.0, header_bg);
+    for i in 0..state.tracks.len() {
+        let r = track_rect(i);
+        let full = Rect::new(Vec2::new(frame.min.x, r.min.y), r.max);
+        draw.filled_rect(full, 0.0, if i % 2 == 1 { row_alt } else { row_bg });
+    }
+
+    // Ruler ticks and grid
+    let (major, minor) = tick_steps(pps, state.fps);
+    let first = (view_start / major).floor() as i64;
+    let last  = ((view_start + visible) / major).ceil() as i64;
+    let mut labels = Vec::new();
+    draw.push_clip_rect(Rect::new(ruler.min, area.max));
+    for m in first..=last {
+        let t = m as f32 * major;
+        let x = to_x(t);
+        draw.line(Vec2::new(x, ruler.min.y + ruler_h * 0.4), Vec2::new(x, ruler.max.y), 1.0, text_dim);
+        draw.line(Vec2::new(x, area.min.y), Vec2::new(x, area.max.y), 1.0, grid);
+        let subs = (major / minor).round() as i64;
+        for s in 1..subs {
+            let xs = to_x(t + s as f32 * minor);
+            draw.line(Vec2::new(xs, ruler.max.y - ruler_h * 0.25), Vec2::new(xs, ruler.max.y), 1.0, text_dim);
+        }
+        labels.push((format_time(t, state.fps), Vec2::new(x + 3.0, ruler.min.y + 2.0)));
+    }
+    let end_x = to_x(duration);
+    if end_x < area.max.x {
+        draw.filled_rect(Rect::new(Vec2::new(end_x, ruler.min.y), area.max), 0.0, Color::BLACK.with_alpha(0.25));
+    }
+    draw.pop_clip_rect();
+
+    // Clips and keys
+    draw.push_clip_rect(area);
+    let mut clip_labels = Vec::new();
+    for (i, track) in state.tracks.iter().enumerate() {
+        let r = track_rect(i);
+        for c in &track.clips {
+            let cr  = Rect::new(Vec2::new(to_x(c.start), r.min.y + 3.0), Vec2::new(to_x(c.end()), r.max.y - 3.0));
+            let col = c.color.unwrap_or_else(|| Color::from_hex(CLIP_COLORS[i % CLIP_COLORS.len()]));
+            draw.filled_rect(cr, rounding, col);
+            if state.selected_clip == Some(c.id) {
+                draw.rect_outline(cr, 2.0, sel_col);
+            } else {
+                draw.rect_outline(cr, 1.0, border);
+            }
+            if !c.label.is_empty() { clip_labels.push((cr, c.label.as_str())); }
+        }
+    }
+    for (cr, label) in clip_labels {
+        let inner = Rect::new(cr.min + Vec2::new(4.0, 0.0), cr.max - Vec2::new(4.0, 0.0));
+        if inner.width() <= 0.0 { continue; }
+        ui.ctx.draw_list.push_clip_rect(inner);
+        ui.draw_text(label, Vec2::new(inner.min.x, cr.center().y - fs * 0.5), text_col);
+        ui.ctx.draw_list.pop_clip_rect();
+    }
+    let draw = &mut ui.ctx.draw_list;
+    for (i, track) in state.tracks.iter().enumerate() {
+        let mid = track_rect(i).center().y;
+        for k in &track.keys {
+            let c   = Vec2::new(to_x(k.time), mid);
+            let col = if state.selected_keys.contains(&k.id) { sel_col } else { key_col };
+            diamond(draw, c, key_r, col);
+        }
+    }
+    if let Some(r) = select_box {
+        draw.filled_rect(r, 0.0, box_col);
+        draw.rect_outline(r, 1.0, box_col.with_alpha(1.0));
+    }
+    draw.pop_clip_rect();
+
+    // Playhead
+    let px = to_x(state.playhead);
+    if px >= area.min.x && px <= area.max.x {
+        draw.line(Vec2::new(px, ruler.min.y + ruler_h * 0.5), Vec2::new(px, area.max.y), 1.5, playhead_c);
+        let hw = ruler_h * 0.3;
+        draw.triangle_filled(
+            Vec2::new(px - hw, ruler.min.y + ruler_h * 0.5),
+            Vec2::new(px + hw, ruler.min.y + ruler_h * 0.5),
+            Vec2::new(px, ruler.max.y),
+            playhead_c,
+        );
+    }
+
+    draw.line(Vec2::new(area.min.x, frame.min.y), Vec2::new(area.min.x, area.max.y), 1.0, border);
+    draw.line(Vec2::new(frame.min.x, ruler.max.y), Vec2::new(frame.max.x, ruler.max.y), 1.0, border);
+    draw.rect_outline(frame, 1.0, border);
+
+    // Text: ruler labels, current time, track names
+    ui.ctx.draw_list.push_clip_rect(ruler);
+    for (text, at) in labels {
+        ui.draw_text(&text, at, text_dim);
+    }
+    ui.ctx.draw_list.pop_clip_rect();
+    let now = format_time(state.playhead, state.fps);
+    ui.draw_text(&now, Vec2::new(frame.min.x + 6.0, frame.min.y + (ruler_h - fs) * 0.5), playhead_c);
+    ui.ctx.draw_list.push_clip_rect(Rect::new(frame.min, Vec2::new(area.min.x - 4.0, area.max.y)));
+    for (i, track) in state.tracks.iter().enumerate() {
+        let r = track_rect(i);
+        ui.draw_text(&track.name, Vec2::new(frame.min.x + 6.0, r.center().y - fs * 0.5), text_col);
+    }
+    ui.ctx.draw_list.pop_clip_rect();
+
+    events
+}
+
+/// `t` snapped unless snapping is off or overridden with Alt.
+fn snap(state: &TimelineState, t: f32, free: bool) -> f32 {
+    if free { t } else { state.snap_time(t) }
+}
+
+fn diamond(draw: &mut crate::draw_list::DrawList, c: Vec2, r: f32, col: Color) {
+    let (n, e, s, w) = (c - Vec2::new(0.0, r), c + Vec2::new(r, 0.0), c + Vec2::new(0.0, r), c - Vec2::new(r, 0.0));
+    draw.triangle_filled(n, e, s, col);
+    draw.triangle_filled(s, w, n, col);
+}
+
+/// Major and minor ruler spacing in seconds: whole frames below a second,
+/// then 1, 2, 5, 10, 15, 30 s, minutes…
+fn tick_steps(pps: f32, fps: f32) -> (f32, f32) {
+    const SECONDS: [f32; 12] = [1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0, 3600.0];
+    let min = MIN_TICK_PX / pps;
+    if fps > 0.0 && min < 1.0 {
+        let frames = [1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0].into_iter()
+            .find(|&f| f / fps >= min && f < fps)
+            .unwrap_or(fps);
+        let major = frames / fps;
+        let minor = if frames >= 5.0 { major / 5.0 } else { 1.0 / fps };
+        return (major, minor);
+    }
+    if fps <= 0.0 && min < 1.0 {
+        let major = [0.01, 0.02, 0.05, 0.1, 0.2, 0.5, 1.0].into_iter().find(|&s| s >= min).unwrap_or(1.0);
+        return (major, major / 5.0);
+    }
+    let major = SECONDS.into_iter().find(|&s| s >= min).unwrap_or_else(|| (min / 3600.0).ceil() * 3600.0);
+    let minor = if major == 15.0 { 5.0 } else if major == 2.0 { 0.5 } else { major / 5.0 };
+    (major, minor)
+}
+
+/// `s:ff` (seconds and frames), `m:ss:ff` past a minute; `s.ss` without fps.
+fn format_time(t: f32, fps: f32) -> String {
+    if fps <= 0.0 { return format!("{t:.2}"); }
+    let frames = (t * fps).round() as i64;
+    let fps_i  = fps.round().max(1.0) as i64;
+    let (secs, ff) = (frames / fps_i, frames % fps_i);
+    if secs >= 60 {
+        format!("{}:{:02}:{ff:02}", secs / 60, secs % 60)
+    } else {
+        format!("{secs}:{ff:02}")
+    }
+}
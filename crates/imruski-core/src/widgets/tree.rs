@@ -0,0 +1,120 @@
+//! Tree-node widget – `tree_node` / `tree_pop` pairs that nest with indentation.
+
+use crate::{
+    id::parse_label,
+    input::MouseButton,
+    style::StyleColor,
+    ui::Ui,
+    Rect, Vec2,
+};
+
+bitflags::bitflags! {
+    /// Controls tree-node behaviour and appearance.
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct TreeNodeFlags: u32 {
+        /// No arrow and never toggles; the node is always "open".
+        const LEAF                 = 1 << 0;
+        /// Open the first time the node is shown.
+        const DEFAULT_OPEN         = 1 << 1;
+        /// Only a click on the arrow toggles (combine with
+        /// `OPEN_ON_DOUBLE_CLICK` to also allow double-clicking the label).
+        const OPEN_ON_ARROW        = 1 << 2;
+        /// Only a double-click toggles.
+        const OPEN_ON_DOUBLE_CLICK = 1 << 3;
+        /// Draw with the selected (`Header`) background.
+        const SELECTED             = 1 << 4;
+        /// Hit-box and highlight extend to the right edge of the window.
+        const SPAN_FULL_WIDTH      = 1 << 5;
+        /// Framed header look, like `collapsing`.
+        const FRAMED               = 1 << 6;
+    }
+}
+
+/// Returns `true` when open; the caller must then call `tree_pop`.
+///
+/// The open state persists in widget storage (`open`). A pending
+/// `set_next_item_open` overrides it.
+pub fn tree_node(ui: &mut Ui<'_>, label: &str, flags: TreeNodeFlags) -> bool {
+    let (text, id_src) = parse_label(label);
+    let id = ui.ctx.make_id(id_src);
+
+    let leaf = flags.contains(TreeNodeFlags::LEAF);
+    let mut open = match ui.ctx.next_item_open.take() {
+        Some(forced) => forced,
+        None => ui.ctx.get_storage(id)
+            .map_or(flags.contains(TreeNodeFlags::DEFAULT_OPEN), |s| s.open),
+    };
+
+    let fs      = ui.ctx.style.font_size;
+    let fp      = ui.ctx.style.frame_padding;
+    let framed  = flags.contains(TreeNodeFlags::FRAMED);
+    let h       = if framed { fs + fp.1 * 2.0 } else { fs + 2.0 };
+    let arrow_w = fs;
+    let tw      = ui.text_width(text);
+    let full    = framed || flags.contains(TreeNodeFlags::SPAN_FULL_WIDTH);
+    let width   = if full { ui.available_width() } else { arrow_w + fp.0 + tw + fp.0 };
+
+    let pos = match ui.layout_next(Vec2::new(width, h)) { Some(p) => p, None => return false };
+    let rect       = Rect::from_min_size(pos, Vec2::new(width, h));
+    let arrow_rect = Rect::from_min_size(pos, Vec2::new(arrow_w + fp.0, h));
+
+    let (hovered, _, clicked) = ui.ctx.button_behavior(id, rect);
+
+    if !leaf {
+        let on_arrow  = flags.contains(TreeNodeFlags::OPEN_ON_ARROW);
+        let on_double = flags.contains(TreeNodeFlags::OPEN_ON_DOUBLE_CLICK);
+        let mouse     = ui.ctx.input.mouse_pos;
+        let pressed   = hovered && ui.ctx.input.mouse_clicked(MouseButton::Left);
+        let toggle = if on_arrow || on_double {
+            (on_arrow && pressed && arrow_rect.contains(mouse))
+                || (on_double && hovered && ui.ctx.input.mouse_double_clicked(MouseButton::Left))
+        } else {
+            clicked
+        };
+        if toggle { open = !open; }
+    }
+    if !leaf { ui.ctx.get_storage_mut(id).open = open; }
+
+    // ── Drawing ──────────────────────────────────────────────────────────────
+    let rounding = if framed { ui.ctx.style.frame_rounding } else { 0.0 };
+    let bg = if hovered && ui.ctx.input.mouse_down(MouseButton::Left) {
+        Some(StyleColor::HeaderActive)
+    } else if hovered {
+        Some(StyleColor::HeaderHovered)
+    } else if flags.contains(TreeNodeFlags::SELECTED) || framed {
+        Some(StyleColor::Header)
+    } else {
+        None
+    };
+    if let Some(bg) = bg {
+        let c = ui.ctx.style.color(bg);
+        ui.ctx.draw_list.filled_rect(rect, rounding, c);
+    }
+
+    let tc = ui.ctx.style.color(StyleColor::Text);
+    if !leaf {
+        let ax = pos.x + (arrow_w - 6.0) * 0.5 + if framed { fp.0 * 0.5 } else { 0.0 };
+        let ay = pos.y + h * 0.5;
+        let (a, b, c) = if open {
+            (Vec2::new(ax, ay - 3.0), Vec2::new(ax + 6.0, ay - 3.0), Vec2::new(ax + 3.0, ay + 3.0))
+        } else {
+            (Vec2::new(ax + 1.0, ay - 4.0), Vec2::new(ax + 5.0, ay), Vec2::new(ax + 1.0, ay + 4.0))
+        };
+        ui.ctx.draw_list.triangle_filled(a, b, c, tc);
+    }
+    let tp = Vec2::new(pos.x + arrow_w + fp.0, pos.y + (h - fs) * 0.5);
+    ui.draw_text(text, tp, tc);
+
+    let open = leaf || open;
+    if open {
+        ui.ctx.id_stack.push(id);
+        ui.indent();
+    }
+    open
+}
+
+/// Close a node opened by `tree_node` (only call when it returned `true`).
+pub fn tree_pop(ui: &mut Ui<'_>) {
+    ui.unindent();
+    ui.ctx.id_stack.pop();
+}
@@ -31,6 +31,13 @@ pub fn begin(
         WindowState::new(default_pos, default_size, flags)
     }).clone();
 
+    // Clicking anywhere in the window focuses it (later windows are on top)
+    if ws.rect().contains(ui.ctx.input.mouse_pos)
+        && ui.ctx.input.mouse_clicked(crate::input::MouseButton::Left)
+    {
+        ui.ctx.focused_window = Some(win_id);
+    }
+
     // ── Title bar interaction ─────────────────────────────────────────────────
 
     let title_rect = Rect::from_min_size(ws.pos, Vec2::new(ws.size.x, TITLE_BAR_H));
@@ -156,6 +156,9 @@ impl<'a> Dx11Frame<'a> {
 
     /// Submit the finished [`RenderFrame`] to the screen.
     pub fn submit(&mut self, frame: RenderFrame<'_>) {
+        if let Some(text) = frame.clipboard_text {
+            self.imgui_ui.set_clipboard_text(text);
+        }
         let dl = frame.draw_list;
         if dl.cmd_buf.is_empty() { return; }
         let bg    = self.imgui_ui.get_background_draw_list();
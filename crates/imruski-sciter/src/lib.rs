@@ -83,6 +83,7 @@ pub struct SciterRenderer {
     font_atlas: SciterFontAtlas,
     textures:   HashMap<usize, Vec<u8>>, // texture_id → RGBA pixels (CPU-side)
     next_tex:   usize,
+    clipboard:  Option<String>,
 }
 
 impl SciterRenderer {
@@ -107,11 +108,16 @@ impl SciterRenderer {
             font_atlas: SciterFontAtlas,
             textures:   HashMap::new(),
             next_tex:   10, // reserve 0-9 for built-in IDs
+            clipboard:  None,
         })
     }
 
     pub fn font_atlas(&self) -> &SciterFontAtlas { &self.font_atlas }
 
+    /// Text the UI copied since the last call. sciter-rs exposes no clipboard
+    /// writer, so the host window must put this on the system clipboard.
+    pub fn take_clipboard_text(&mut self) -> Option<String> { self.clipboard.take() }
+
     // ── Translate draw list → Sciter Graphics calls ───────────────────────────
     //
     // In a full integration this method would be invoked from within a Sciter
@@ -227,6 +233,9 @@ impl Renderer for SciterRenderer {
     }
 
     fn render(&mut self, frame: RenderFrame<'_>) {
+        if let Some(text) = frame.clipboard_text {
+            self.clipboard = Some(text.to_owned());
+        }
         self.render_with_graphics(frame.draw_list);
     }
 
@@ -80,6 +80,7 @@ pub struct UltralightRenderer {
     font_atlas: UltralightFontAtlas,
     textures:   HashMap<usize, Vec<u8>>,
     next_tex:   usize,
+    clipboard:  Option<String>,
 
     /// Pixel buffer (RGBA, row-major). Written by `render()`.
     /// Attach to a GPU texture or composite with another renderer.
@@ -112,6 +113,7 @@ impl UltralightRenderer {
             font_atlas:  UltralightFontAtlas::new(13.0),
             textures:    HashMap::new(),
             next_tex:    10,
+            clipboard:   None,
             framebuffer: vec![0u8; fb_size],
         })
     }
@@ -204,6 +206,11 @@ impl UltralightRenderer {
     }
 
     pub fn font_atlas(&self) -> &UltralightFontAtlas { &self.font_atlas }
+
+    /// Text the UI copied since the last call. Ultralight reaches the
+    /// clipboard only through a host-supplied `platform::Clipboard`, so the
+    /// host must put this on the system clipboard itself.
+    pub fn take_clipboard_text(&mut self) -> Option<String> { self.clipboard.take() }
 }
 
 // ─── Renderer trait impl ─────────────────────────────────────────────────────
@@ -214,6 +221,9 @@ impl Renderer for UltralightRenderer {
     }
 
     fn render(&mut self, frame: RenderFrame<'_>) {
+        if let Some(text) = frame.clipboard_text {
+            self.clipboard = Some(text.to_owned());
+        }
         self.rasterize(frame.draw_list);
     }
 
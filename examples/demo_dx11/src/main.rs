@@ -81,15 +81,15 @@ fn main() {
 
                     // Tab bar
                     if ui.begin_tab_bar(obfstr!("overlay_tabs")) {
-                        if ui.tab_item(obfstr!("Aimbot")) {
+                        if ui.tab_item(obfstr!("Aimbot"), None, imruski::TabItemFlags::empty()) {
                             ui.text(obfstr!("Aimbot settings here."));
                             ui.end_tab_item();
                         }
-                        if ui.tab_item(obfstr!("Visuals")) {
+                        if ui.tab_item(obfstr!("Visuals"), None, imruski::TabItemFlags::empty()) {
                             ui.text(obfstr!("Visuals settings here."));
                             ui.end_tab_item();
                         }
-                        if ui.tab_item(obfstr!("Misc")) {
+                        if ui.tab_item(obfstr!("Misc"), None, imruski::TabItemFlags::empty()) {
                             ui.text(obfstr!("Misc settings here."));
                             ui.end_tab_item();
                         }
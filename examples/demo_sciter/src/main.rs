@@ -76,11 +76,11 @@ fn main() {
 
             // ── Tab bar ──────────────────────────────────────────────────────
             if ui.begin_tab_bar("tabs##main") {
-                if ui.tab_item("Info##tab1") {
+                if ui.tab_item("Info##tab1", None, imruski::TabItemFlags::empty()) {
                     ui.text("This is the Info tab.");
                     ui.end_tab_item();
                 }
-                if ui.tab_item("Settings##tab2") {
+                if ui.tab_item("Settings##tab2", None, imruski::TabItemFlags::empty()) {
                     ui.text("This is the Settings tab.");
                     ui.end_tab_item();
                 }